@@ -1,140 +1,715 @@
 use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use anyhow::Result;
 
 use chia_bls::derivable_key::DerivableKey;
 use chia_bls::derive_keys;
-use chia_bls::mnemonic::entropy_to_mnemonic;
-use chia_bls::mnemonic::entropy_to_seed;
-use chia_bls::mnemonic::mnemonic_to_entropy;
 use chia_bls::public_key::PublicKey;
 use chia_bls::secret_key::SecretKey;
 use chia_bls::signature;
 use chia_bls::signature::sign;
-use clvm_tools_rs::classic::clvm::__type_compatibility__::{t, Bytes, BytesFromType, Stream};
-use clvm_tools_rs::classic::clvm::serialize::{sexp_from_stream, SimpleCreateCLVMObject};
-use clvm_tools_rs::classic::clvm_tools::binutils::disassemble;
+use clvm_tools_rs::classic::clvm::__type_compatibility__::Stream;
 use clvm_tools_rs::classic::clvm_tools::cmds::{launch_tool, OpcConversion, OpdConversion};
 use clvmr::allocator::Allocator;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use yaml_rust::YamlEmitter;
 
+use crate::api_error::ApiError;
+use crate::blockchain::bech32m::decode_puzzle_hash;
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_record::CoinRecord;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::constants::{Constants, MAINNET, TESTNET10};
+use crate::blockchain::network_info::NetworkInfo;
+use crate::blockchain::sized_bytes::prep_hex_str;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::sized_bytes::Bytes48;
+use crate::blockchain::sized_bytes::Bytes96;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::cat::cat_utils::{
+    parse_cat_from_parent, unsigned_spend_bundle_for_spendable_cats, CatVersion, SpendableCat,
+};
+use crate::chia_wallet::cat::issuance::{
+    create_cat_issuance_bundle, create_cat_melt_bundle, create_cat_reissue_bundle, StandardCatCoin,
+    Tail,
+};
 use crate::chia_wallet::cat::puzzles::create_cat_outer_puzzle::create_cat_puzzle;
-use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::get_puzzle_from_pk;
+use crate::chia_wallet::clawback::clawback_utils::{
+    create_clawback_claim_spend, create_clawback_payment, create_clawback_spend,
+    parse_clawback_coins, ClawbackMetadata,
+};
+use crate::chia_wallet::coin_selection::{select_coins, CoinSelectionConfig, SelectionStrategy};
+use crate::chia_wallet::did::did_utils::{
+    create_did_launch_bundle, create_did_metadata_update_spend, create_did_nft_assignment_spend,
+    create_did_transfer_spend, did_metadata_from_program, did_metadata_program, parse_did_puzzle,
+    DidInfo,
+};
+use crate::chia_wallet::keys::fingerprint::fingerprint;
+use crate::chia_wallet::keys::key_info::KeyInfo;
+use crate::chia_wallet::keys::keyring::{Keyring, KeyringError};
+use crate::chia_wallet::keys::mnemonic::{self, MnemonicError};
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::message_signing::{
+    sign_message, synthetic_pk_matches_puzzle_hash, verify_message,
+};
+use crate::chia_wallet::multisig::multisig_utils::{
+    add_partial_signatures, create_multisig_spend_bundle, sign_multisig_spends, MultisigInfo,
+};
+use crate::chia_wallet::nft::nft_utils::{
+    create_nft_mint_bundle, create_nft_transfer_spend, parse_nft_puzzle, DidOwner, NftMetadata,
+    NftMint, OwnerChange,
+};
+use crate::chia_wallet::notification::notification_utils::{
+    create_notification_bundle, parse_notifications,
+};
+use crate::chia_wallet::offer::offer_utils::{
+    complete_offer, create_offer, create_taker_offer, AssetId, Offer, OfferedCat,
+};
+use crate::chia_wallet::pool::pool_utils::{
+    create_plot_nft_absorb_spends, create_plot_nft_launch_bundle, create_plot_nft_travel_spend,
+    parse_plot_nft, PlotNft, PoolState, DEFAULT_DELAY_TIME,
+};
+use crate::chia_wallet::puzzle_compression::{compress_spend_bundle, decompress_spend_bundle};
+use crate::chia_wallet::puzzle_drivers::{CurriedArgsDriver, DriverRegistry, PuzzleLayer};
+use crate::chia_wallet::sign_coin_spends::sign_coin_spends;
+use crate::chia_wallet::singleton::singleton_utils::{
+    create_singleton_launch_bundle, lineage_proof_for_coin_spend, match_singleton_puzzle,
+    puzzle_for_singleton, spend_singleton,
+};
+use crate::chia_wallet::standart::derivation::{
+    derive_puzzle_hashes_from_master_sk, derive_unhardened_puzzle_hashes,
+    master_pk_to_wallet_unhardened_intermediate, DerivationError, DerivationRecord,
+};
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    calculate_synthetic_public_key, calculate_synthetic_secret_key, default_hidden_puzzle,
+    get_puzzle_from_pk,
+};
+use crate::chia_wallet::standart::transaction::{
+    create_standard_spend_bundle, empty_signature, Payment, SpendableCoin,
+};
 use crate::program_utils::call_tool::call_tool_with_return;
 use crate::program_utils::cldb::cldb_with_return;
 use crate::program_utils::cldb::to_yaml;
 use crate::program_utils::curry_utils::curry;
+use crate::program_utils::serialize::validate_serialized_program;
+use crate::program_utils::utils::INFINITE_COST;
 
 use crate::program_utils::program::Program;
-use crate::program_utils::serialized_program::SerializedProgram;
 
 pub struct UncurriedProgramToDart {
     pub program: Vec<u8>,
     pub args: Vec<String>,
-    pub error: String,
 }
 pub struct ApiOutputProgram {
     pub program: Vec<u8>,
     pub cost: u64,
 }
+pub struct ApiKeyInfo {
+    pub fingerprint: u32,
+    pub master_public_key: Vec<u8>,
+    pub farmer_public_key: Option<Vec<u8>>,
+    pub pool_public_key: Option<Vec<u8>>,
+    pub first_wallet_address: String,
+}
+pub struct SignatureVerification {
+    pub valid: bool,
+    pub coin_spend_index: Option<u32>,
+    pub coin_name: Option<Vec<u8>>,
+    pub error: Option<String>,
+}
+pub struct MnemonicValidation {
+    pub valid: bool,
+    pub invalid_word_index: Option<u32>,
+    pub checksum_failed: bool,
+    pub error: Option<String>,
+}
+pub struct KeyringKey {
+    pub fingerprint: u32,
+    pub label: Option<String>,
+    pub public_key: Vec<u8>,
+    pub has_mnemonic: bool,
+}
+pub struct ApiCatInfo {
+    pub is_cat: bool,
+    /// CAT1 coins predate the CAT2 upgrade and cannot be spent by this wallet.
+    pub is_cat1: bool,
+    pub tail_hash: Option<Vec<u8>>,
+    pub parent_inner_puzzle: Option<Vec<u8>>,
+    pub inner_puzzle_hash: Option<Vec<u8>>,
+    /// JSON, in the form `cat_spend_bundle_create` expects.
+    pub lineage_proof: Option<String>,
+}
+pub struct ApiSingletonLaunch {
+    pub launcher_id: Vec<u8>,
+    /// Unsigned, as JSON.
+    pub spend_bundle: String,
+}
+pub struct ApiSingletonInfo {
+    pub is_singleton: bool,
+    pub launcher_id: Option<Vec<u8>>,
+    pub inner_puzzle: Option<Vec<u8>>,
+}
+pub struct ApiNftInfo {
+    pub is_nft: bool,
+    pub launcher_id: Option<Vec<u8>>,
+    /// Serialized metadata program, and the same as JSON when it follows the NFT1 format.
+    pub metadata: Option<Vec<u8>>,
+    pub metadata_json: Option<String>,
+    pub metadata_updater_puzzle_hash: Option<Vec<u8>>,
+    /// Launcher id of the DID the NFT is assigned to.
+    pub current_owner: Option<Vec<u8>>,
+    pub royalty_puzzle_hash: Option<Vec<u8>>,
+    /// In basis points.
+    pub royalty_percentage: Option<u32>,
+    pub p2_puzzle_hash: Option<Vec<u8>>,
+}
+pub struct ApiDidInfo {
+    pub is_did: bool,
+    pub launcher_id: Option<Vec<u8>>,
+    pub recovery_list_hash: Option<Vec<u8>>,
+    pub num_verifications_required: Option<u64>,
+    /// Serialized metadata program, and the same as a JSON object of strings when it
+    /// holds only string pairs.
+    pub metadata: Option<Vec<u8>>,
+    pub metadata_json: Option<String>,
+    pub p2_puzzle_hash: Option<Vec<u8>>,
+}
+pub struct ApiSignedMessage {
+    /// The synthetic public key the message was signed with.
+    pub public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+pub struct DerivedPuzzleHash {
+    pub index: u32,
+    pub public_key: Vec<u8>,
+    pub puzzle_hash: Vec<u8>,
+    pub hardened: bool,
+}
+
+fn fixed_bytes<const N: usize>(bytes: &[u8], kind: &'static str) -> Result<[u8; N], ApiError> {
+    if bytes.len() != N {
+        return Err(ApiError::InvalidLength {
+            kind,
+            expected: N,
+            got: bytes.len(),
+        });
+    }
+    let mut array = [0u8; N];
+    array.copy_from_slice(bytes);
+    Ok(array)
+}
+
+fn secret_key_from_vec(sk: &[u8]) -> Result<SecretKey, ApiError> {
+    let sk_array = fixed_bytes::<32>(sk, "secret key")?;
+    SecretKey::from_bytes(&sk_array).map_err(|e| ApiError::InvalidSecretKey(format!("{:?}", e)))
+}
+
+fn public_key_from_vec(pk: &[u8]) -> Result<PublicKey, ApiError> {
+    let pk_array = fixed_bytes::<48>(pk, "G1 element")?;
+    PublicKey::from_bytes(&pk_array).map_err(|e| ApiError::InvalidPublicKey(format!("{:?}", e)))
+}
+
+fn signature_from_vec(sig: &[u8]) -> Result<signature::Signature, ApiError> {
+    let sig_array = fixed_bytes::<96>(sig, "G2 element")?;
+    signature::Signature::from_bytes(&sig_array)
+        .map_err(|e| ApiError::InvalidSignature(format!("{:?}", e)))
+}
+
+fn mnemonic_error(e: MnemonicError) -> ApiError {
+    ApiError::InvalidMnemonic(e.to_string())
+}
+
+fn constants_for_network(network: &str) -> Result<&'static Constants, ApiError> {
+    match network {
+        "mainnet" => Ok(&MAINNET),
+        "testnet10" => Ok(&TESTNET10),
+        _ => Err(ApiError::UnknownNetwork(network.to_string())),
+    }
+}
+
+fn spend_bundle_from_json(spend_bundle_json: &str) -> Result<SpendBundle, ApiError> {
+    serde_json::from_str(spend_bundle_json).map_err(|e| ApiError::InvalidJson(e.to_string()))
+}
+
+#[derive(Deserialize)]
+struct SpendableCoinJson {
+    coin: Coin,
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+struct PaymentJson {
+    puzzle_hash: Bytes32,
+    amount: u64,
+    #[serde(default)]
+    memos: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct SpendableCatJson {
+    coin: Coin,
+    tail_hash: Bytes32,
+    inner_puzzle: String,
+    inner_solution: String,
+    #[serde(default)]
+    lineage_proof: LineageProof,
+    #[serde(default)]
+    extra_delta: i64,
+}
+
+#[derive(Deserialize)]
+struct StandardCatJson {
+    coin: Coin,
+    public_key: String,
+    #[serde(default)]
+    lineage_proof: LineageProof,
+}
+
+#[derive(Deserialize)]
+struct NftMintJson {
+    metadata: NftMetadata,
+    royalty_puzzle_hash: Bytes32,
+    royalty_percentage: u16,
+    minter_public_key: String,
+    target_puzzle_hash: Bytes32,
+    #[serde(default)]
+    did_owner: Option<DidOwner>,
+}
+
+#[derive(Deserialize)]
+struct OfferCatsJson {
+    tail_hash: Bytes32,
+    #[serde(default)]
+    amount: u64,
+    coins: Vec<StandardCatJson>,
+}
+
+#[derive(Deserialize)]
+struct RequestedPaymentJson {
+    #[serde(default)]
+    asset_id: Option<Bytes32>,
+    puzzle_hash: Bytes32,
+    amount: u64,
+    #[serde(default)]
+    memos: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct OfferSummaryJson {
+    offered: BTreeMap<String, u64>,
+    requested: BTreeMap<String, u64>,
+    fee: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ClawbackMetadataJson {
+    time_lock: u64,
+    sender_puzzle_hash: Bytes32,
+    recipient_puzzle_hash: Bytes32,
+}
+
+impl From<ClawbackMetadataJson> for ClawbackMetadata {
+    fn from(metadata: ClawbackMetadataJson) -> Self {
+        ClawbackMetadata {
+            time_lock: metadata.time_lock,
+            sender_puzzle_hash: metadata.sender_puzzle_hash,
+            recipient_puzzle_hash: metadata.recipient_puzzle_hash,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ClawbackCoinJson {
+    coin: Coin,
+    #[serde(flatten)]
+    metadata: ClawbackMetadataJson,
+}
+
+#[derive(Serialize)]
+struct NotificationJson {
+    coin: Coin,
+    target_puzzle_hash: Bytes32,
+    amount: u64,
+    // Hex; offer notifications carry an offer1... string.
+    message: String,
+}
+
+#[derive(Serialize)]
+struct PuzzleParamJson {
+    name: String,
+    // The atom's bytes, when the parameter is an atom.
+    atom: Option<String>,
+    program: String,
+}
+
+#[derive(Serialize)]
+struct PuzzleLayerJson {
+    name: String,
+    mod_hash: String,
+    params: Vec<PuzzleParamJson>,
+    inner: Option<Box<PuzzleLayerJson>>,
+}
+
+impl From<&PuzzleLayer> for PuzzleLayerJson {
+    fn from(layer: &PuzzleLayer) -> Self {
+        PuzzleLayerJson {
+            name: layer.name.clone(),
+            mod_hash: hex::encode(&layer.mod_hash.bytes),
+            params: layer
+                .params
+                .iter()
+                .map(|(name, value)| PuzzleParamJson {
+                    name: name.clone(),
+                    atom: value.as_vec().map(hex::encode),
+                    program: hex::encode(&value.serialized),
+                })
+                .collect(),
+            inner: layer
+                .inner
+                .as_ref()
+                .map(|inner| Box::new(PuzzleLayerJson::from(inner.as_ref()))),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref PUZZLE_DRIVERS: RwLock<DriverRegistry> = RwLock::new(DriverRegistry::default());
+}
+
+// Registering is a single map insert, so a panic elsewhere while the lock was held
+// cannot leave the registry half updated and a poisoned lock is still safe to use.
+fn puzzle_drivers() -> RwLockReadGuard<'static, DriverRegistry> {
+    PUZZLE_DRIVERS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+fn puzzle_drivers_mut() -> RwLockWriteGuard<'static, DriverRegistry> {
+    PUZZLE_DRIVERS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+fn did_metadata_from_json(metadata_json: &str) -> Result<Program, ApiError> {
+    let metadata: BTreeMap<String, String> =
+        serde_json::from_str(metadata_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    Ok(did_metadata_program(&metadata))
+}
+
+fn did_info_from_puzzle(did_puzzle: &Vec<u8>) -> Result<DidInfo, ApiError> {
+    match parse_did_puzzle(&program_from_vec(did_puzzle)?) {
+        Some((info, _p2_puzzle)) => Ok(info),
+        None => Err(ApiError::ProgramFailed("puzzle is not a DID".to_string())),
+    }
+}
+
+fn bytes32_list_from_hex(
+    hex_list: &[String],
+    kind: &'static str,
+) -> Result<Vec<Bytes32>, ApiError> {
+    let mut list = Vec::new();
+    for hex in hex_list {
+        list.push(Bytes32::from(
+            fixed_bytes::<32>(&hex_to_vec(hex)?, kind)?.to_vec(),
+        ));
+    }
+    Ok(list)
+}
+
+fn tail_from_kind(kind: &str, argument: &[u8]) -> Result<Tail, ApiError> {
+    match kind {
+        "genesis_by_coin_id" => Ok(Tail::GenesisByCoinId(Bytes32::from(
+            fixed_bytes::<32>(argument, "genesis coin id")?.to_vec(),
+        ))),
+        "everything_with_signature" => Ok(Tail::EverythingWithSignature(public_key_from_vec(
+            argument,
+        )?)),
+        "delegated_tail" => Ok(Tail::DelegatedTail(public_key_from_vec(argument)?)),
+        _ => Err(ApiError::UnknownTail(kind.to_string())),
+    }
+}
+
+// An empty serialized program stands for nil, e.g. a standard TAIL's solution or an
+// empty singleton key/value list.
+fn program_or_nil_from_vec(ser_program_bytes: &Vec<u8>) -> Result<Program, ApiError> {
+    if ser_program_bytes.is_empty() {
+        Ok(Program::from(Vec::<u8>::new()))
+    } else {
+        program_from_vec(ser_program_bytes)
+    }
+}
+
+fn spendable_coins_from_json(coins_json: &str) -> Result<Vec<SpendableCoin>, ApiError> {
+    let coins: Vec<SpendableCoinJson> =
+        serde_json::from_str(coins_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let mut spendable_coins = Vec::new();
+    for coin in coins {
+        spendable_coins.push(SpendableCoin {
+            coin: coin.coin,
+            public_key: public_key_from_vec(&hex_to_vec(&coin.public_key)?)?,
+        });
+    }
+    Ok(spendable_coins)
+}
+
+fn payments_from_json(payments_json: &str) -> Result<Vec<Payment>, ApiError> {
+    let payments: Vec<PaymentJson> =
+        serde_json::from_str(payments_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let mut primaries = Vec::new();
+    for payment in payments {
+        let mut memos = Vec::new();
+        for memo in payment.memos {
+            memos.push(hex_to_vec(&memo)?);
+        }
+        primaries.push(Payment {
+            puzzle_hash: payment.puzzle_hash,
+            amount: payment.amount,
+            memos,
+        });
+    }
+    Ok(primaries)
+}
+
+fn public_keys_from_hex(public_keys: &[String]) -> Result<Vec<Bytes48>, ApiError> {
+    let mut pks = Vec::new();
+    for pk_hex in public_keys {
+        let pk = public_key_from_vec(&hex_to_vec(pk_hex)?)?;
+        pks.push(Bytes48::from(pk.to_bytes().to_vec()));
+    }
+    Ok(pks)
+}
+
+fn standard_cats_from_json(cats_json: &str) -> Result<Vec<StandardCatCoin>, ApiError> {
+    let cats: Vec<StandardCatJson> =
+        serde_json::from_str(cats_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    standard_cats_from_parsed(cats)
+}
 
-pub fn secret_key_from_seed(seed: Vec<u8>) -> Vec<u8> {
-    let mut seed_array = [0u8; 64];
-    seed_array.copy_from_slice(&seed);
+fn standard_cats_from_parsed(cats: Vec<StandardCatJson>) -> Result<Vec<StandardCatCoin>, ApiError> {
+    let mut standard_cats = Vec::new();
+    for cat in cats {
+        standard_cats.push(StandardCatCoin {
+            coin: cat.coin,
+            public_key: public_key_from_vec(&hex_to_vec(&cat.public_key)?)?,
+            lineage_proof: cat.lineage_proof,
+        });
+    }
+    Ok(standard_cats)
+}
+
+fn offered_cats_from_json(cats_json: &str) -> Result<Vec<OfferedCat>, ApiError> {
+    let cats: Vec<OfferCatsJson> =
+        serde_json::from_str(cats_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let mut offered_cats = Vec::new();
+    for cat in cats {
+        offered_cats.push(OfferedCat {
+            tail_hash: cat.tail_hash,
+            coins: standard_cats_from_parsed(cat.coins)?,
+            amount: cat.amount,
+        });
+    }
+    Ok(offered_cats)
+}
+
+fn secret_keys_from_hex(secret_keys: &[String]) -> Result<Vec<SecretKey>, ApiError> {
+    let mut sks = Vec::new();
+    for sk_hex in secret_keys {
+        sks.push(secret_key_from_vec(&hex_to_vec(sk_hex)?)?);
+    }
+    Ok(sks)
+}
+
+fn sign_offer(offer: Offer, secret_keys: &[String], network: &str) -> Result<Offer, ApiError> {
+    let constants = constants_for_network(network)?;
+    let bundle = sign_coin_spends(
+        offer.bundle.coin_spends,
+        &secret_keys_from_hex(secret_keys)?,
+        constants,
+        INFINITE_COST,
+    )
+    .map_err(|e| ApiError::SigningFailed(e.to_string()))?;
+    Ok(Offer {
+        requested_payments: offer.requested_payments,
+        bundle,
+    })
+}
+
+fn offer_asset_key(asset_id: &AssetId) -> String {
+    match asset_id {
+        Some(tail_hash) => tail_hash.to_string(),
+        None => "xch".to_string(),
+    }
+}
+
+fn selection_strategy(strategy: &str) -> Result<SelectionStrategy, ApiError> {
+    match strategy {
+        "exact" => Ok(SelectionStrategy::Exact),
+        "smallest" => Ok(SelectionStrategy::SmallestSufficient),
+        "largest" => Ok(SelectionStrategy::LargestFirst),
+        "knapsack" => Ok(SelectionStrategy::Knapsack),
+        _ => Err(ApiError::UnknownSelectionStrategy(strategy.to_string())),
+    }
+}
+
+fn hex_to_vec(hex_str: &str) -> Result<Vec<u8>, ApiError> {
+    let cleaned = prep_hex_str(hex_str);
+    hex::decode(&cleaned).map_err(|e| ApiError::from_hex_error(e, cleaned.len()))
+}
+
+fn hidden_puzzle_hash_or_default(hidden_puzzle_hash: Option<Vec<u8>>) -> Result<Bytes32, ApiError> {
+    match hidden_puzzle_hash {
+        Some(hash) => Ok(Bytes32::from(
+            fixed_bytes::<32>(&hash, "hidden puzzle hash")?.to_vec(),
+        )),
+        None => Ok(default_hidden_puzzle().tree_hash()),
+    }
+}
+
+fn derivation_error(e: DerivationError) -> ApiError {
+    match e {
+        DerivationError::TooManyKeys { count, max } => ApiError::TooManyKeys { count, max },
+        DerivationError::IndexOverflow { start, count } => {
+            ApiError::InvalidIndexRange { start, count }
+        }
+    }
+}
+
+fn program_from_vec(ser_program_bytes: &Vec<u8>) -> Result<Program, ApiError> {
+    validate_serialized_program(ser_program_bytes).map_err(|(offset, e)| {
+        ApiError::InvalidClvm {
+            offset,
+            reason: e.to_string(),
+        }
+    })?;
+    Ok(Program::new(ser_program_bytes.clone()))
+}
+
+fn program_from_hex(hex: &String) -> Result<Program, ApiError> {
+    let cleaned = prep_hex_str(hex);
+    let bytes = hex::decode(&cleaned).map_err(|e| ApiError::from_hex_error(e, cleaned.len()))?;
+    program_from_vec(&bytes)
+}
+
+pub fn secret_key_from_seed(seed: Vec<u8>) -> Result<Vec<u8>> {
+    let seed_array = fixed_bytes::<64>(&seed, "seed")?;
     let sk = SecretKey::from_seed(&seed_array);
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn secret_key_public_key(sk: Vec<u8>) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&sk);
-    let sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn secret_key_public_key(sk: Vec<u8>) -> Result<Vec<u8>> {
+    let sk = secret_key_from_vec(&sk)?;
     let pk = sk.public_key();
-    pk.to_bytes().to_vec()
+    Ok(pk.to_bytes().to_vec())
 }
 
-pub fn secret_key_derive_path_hardened(sk: Vec<u8>, path: Vec<u32>) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&sk);
-    let mut sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn secret_key_derive_path_hardened(sk: Vec<u8>, path: Vec<u32>) -> Result<Vec<u8>> {
+    let mut sk = secret_key_from_vec(&sk)?;
     for idx in path {
         sk = sk.derive_hardened(idx);
     }
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn secret_key_derive_path_unhardened(sk: Vec<u8>, path: Vec<u32>) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&sk);
-    let mut sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn secret_key_derive_path_unhardened(sk: Vec<u8>, path: Vec<u32>) -> Result<Vec<u8>> {
+    let mut sk = secret_key_from_vec(&sk)?;
     for idx in path {
         sk = sk.derive_unhardened(idx);
     }
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn public_key_derive_path_unhardened(sk: Vec<u8>, path: Vec<u32>) -> Vec<u8> {
-    let mut sk_array = [0u8; 48];
-    sk_array.copy_from_slice(&sk);
-    let mut pk = PublicKey::from_bytes(&sk_array).unwrap();
+pub fn public_key_derive_path_unhardened(sk: Vec<u8>, path: Vec<u32>) -> Result<Vec<u8>> {
+    let mut pk = public_key_from_vec(&sk)?;
     for idx in path {
         pk = pk.derive_unhardened(idx);
     }
-    pk.to_bytes().to_vec()
+    Ok(pk.to_bytes().to_vec())
 }
 
-pub fn signature_sign(sk: Vec<u8>, msg: Vec<u8>) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&sk);
-    let sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn signature_sign(sk: Vec<u8>, msg: Vec<u8>) -> Result<Vec<u8>> {
+    let sk = secret_key_from_vec(&sk)?;
     let sig = sign(&sk, &msg);
-    sig.to_bytes().to_vec()
+    Ok(sig.to_bytes().to_vec())
 }
 
-pub fn signature_is_valid(sig: Vec<u8>) -> bool {
-    let mut sig_array = [0u8; 96];
-    sig_array.copy_from_slice(&sig);
-    let sig = signature::Signature::from_bytes(&sig_array).unwrap();
-    sig.is_valid()
+pub fn signature_is_valid(sig: Vec<u8>) -> Result<bool> {
+    let sig = signature_from_vec(&sig)?;
+    Ok(sig.is_valid())
 }
 
-pub fn signature_aggregate(sigs_stream: Vec<u8>, length: usize) -> Vec<u8> {
+pub fn signature_aggregate(sigs_stream: Vec<u8>, length: usize) -> Result<Vec<u8>> {
+    let expected = length.checked_mul(96).ok_or_else(|| {
+        ApiError::InvalidSignature(format!("{} signatures do not fit in memory", length))
+    })?;
+    if sigs_stream.len() != expected {
+        return Err(ApiError::InvalidLength {
+            kind: "signature stream",
+            expected,
+            got: sigs_stream.len(),
+        }
+        .into());
+    }
     let mut sigs = Vec::new();
     for i in 0..length {
-        let mut sig_array = [0u8; 96];
-        sig_array.copy_from_slice(&sigs_stream[i * 96..i * 96 + 96]);
-        let sig = signature::Signature::from_bytes(&sig_array).unwrap();
+        let sig = signature_from_vec(&sigs_stream[i * 96..i * 96 + 96])?;
         sigs.push(sig);
     }
     let sig = signature::aggregate(&sigs);
-    sig.to_bytes().to_vec()
+    Ok(sig.to_bytes().to_vec())
 }
 
-pub fn signature_verify(pk: Vec<u8>, msg: Vec<u8>, sig: Vec<u8>) -> bool {
-    let mut pk_array = [0u8; 48];
-    pk_array.copy_from_slice(&pk);
-    let public_key = PublicKey::from_bytes(&pk_array).unwrap();
-    let mut sig_array = [0u8; 96];
-    sig_array.copy_from_slice(&sig);
-    let sig = signature::Signature::from_bytes(&sig_array).unwrap();
-    signature::verify(&sig, &public_key, &msg)
+pub fn signature_verify(pk: Vec<u8>, msg: Vec<u8>, sig: Vec<u8>) -> Result<bool> {
+    let public_key = public_key_from_vec(&pk)?;
+    let sig = signature_from_vec(&sig)?;
+    Ok(signature::verify(&sig, &public_key, &msg))
 }
 
-pub fn pub_mnemonic_to_entropy(mnemonic_words: String) -> Vec<u8> {
-    let entropy = mnemonic_to_entropy(&mnemonic_words).unwrap();
-    entropy.to_vec()
+pub fn pub_mnemonic_to_entropy(mnemonic_words: String) -> Result<Vec<u8>> {
+    let entropy = mnemonic::mnemonic_to_entropy(&mnemonic_words).map_err(mnemonic_error)?;
+    Ok(entropy)
 }
 
-pub fn pub_entropy_to_mnemonic(entropy: Vec<u8>) -> String {
-    let mut entropy_array = [0u8; 32];
-    entropy_array.copy_from_slice(&entropy);
-    let mnemonic = entropy_to_mnemonic(&entropy_array);
-    mnemonic
+pub fn pub_entropy_to_mnemonic(entropy: Vec<u8>) -> Result<String> {
+    let mnemonic = mnemonic::entropy_to_mnemonic(&entropy).map_err(mnemonic_error)?;
+    Ok(mnemonic)
 }
-pub fn pub_entropy_to_seed(entropy: Vec<u8>) -> Vec<u8> {
-    let mut entropy_array = [0u8; 32];
-    entropy_array.copy_from_slice(&entropy);
-    let seed = entropy_to_seed(&entropy_array);
-    seed.to_vec()
+pub fn pub_entropy_to_seed(entropy: Vec<u8>) -> Result<Vec<u8>> {
+    let seed = mnemonic::entropy_to_seed(&entropy, "").map_err(mnemonic_error)?;
+    Ok(seed)
+}
+
+pub fn mnemonic_generate(word_count: u32) -> Result<String> {
+    let mnemonic = mnemonic::generate_mnemonic(word_count as usize).map_err(mnemonic_error)?;
+    Ok(mnemonic)
+}
+
+pub fn mnemonic_to_seed(mnemonic_words: String, passphrase: Option<String>) -> Result<Vec<u8>> {
+    let passphrase = passphrase.unwrap_or_default();
+    let seed = mnemonic::mnemonic_to_seed(&mnemonic_words, &passphrase).map_err(mnemonic_error)?;
+    Ok(seed)
+}
+
+pub fn mnemonic_validate(mnemonic_words: String) -> MnemonicValidation {
+    match mnemonic::parse_mnemonic(&mnemonic_words) {
+        Ok(_) => MnemonicValidation {
+            valid: true,
+            invalid_word_index: None,
+            checksum_failed: false,
+            error: None,
+        },
+        Err(e) => MnemonicValidation {
+            valid: false,
+            invalid_word_index: match e {
+                MnemonicError::InvalidWord { index, .. } => Some(index as u32),
+                _ => None,
+            },
+            checksum_failed: e == MnemonicError::InvalidChecksum,
+            error: Some(e.to_string()),
+        },
+    }
 }
 
 pub fn bytes_to_hex(bytes: Vec<u8>) -> String {
@@ -145,13 +720,8 @@ pub fn bytes_to_hex(bytes: Vec<u8>) -> String {
     hex
 }
 
-pub fn hex_to_bytes(hex: String) -> Vec<u8> {
-    let mut bytes = Vec::new();
-    for i in 0..hex.len() / 2 {
-        let byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
-        bytes.push(byte);
-    }
-    bytes
+pub fn hex_to_bytes(hex: String) -> Result<Vec<u8>> {
+    Ok(hex_to_vec(&hex)?)
 }
 
 pub fn bytes_to_sha256(bytes: Vec<u8>) -> Vec<u8> {
@@ -160,74 +730,68 @@ pub fn bytes_to_sha256(bytes: Vec<u8>) -> Vec<u8> {
     hasher.finalize().to_vec()
 }
 
-pub fn pub_master_to_wallet_unhardened_intermediate(master: Vec<u8>) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&master);
-    let master_sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn pub_master_to_wallet_unhardened_intermediate(master: Vec<u8>) -> Result<Vec<u8>> {
+    let master_sk = secret_key_from_vec(&master)?;
     let sk = derive_keys::master_to_wallet_unhardened_intermediate(&master_sk);
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn pub_master_to_wallet_unhardened(master: Vec<u8>, idx: u32) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&master);
-    let master_sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn pub_master_to_wallet_unhardened(master: Vec<u8>, idx: u32) -> Result<Vec<u8>> {
+    let master_sk = secret_key_from_vec(&master)?;
     let sk = derive_keys::master_to_wallet_unhardened(&master_sk, idx);
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn pub_master_to_wallet_hardened_intermediate(master: Vec<u8>) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&master);
-    let master_sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn pub_master_to_wallet_hardened_intermediate(master: Vec<u8>) -> Result<Vec<u8>> {
+    let master_sk = secret_key_from_vec(&master)?;
     let sk = derive_keys::master_to_wallet_hardened_intermediate(&master_sk);
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn pub_master_to_wallet_hardened(master: Vec<u8>, idx: u32) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&master);
-    let master_sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn pub_master_to_wallet_hardened(master: Vec<u8>, idx: u32) -> Result<Vec<u8>> {
+    let master_sk = secret_key_from_vec(&master)?;
     let sk = derive_keys::master_to_wallet_hardened(&master_sk, idx);
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn pub_master_to_pool_singleton(master: Vec<u8>, pool_wallet_idx: u32) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&master);
-    let master_sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn pub_master_to_pool_singleton(master: Vec<u8>, pool_wallet_idx: u32) -> Result<Vec<u8>> {
+    let master_sk = secret_key_from_vec(&master)?;
     let sk = derive_keys::master_to_pool_singleton(&master_sk, pool_wallet_idx);
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn pub_master_to_pool_authentication(sk: Vec<u8>, pool_wallet_idx: u32, idx: u32) -> Vec<u8> {
-    let mut sk_array = [0u8; 32];
-    sk_array.copy_from_slice(&sk);
-    let master_sk = SecretKey::from_bytes(&sk_array).unwrap();
+pub fn pub_master_to_pool_authentication(
+    sk: Vec<u8>,
+    pool_wallet_idx: u32,
+    idx: u32,
+) -> Result<Vec<u8>> {
+    let master_sk = secret_key_from_vec(&sk)?;
     let sk = derive_keys::master_to_pool_authentication(&master_sk, pool_wallet_idx, idx);
-    sk.to_bytes().to_vec()
+    Ok(sk.to_bytes().to_vec())
 }
 
-pub fn cmds_program_run(args: Vec<String>) -> String {
+pub fn cmds_program_run(args: Vec<String>) -> Result<String> {
     let mut s = Stream::new(None);
     let mut args = args;
     args.insert(0, "".to_string());
     launch_tool(&mut s, args.as_slice(), "run", 2);
     let result = s.get_value().data().to_vec();
-    String::from_utf8(result).unwrap()
+    let output = String::from_utf8(result).map_err(|e| ApiError::ToolFailed(e.to_string()))?;
+    Ok(output)
 }
 
-pub fn cmds_program_brun(args: Vec<String>) -> String {
+pub fn cmds_program_brun(args: Vec<String>) -> Result<String> {
     let mut s = Stream::new(None);
     let mut args = args;
     args.insert(0, "".to_string());
     launch_tool(&mut s, args.as_slice(), "brun", 0);
     let result = s.get_value().data().to_vec();
-    String::from_utf8(result).unwrap()
+    let output = String::from_utf8(result).map_err(|e| ApiError::ToolFailed(e.to_string()))?;
+    Ok(output)
 }
 
 // Compile a clvm script. convert to serialezed bytes
-pub fn cmd_program_opc(args: Vec<String>) -> Vec<String> {
+pub fn cmd_program_opc(args: Vec<String>) -> Result<Vec<String>> {
     let mut args = args;
     args.insert(0, "".to_string());
     let mut allocator = Allocator::new();
@@ -239,21 +803,15 @@ pub fn cmd_program_opc(args: Vec<String>) -> Vec<String> {
         args.as_slice(),
     );
     match call_result {
-        Ok(result) => result,
-        Err(e) => {
-            vec![format!("Error: {}", e)]
-        }
+        Ok(result) => Ok(result),
+        Err(e) => Err(ApiError::ToolFailed(e).into()),
     }
 }
 
 //Disassemble a compiled clvm script from hex.
-pub fn cmd_program_opd(args: Vec<String>) -> Vec<String> {
+pub fn cmd_program_opd(args: Vec<String>) -> Result<Vec<String>> {
     let mut args = args;
     args.insert(0, "".to_string());
-    let mut args_str = String::new();
-    for arg in args.iter() {
-        args_str.push_str(arg);
-    }
     let mut allocator = Allocator::new();
     let call_result = call_tool_with_return(
         &mut allocator,
@@ -263,23 +821,18 @@ pub fn cmd_program_opd(args: Vec<String>) -> Vec<String> {
         args.as_slice(),
     );
     match call_result {
-        Ok(result) => {
-            let copy = result.clone();
-            copy
-        }
-        Err(e) => {
-            vec![format!("Error: {}", e)]
-        }
+        Ok(result) => Ok(result),
+        Err(e) => Err(ApiError::ToolFailed(e).into()),
     }
 }
 
-pub fn cmd_program_cldb(args: Vec<String>) -> String {
+pub fn cmd_program_cldb(args: Vec<String>) -> Result<String> {
     let yamlette_string = |to_print: Vec<BTreeMap<String, String>>| {
         let mut result = String::new();
         let mut emitter = YamlEmitter::new(&mut result);
         match emitter.dump(&to_yaml(&to_print)) {
-            Ok(_) => result,
-            Err(e) => format!("error producing yaml: {e:?}"),
+            Ok(_) => Ok(result),
+            Err(e) => Err(ApiError::ToolFailed(format!("error producing yaml: {e:?}"))),
         }
     };
     let mut args = args;
@@ -287,118 +840,1540 @@ pub fn cmd_program_cldb(args: Vec<String>) -> String {
 
     let call_result = cldb_with_return(args.as_slice());
     match call_result {
-        Ok(result) => format!("{}", yamlette_string(result)),
-        Err(e) => {
-            format!("Error: {}", e)
-        }
+        Ok(result) => Ok(yamlette_string(result)?),
+        Err(e) => Err(ApiError::ToolFailed(e.to_string()).into()),
     }
 }
 
-pub fn program_tree_hash(ser_program_bytes: Vec<u8>) -> [u8; 32] {
-    let program = SerializedProgram::from_bytes(&ser_program_bytes)
-        .to_program()
-        .unwrap();
-    return program.tree_hash().to_sized_bytes();
+pub fn program_tree_hash(ser_program_bytes: Vec<u8>) -> Result<[u8; 32]> {
+    let program = program_from_vec(&ser_program_bytes)?;
+    Ok(program.tree_hash().to_sized_bytes())
 }
 
-pub fn program_curry(ser_program_bytes: Vec<u8>, args_str: Vec<String>) -> Vec<u8> {
-    let args = args_str;
+pub fn program_curry(ser_program_bytes: Vec<u8>, args_str: Vec<String>) -> Result<Vec<u8>> {
     let mut args_vec: Vec<Program> = Vec::new();
-    for arg in args {
-        let program_serialized = SerializedProgram::from(arg.to_string());
-        args_vec.push(program_serialized.to_program().unwrap());
-    }
-    let raw_program = SerializedProgram::from_bytes(&ser_program_bytes)
-        .to_program()
-        .unwrap();
-    let curried = curry(&raw_program, args_vec);
-    let (_cost, program_result) = curried.unwrap();
-    program_result.serialized.clone()
-}
-
-pub fn program_uncurry(ser_program_bytes: Vec<u8>) -> UncurriedProgramToDart {
-    let raw_program = SerializedProgram::from_bytes(&ser_program_bytes)
-        .to_program()
-        .unwrap();
-    let uncurried = raw_program.uncurry();
+    for arg in args_str {
+        args_vec.push(program_from_hex(&arg)?);
+    }
+    let raw_program = program_from_vec(&ser_program_bytes)?;
+    let (_cost, program_result) =
+        curry(&raw_program, args_vec).map_err(|e| ApiError::ProgramFailed(e.to_string()))?;
+    Ok(program_result.serialized.clone())
+}
+
+pub fn program_uncurry(ser_program_bytes: Vec<u8>) -> Result<UncurriedProgramToDart> {
+    let raw_program = program_from_vec(&ser_program_bytes)?;
+    let uncurried = raw_program
+        .try_uncurry()
+        .ok_or(ApiError::ProgramNotCurried)?;
     let program_bytes = uncurried.program.serialized.clone();
     let mut args = Vec::new();
     for arg in uncurried.args {
         args.push(bytes_to_hex(arg.serialized.clone()));
     }
-    UncurriedProgramToDart {
+    Ok(UncurriedProgramToDart {
         program: program_bytes,
         args: args,
-        error: "".to_string(),
-    }
+    })
 }
 
-pub fn program_from_list(program_list: Vec<String>) -> Vec<u8> {
+pub fn program_from_list(program_list: Vec<String>) -> Result<Vec<u8>> {
     let mut actual = Program::null();
     for element in program_list {
-        let program = SerializedProgram::from(element).to_program().unwrap();
+        let program = program_from_hex(&element)?;
         actual = actual.cons(&program);
     }
-    actual.serialized.clone()
+    Ok(actual.serialized.clone())
 }
 
-pub fn program_disassemble(ser_program_bytes: Vec<u8>) -> String {
-    let mut stream = Stream::new(Some(Bytes::new(Some(BytesFromType::Raw(ser_program_bytes)))));
-    let mut allocator = Allocator::new();
-
-    let result = sexp_from_stream(
-        &mut allocator,
-        &mut stream,
-        Box::new(SimpleCreateCLVMObject {}),
-    )
-    .map_err(|e| e.1)
-    .map(|sexp| {
-        let disassembled = disassemble(&mut allocator, sexp.1);
-        t(sexp.1, disassembled)
-    })
-    .unwrap();
-
-    result.rest().to_string()
+pub fn program_disassemble(ser_program_bytes: Vec<u8>) -> Result<String> {
+    let program = program_from_vec(&ser_program_bytes)?;
+    Ok(program.disassemble())
 }
 
-pub fn program_run(ser_program_bytes: Vec<u8>, args_str: Vec<String>) -> ApiOutputProgram {
-    let args = args_str;
+pub fn program_run(ser_program_bytes: Vec<u8>, args_str: Vec<String>) -> Result<ApiOutputProgram> {
     let mut args_vec: Vec<Program> = Vec::new();
-    for arg in args {
-        let program_serialized = SerializedProgram::from(arg.to_string());
-        args_vec.push(program_serialized.to_program().unwrap());
+    for arg in args_str {
+        args_vec.push(program_from_hex(&arg)?);
     }
-    let raw_program = SerializedProgram::from_bytes(&ser_program_bytes)
-        .to_program()
-        .unwrap();
+    let raw_program = program_from_vec(&ser_program_bytes)?;
     let args_program = Program::from(args_vec);
-    let run_result = raw_program.run(args_program);
-    ApiOutputProgram {
+    let run_result = raw_program
+        .run(args_program)
+        .map_err(|e| ApiError::ProgramFailed(e.to_string()))?;
+    Ok(ApiOutputProgram {
         program: run_result.program.serialized.clone(),
         cost: run_result.cost,
-    }
+    })
 }
 pub fn program_from_atom_bytes(ser_program_bytes: Vec<u8>) -> Vec<u8> {
     let program = Program::from(&ser_program_bytes);
     program.serialized.clone()
 }
-pub fn program_to_atom_bytes(ser_program_bytes: Vec<u8>) -> Vec<u8> {
-    let program = SerializedProgram::from_bytes(&ser_program_bytes)
-        .to_program()
-        .unwrap();
-    let atom = program.as_atom().unwrap();
-    atom.serialized.clone()
+pub fn program_to_atom_bytes(ser_program_bytes: Vec<u8>) -> Result<Vec<u8>> {
+    let program = program_from_vec(&ser_program_bytes)?;
+    let atom = program.as_atom().ok_or(ApiError::ProgramNotAtom)?;
+    Ok(atom.serialized.clone())
 }
 
-pub fn get_puzzle_from_public_key(pk: Vec<u8>) -> Vec<u8> {
-    let mut pk_array = [0u8; 48];
-    pk_array.copy_from_slice(&pk);
-    let public_key = PublicKey::from_bytes(&pk_array.clone()).unwrap();
+pub fn get_puzzle_from_public_key(pk: Vec<u8>) -> Result<Vec<u8>> {
+    let public_key = public_key_from_vec(&pk)?;
     let program = get_puzzle_from_pk(public_key);
-    program.serialized.clone()
+    Ok(program.serialized.clone())
+}
+
+// Synthetic keys for the standard puzzle; the default hidden puzzle is used when
+// `hidden_puzzle_hash` is None.
+pub fn pub_calculate_synthetic_secret_key(
+    sk: Vec<u8>,
+    hidden_puzzle_hash: Option<Vec<u8>>,
+) -> Result<Vec<u8>> {
+    let secret_key = secret_key_from_vec(&sk)?;
+    let hidden_puzzle_hash = hidden_puzzle_hash_or_default(hidden_puzzle_hash)?;
+    let synthetic_sk = calculate_synthetic_secret_key(&secret_key, &hidden_puzzle_hash);
+    Ok(synthetic_sk.to_bytes().to_vec())
+}
+
+pub fn pub_calculate_synthetic_public_key(
+    pk: Vec<u8>,
+    hidden_puzzle_hash: Option<Vec<u8>>,
+) -> Result<Vec<u8>> {
+    let public_key = public_key_from_vec(&pk)?;
+    let hidden_puzzle_hash = hidden_puzzle_hash_or_default(hidden_puzzle_hash)?;
+    let synthetic_pk = calculate_synthetic_public_key(&public_key, &hidden_puzzle_hash);
+    Ok(synthetic_pk.to_bytes().to_vec())
 }
 
-pub fn cats_create_cat_puzzle(tail_hash: Vec<u8>, inner_puzzle_hash: Vec<u8>) -> Vec<u8> {
+pub fn cats_create_cat_puzzle(tail_hash: Vec<u8>, inner_puzzle_hash: Vec<u8>) -> Result<Vec<u8>> {
+    fixed_bytes::<32>(&tail_hash, "tail hash")?;
+    fixed_bytes::<32>(&inner_puzzle_hash, "inner puzzle hash")?;
     let program = create_cat_puzzle(tail_hash.clone(), inner_puzzle_hash.clone());
-    program.serialized.clone()
+    Ok(program.serialized.clone())
+}
+
+fn network_from_prefix(prefix: String) -> Result<NetworkInfo, ApiError> {
+    let valid_char = |b: u8| (33..=126).contains(&b) && b != b'1';
+    if prefix.is_empty() || !prefix.bytes().all(valid_char) {
+        let reason = format!("invalid prefix {:?}", prefix);
+        return Err(ApiError::InvalidAddress(reason));
+    }
+    Ok(NetworkInfo {
+        network_name: prefix.clone(),
+        network_prefix: prefix.to_lowercase(),
+    })
+}
+
+pub fn puzzle_hash_to_address(puzzle_hash: Vec<u8>, prefix: String) -> Result<String> {
+    let puzzle_hash = fixed_bytes::<32>(&puzzle_hash, "puzzle hash")?;
+    let network = network_from_prefix(prefix)?;
+    Ok(network.puzzle_hash_to_address(&Bytes32::from(puzzle_hash.to_vec())))
+}
+
+pub fn address_to_puzzle_hash(address: String, prefix: String) -> Result<Vec<u8>> {
+    let network = network_from_prefix(prefix)?;
+    let puzzle_hash = network
+        .address_to_puzzle_hash(&address)
+        .map_err(|e| ApiError::InvalidAddress(e.to_string()))?;
+    Ok(puzzle_hash.bytes)
+}
+
+fn derived_to_dart(records: Vec<DerivationRecord>) -> Vec<DerivedPuzzleHash> {
+    records
+        .into_iter()
+        .map(|record| DerivedPuzzleHash {
+            index: record.index,
+            public_key: record.public_key.to_bytes().to_vec(),
+            puzzle_hash: record.puzzle_hash.bytes,
+            hardened: record.hardened,
+        })
+        .collect()
+}
+
+// Unhardened standard puzzle hashes for `count` indexes from a master public key,
+// or from the m/12381/8444/2 intermediate key when `from_intermediate` is set.
+pub fn derive_puzzle_hashes_from_public_key(
+    pk: Vec<u8>,
+    from_intermediate: bool,
+    start: u32,
+    count: u32,
+) -> Result<Vec<DerivedPuzzleHash>> {
+    let mut intermediate = public_key_from_vec(&pk)?;
+    if !from_intermediate {
+        intermediate = master_pk_to_wallet_unhardened_intermediate(&intermediate);
+    }
+    let records =
+        derive_unhardened_puzzle_hashes(&intermediate, start, count).map_err(derivation_error)?;
+    Ok(derived_to_dart(records))
+}
+
+// Unhardened and hardened standard puzzle hashes for `count` indexes from a master secret key.
+pub fn derive_puzzle_hashes_from_secret_key(
+    sk: Vec<u8>,
+    start: u32,
+    count: u32,
+) -> Result<Vec<DerivedPuzzleHash>> {
+    let master_sk = secret_key_from_vec(&sk)?;
+    let records =
+        derive_puzzle_hashes_from_master_sk(&master_sk, start, count).map_err(derivation_error)?;
+    Ok(derived_to_dart(records))
+}
+
+fn keyring_error(e: KeyringError) -> ApiError {
+    ApiError::Keyring(e.to_string())
+}
+
+fn open_keyring(path: &str, passphrase: &str) -> Result<Keyring, ApiError> {
+    Keyring::open(Path::new(path), passphrase).map_err(keyring_error)
+}
+
+pub fn keyring_create(path: String, passphrase: String, hint: Option<String>) -> Result<()> {
+    Keyring::create(Path::new(&path), &passphrase, hint).map_err(keyring_error)?;
+    Ok(())
+}
+
+pub fn keyring_passphrase_hint(path: String) -> Result<Option<String>> {
+    let hint = Keyring::passphrase_hint(Path::new(&path)).map_err(keyring_error)?;
+    Ok(hint)
+}
+
+pub fn keyring_list_keys(path: String, passphrase: String) -> Result<Vec<KeyringKey>> {
+    let keyring = open_keyring(&path, &passphrase)?;
+    let mut keys = Vec::new();
+    for entry in keyring.keys() {
+        let sk = entry.secret_key().map_err(keyring_error)?;
+        keys.push(KeyringKey {
+            fingerprint: entry.fingerprint,
+            label: entry.label.clone(),
+            public_key: sk.public_key().to_bytes().to_vec(),
+            has_mnemonic: entry.mnemonic.is_some(),
+        });
+    }
+    Ok(keys)
+}
+
+pub fn keyring_add_mnemonic(
+    path: String,
+    passphrase: String,
+    mnemonic: String,
+    label: Option<String>,
+) -> Result<u32> {
+    let mut keyring = open_keyring(&path, &passphrase)?;
+    let fingerprint = keyring
+        .add_mnemonic(&mnemonic, label)
+        .map_err(keyring_error)?;
+    Ok(fingerprint)
+}
+
+pub fn keyring_add_secret_key(
+    path: String,
+    passphrase: String,
+    sk: Vec<u8>,
+    label: Option<String>,
+) -> Result<u32> {
+    let sk = secret_key_from_vec(&sk)?;
+    let mut keyring = open_keyring(&path, &passphrase)?;
+    let fingerprint = keyring.add_secret_key(&sk, label).map_err(keyring_error)?;
+    Ok(fingerprint)
+}
+
+pub fn keyring_get_secret_key(
+    path: String,
+    passphrase: String,
+    fingerprint: u32,
+) -> Result<Vec<u8>> {
+    let keyring = open_keyring(&path, &passphrase)?;
+    let entry = keyring.get(fingerprint).map_err(keyring_error)?;
+    let sk = entry.secret_key().map_err(keyring_error)?;
+    Ok(sk.to_bytes().to_vec())
+}
+
+pub fn keyring_export_mnemonic(
+    path: String,
+    passphrase: String,
+    fingerprint: u32,
+) -> Result<String> {
+    let keyring = open_keyring(&path, &passphrase)?;
+    let mnemonic = keyring
+        .export_mnemonic(fingerprint)
+        .map_err(keyring_error)?;
+    Ok(mnemonic)
+}
+
+pub fn keyring_set_label(
+    path: String,
+    passphrase: String,
+    fingerprint: u32,
+    label: Option<String>,
+) -> Result<()> {
+    let mut keyring = open_keyring(&path, &passphrase)?;
+    keyring
+        .set_label(fingerprint, label)
+        .map_err(keyring_error)?;
+    Ok(())
+}
+
+pub fn keyring_remove_key(path: String, passphrase: String, fingerprint: u32) -> Result<()> {
+    let mut keyring = open_keyring(&path, &passphrase)?;
+    keyring.remove(fingerprint).map_err(keyring_error)?;
+    Ok(())
+}
+
+pub fn keyring_change_passphrase(
+    path: String,
+    current_passphrase: String,
+    new_passphrase: String,
+    hint: Option<String>,
+) -> Result<()> {
+    let mut keyring = open_keyring(&path, &current_passphrase)?;
+    keyring
+        .change_passphrase(&current_passphrase, &new_passphrase, hint)
+        .map_err(keyring_error)?;
+    Ok(())
+}
+
+fn key_info_to_dart(info: KeyInfo) -> ApiKeyInfo {
+    ApiKeyInfo {
+        fingerprint: info.fingerprint,
+        master_public_key: info.master_public_key.to_bytes().to_vec(),
+        farmer_public_key: info.farmer_public_key.map(|pk| pk.to_bytes().to_vec()),
+        pool_public_key: info.pool_public_key.map(|pk| pk.to_bytes().to_vec()),
+        first_wallet_address: info.first_wallet_address,
+    }
+}
+
+pub fn public_key_fingerprint(pk: Vec<u8>) -> Result<u32> {
+    let public_key = public_key_from_vec(&pk)?;
+    Ok(fingerprint(&public_key))
+}
+
+pub fn key_info_from_secret_key(sk: Vec<u8>, prefix: String) -> Result<ApiKeyInfo> {
+    let sk = secret_key_from_vec(&sk)?;
+    let network = network_from_prefix(prefix)?;
+    Ok(key_info_to_dart(KeyInfo::from_secret_key(&sk, &network)))
+}
+
+pub fn key_info_from_public_key(pk: Vec<u8>, prefix: String) -> Result<ApiKeyInfo> {
+    let pk = public_key_from_vec(&pk)?;
+    let network = network_from_prefix(prefix)?;
+    Ok(key_info_to_dart(KeyInfo::from_public_key(&pk, &network)))
+}
+
+pub fn key_info_from_mnemonic(
+    mnemonic_words: String,
+    passphrase: Option<String>,
+    prefix: String,
+) -> Result<ApiKeyInfo> {
+    let passphrase = passphrase.unwrap_or_default();
+    let sk =
+        mnemonic::secret_key_from_mnemonic(&mnemonic_words, &passphrase).map_err(mnemonic_error)?;
+    let network = network_from_prefix(prefix)?;
+    Ok(key_info_to_dart(KeyInfo::from_secret_key(&sk, &network)))
+}
+
+// Signs every AGG_SIG_ME/AGG_SIG_UNSAFE condition of a JSON spend bundle with the
+// given hex secret keys (or their synthetic keys) and returns the signed bundle as JSON.
+pub fn spend_bundle_sign(
+    spend_bundle_json: String,
+    secret_keys: Vec<String>,
+    network: String,
+) -> Result<String> {
+    let spend_bundle = spend_bundle_from_json(&spend_bundle_json)?;
+    let constants = constants_for_network(&network)?;
+    let sks = secret_keys_from_hex(&secret_keys)?;
+    let signed = sign_coin_spends(spend_bundle.coin_spends, &sks, constants, INFINITE_COST)
+        .map_err(|e| ApiError::SigningFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&signed)?)
+}
+
+pub fn spend_bundle_verify_signature(
+    spend_bundle_json: String,
+    network: String,
+) -> Result<SignatureVerification> {
+    let spend_bundle = spend_bundle_from_json(&spend_bundle_json)?;
+    let constants = constants_for_network(&network)?;
+    let verification = match spend_bundle.verify_signature(constants) {
+        Ok(()) => SignatureVerification {
+            valid: true,
+            coin_spend_index: None,
+            coin_name: None,
+            error: None,
+        },
+        Err(e) => SignatureVerification {
+            valid: false,
+            coin_spend_index: e.coin_spend().map(|(index, _)| index as u32),
+            coin_name: e.coin_spend().map(|(_, coin_name)| coin_name.bytes.clone()),
+            error: Some(e.to_string()),
+        },
+    };
+    Ok(verification)
+}
+
+// Builds an unsigned standard spend bundle. `coins_json` is a list of
+// {"coin": {...}, "public_key": hex} and `payments_json` a list of
+// {"puzzle_hash": hex, "amount": u64, "memos": [hex]}; change goes to
+// `change_puzzle_hash`.
+pub fn standard_transaction_create(
+    coins_json: String,
+    payments_json: String,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let primaries = payments_from_json(&payments_json)?;
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let spend_bundle =
+        create_standard_spend_bundle(&spendable_coins, &primaries, fee, &change_puzzle_hash)
+            .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// Selects coins worth at least `amount` from a JSON list of coin records, skipping
+// spent coins and `excluded_coin_ids` (hex). `strategy` is one of "exact",
+// "smallest", "largest" or "knapsack". Returns the selected coins as JSON.
+pub fn coin_selection_select(
+    coin_records_json: String,
+    amount: u64,
+    strategy: String,
+    max_coins: u32,
+    min_coin_amount: u64,
+    excluded_coin_ids: Vec<String>,
+) -> Result<String> {
+    let records: Vec<CoinRecord> = serde_json::from_str(&coin_records_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let strategy = selection_strategy(&strategy)?;
+    let mut config = CoinSelectionConfig {
+        max_coins: max_coins as usize,
+        min_coin_amount,
+        ..Default::default()
+    };
+    for coin_id in excluded_coin_ids {
+        let coin_id = fixed_bytes::<32>(&hex_to_vec(&coin_id)?, "coin id")?;
+        config
+            .excluded_coin_ids
+            .insert(Bytes32::from(coin_id.to_vec()));
+    }
+    let coins = select_coins(&records, amount, strategy, &config)
+        .map_err(|e| ApiError::CoinSelectionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coins)?)
+}
+
+// Builds the unsigned ring of CAT2 spends for a JSON list of
+// {"coin", "tail_hash", "inner_puzzle" hex, "inner_solution" hex,
+// "lineage_proof": {"parent_name", "inner_puzzle_hash", "amount"}, "extra_delta"}.
+pub fn cat_spend_bundle_create(spendable_cats_json: String) -> Result<String> {
+    let cats: Vec<SpendableCatJson> = serde_json::from_str(&spendable_cats_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let mut spendable_cats = Vec::new();
+    for cat in cats {
+        spendable_cats.push(SpendableCat {
+            coin: cat.coin,
+            limitations_program_hash: cat.tail_hash,
+            inner_puzzle: program_from_hex(&cat.inner_puzzle)?,
+            inner_solution: program_from_hex(&cat.inner_solution)?,
+            lineage_proof: cat.lineage_proof,
+            extra_delta: cat.extra_delta,
+        });
+    }
+    let spend_bundle = unsigned_spend_bundle_for_spendable_cats(&spendable_cats)
+        .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// Checks whether `child_coin_json` is a CAT by looking at its parent's coin spend,
+// returning its TAIL hash, inner puzzle hash and the lineage proof to spend it.
+pub fn cat_parse_child(
+    parent_coin_spend_json: String,
+    child_coin_json: String,
+) -> Result<ApiCatInfo> {
+    let parent_spend: CoinSpend = serde_json::from_str(&parent_coin_spend_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let child: Coin =
+        serde_json::from_str(&child_coin_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let info = parse_cat_from_parent(&parent_spend, &child)
+        .map_err(|e| ApiError::ProgramFailed(e.to_string()))?;
+    Ok(match info {
+        Some(info) => ApiCatInfo {
+            is_cat: true,
+            is_cat1: info.version == CatVersion::Cat1,
+            tail_hash: Some(info.tail_hash.bytes),
+            parent_inner_puzzle: Some(info.parent_inner_puzzle.serialized),
+            inner_puzzle_hash: Some(info.inner_puzzle_hash.bytes),
+            lineage_proof: Some(serde_json::to_string(&info.lineage_proof)?),
+        },
+        None => ApiCatInfo {
+            is_cat: false,
+            is_cat1: false,
+            tail_hash: None,
+            parent_inner_puzzle: None,
+            inner_puzzle_hash: None,
+            lineage_proof: None,
+        },
+    })
+}
+
+// Returns the serialized TAIL for `kind` ("genesis_by_coin_id",
+// "everything_with_signature" or "delegated_tail"), curried with `argument`
+// (a genesis coin id or a public key). Its tree hash is the asset id.
+pub fn cat_tail_puzzle(kind: String, argument: Vec<u8>) -> Result<Vec<u8>> {
+    Ok(tail_from_kind(&kind, &argument)?.puzzle().serialized)
+}
+
+// Issues a new CAT from standard XCH coins (JSON as in `standard_transaction_create`)
+// and returns the unsigned spend bundle, including the eve CAT spend, as JSON.
+#[allow(clippy::too_many_arguments)]
+pub fn cat_issuance_create(
+    coins_json: String,
+    tail_kind: String,
+    tail_argument: Vec<u8>,
+    tail_solution: Vec<u8>,
+    inner_public_key: Vec<u8>,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let tail = tail_from_kind(&tail_kind, &tail_argument)?;
+    let tail_solution = program_or_nil_from_vec(&tail_solution)?;
+    let inner_public_key = public_key_from_vec(&inner_public_key)?;
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let spend_bundle = create_cat_issuance_bundle(
+        &spendable_coins,
+        &tail,
+        &tail_solution,
+        &inner_public_key,
+        amount,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// Melts `melt_amount` mojos from a JSON list of {"coin", "public_key" hex,
+// "lineage_proof"} standard CAT coins, sending the rest to `output_inner_puzzle_hash`.
+pub fn cat_melt_create(
+    cats_json: String,
+    tail_kind: String,
+    tail_argument: Vec<u8>,
+    tail_solution: Vec<u8>,
+    melt_amount: u64,
+    output_inner_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let cats = standard_cats_from_json(&cats_json)?;
+    let tail = tail_from_kind(&tail_kind, &tail_argument)?;
+    let tail_solution = program_or_nil_from_vec(&tail_solution)?;
+    let output_inner_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&output_inner_puzzle_hash, "inner puzzle hash")?.to_vec());
+    let spend_bundle = create_cat_melt_bundle(
+        &cats,
+        &tail,
+        &tail_solution,
+        melt_amount,
+        &output_inner_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// Like `cat_melt_create`, but mints `reissue_amount` more mojos into the output coin.
+pub fn cat_reissue_create(
+    cats_json: String,
+    tail_kind: String,
+    tail_argument: Vec<u8>,
+    tail_solution: Vec<u8>,
+    reissue_amount: u64,
+    output_inner_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let cats = standard_cats_from_json(&cats_json)?;
+    let tail = tail_from_kind(&tail_kind, &tail_argument)?;
+    let tail_solution = program_or_nil_from_vec(&tail_solution)?;
+    let output_inner_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&output_inner_puzzle_hash, "inner puzzle hash")?.to_vec());
+    let spend_bundle = create_cat_reissue_bundle(
+        &cats,
+        &tail,
+        &tail_solution,
+        reissue_amount,
+        &output_inner_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// Launches a singleton with `inner_puzzle` from standard XCH coins (JSON as in
+// `standard_transaction_create`). The first coin is the launcher's parent and
+// `amount` must be odd. `key_value_list` is a serialized program, empty for nil.
+pub fn singleton_launch_create(
+    coins_json: String,
+    inner_puzzle: Vec<u8>,
+    key_value_list: Vec<u8>,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<ApiSingletonLaunch> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let inner_puzzle = program_from_vec(&inner_puzzle)?;
+    let key_value_list = program_or_nil_from_vec(&key_value_list)?;
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let (launcher_id, spend_bundle) = create_singleton_launch_bundle(
+        &spendable_coins,
+        &inner_puzzle,
+        &key_value_list,
+        amount,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(ApiSingletonLaunch {
+        launcher_id: launcher_id.bytes,
+        spend_bundle: serde_json::to_string(&spend_bundle)?,
+    })
+}
+
+// Returns the serialized singleton puzzle wrapping `inner_puzzle`.
+pub fn singleton_puzzle(launcher_id: Vec<u8>, inner_puzzle: Vec<u8>) -> Result<Vec<u8>> {
+    let launcher_id = Bytes32::from(fixed_bytes::<32>(&launcher_id, "launcher id")?.to_vec());
+    let inner_puzzle = program_from_vec(&inner_puzzle)?;
+    Ok(puzzle_for_singleton(&launcher_id, &inner_puzzle).serialized)
+}
+
+// Returns, as JSON, the lineage proof for spending a child of `parent_coin_spend_json`.
+// For the eve spend the parent is the launcher.
+pub fn singleton_lineage_proof(parent_coin_spend_json: String) -> Result<String> {
+    let parent_spend: CoinSpend = serde_json::from_str(&parent_coin_spend_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let lineage_proof = lineage_proof_for_coin_spend(&parent_spend)
+        .map_err(|e| ApiError::ProgramFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&lineage_proof)?)
+}
+
+// Builds the coin spend for the singleton `coin_json` with its inner puzzle and
+// solution, returned as JSON.
+pub fn singleton_spend_create(
+    coin_json: String,
+    launcher_id: Vec<u8>,
+    inner_puzzle: Vec<u8>,
+    inner_solution: Vec<u8>,
+    lineage_proof_json: String,
+) -> Result<String> {
+    let coin: Coin =
+        serde_json::from_str(&coin_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let lineage_proof: LineageProof = serde_json::from_str(&lineage_proof_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let launcher_id = Bytes32::from(fixed_bytes::<32>(&launcher_id, "launcher id")?.to_vec());
+    let coin_spend = spend_singleton(
+        &coin,
+        &launcher_id,
+        &program_from_vec(&inner_puzzle)?,
+        &program_from_vec(&inner_solution)?,
+        &lineage_proof,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// Checks whether `puzzle` is a singleton top layer v1.1 puzzle, returning its launcher
+// id and inner puzzle.
+pub fn singleton_parse(puzzle: Vec<u8>) -> Result<ApiSingletonInfo> {
+    let puzzle = program_from_vec(&puzzle)?;
+    Ok(match match_singleton_puzzle(&puzzle) {
+        Some((launcher_id, inner_puzzle)) => ApiSingletonInfo {
+            is_singleton: true,
+            launcher_id: Some(launcher_id.bytes),
+            inner_puzzle: Some(inner_puzzle.serialized),
+        },
+        None => ApiSingletonInfo {
+            is_singleton: false,
+            launcher_id: None,
+            inner_puzzle: None,
+        },
+    })
+}
+
+// Mints an NFT1 from standard XCH coins (JSON as in `standard_transaction_create`).
+// `mint_json` is {"metadata": {"data_uris", "data_hash", "metadata_uris",
+// "metadata_hash", "license_uris", "license_hash", "edition_number", "edition_total"},
+// "royalty_puzzle_hash", "royalty_percentage" (basis points), "minter_public_key" hex,
+// "target_puzzle_hash", "did_owner": {"did_id", "did_inner_puzzle_hash"} or null}.
+pub fn nft_mint_create(
+    coins_json: String,
+    mint_json: String,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<ApiSingletonLaunch> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let mint: NftMintJson =
+        serde_json::from_str(&mint_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let mint = NftMint {
+        metadata: mint.metadata,
+        royalty_puzzle_hash: mint.royalty_puzzle_hash,
+        royalty_percentage: mint.royalty_percentage,
+        minter_public_key: public_key_from_vec(&hex_to_vec(&mint.minter_public_key)?)?,
+        target_puzzle_hash: mint.target_puzzle_hash,
+        did_owner: mint.did_owner,
+    };
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let (launcher_id, spend_bundle) =
+        create_nft_mint_bundle(&spendable_coins, &mint, fee, &change_puzzle_hash)
+            .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(ApiSingletonLaunch {
+        launcher_id: launcher_id.bytes,
+        spend_bundle: serde_json::to_string(&spend_bundle)?,
+    })
+}
+
+// Builds the coin spend sending the NFT `coin_json`, whose current puzzle reveal is
+// `nft_puzzle` and whose p2 puzzle is the standard puzzle of `public_key`, to
+// `new_puzzle_hash`. `new_did_owner_json` leaves the owner DID unchanged when absent,
+// clears it when "null" and otherwise is {"did_id", "did_inner_puzzle_hash"}.
+pub fn nft_transfer_create(
+    coin_json: String,
+    nft_puzzle: Vec<u8>,
+    public_key: Vec<u8>,
+    lineage_proof_json: String,
+    new_puzzle_hash: Vec<u8>,
+    new_did_owner_json: Option<String>,
+) -> Result<String> {
+    let coin: Coin =
+        serde_json::from_str(&coin_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let lineage_proof: LineageProof = serde_json::from_str(&lineage_proof_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let (info, _p2_puzzle) = parse_nft_puzzle(&program_from_vec(&nft_puzzle)?)
+        .ok_or_else(|| ApiError::ProgramFailed("puzzle is not an NFT1".to_string()))?;
+    let owner_change = match new_did_owner_json {
+        None => OwnerChange::Keep,
+        Some(json) => {
+            let owner: Option<DidOwner> =
+                serde_json::from_str(&json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+            match owner {
+                Some(owner) => OwnerChange::Assign(owner),
+                None => OwnerChange::Unassign,
+            }
+        }
+    };
+    let new_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&new_puzzle_hash, "puzzle hash")?.to_vec());
+    let coin_spend = create_nft_transfer_spend(
+        &coin,
+        &info,
+        &public_key_from_vec(&public_key)?,
+        &lineage_proof,
+        &new_puzzle_hash,
+        &owner_change,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// Checks whether `puzzle` is an NFT1 puzzle reveal, returning its launcher id, owner
+// DID, metadata and royalty settings.
+pub fn nft_parse(puzzle: Vec<u8>) -> Result<ApiNftInfo> {
+    let puzzle = program_from_vec(&puzzle)?;
+    Ok(match parse_nft_puzzle(&puzzle) {
+        Some((info, p2_puzzle)) => ApiNftInfo {
+            is_nft: true,
+            launcher_id: Some(info.launcher_id.bytes),
+            metadata_json: match NftMetadata::from_program(&info.metadata) {
+                Ok(metadata) => Some(serde_json::to_string(&metadata)?),
+                Err(_) => None,
+            },
+            metadata: Some(info.metadata.serialized),
+            metadata_updater_puzzle_hash: Some(info.metadata_updater_puzzle_hash.bytes),
+            current_owner: info.current_owner.map(|did_id| did_id.bytes),
+            royalty_puzzle_hash: Some(info.royalty_puzzle_hash.bytes),
+            royalty_percentage: Some(info.royalty_percentage as u32),
+            p2_puzzle_hash: Some(p2_puzzle.tree_hash().bytes),
+        },
+        None => ApiNftInfo {
+            is_nft: false,
+            launcher_id: None,
+            metadata: None,
+            metadata_json: None,
+            metadata_updater_puzzle_hash: None,
+            current_owner: None,
+            royalty_puzzle_hash: None,
+            royalty_percentage: None,
+            p2_puzzle_hash: None,
+        },
+    })
+}
+
+// Creates a DID owned by the standard puzzle of `public_key` from standard XCH coins
+// (JSON as in `standard_transaction_create`). `recovery_list` holds hex DID ids and
+// `metadata_json` is a JSON object of strings. `amount` must be odd.
+#[allow(clippy::too_many_arguments)]
+pub fn did_create(
+    coins_json: String,
+    public_key: Vec<u8>,
+    recovery_list: Vec<String>,
+    num_verifications_required: u64,
+    metadata_json: String,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<ApiSingletonLaunch> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let recovery_list = bytes32_list_from_hex(&recovery_list, "recovery DID id")?;
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let (launcher_id, spend_bundle) = create_did_launch_bundle(
+        &spendable_coins,
+        &public_key_from_vec(&public_key)?,
+        &recovery_list,
+        num_verifications_required,
+        &did_metadata_from_json(&metadata_json)?,
+        amount,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(ApiSingletonLaunch {
+        launcher_id: launcher_id.bytes,
+        spend_bundle: serde_json::to_string(&spend_bundle)?,
+    })
+}
+
+// Checks whether `puzzle` is a DID puzzle reveal, returning its launcher id, recovery
+// settings and metadata.
+pub fn did_parse(puzzle: Vec<u8>) -> Result<ApiDidInfo> {
+    let puzzle = program_from_vec(&puzzle)?;
+    Ok(match parse_did_puzzle(&puzzle) {
+        Some((info, p2_puzzle)) => ApiDidInfo {
+            is_did: true,
+            launcher_id: Some(info.launcher_id.bytes),
+            recovery_list_hash: Some(info.recovery_list_hash.bytes),
+            num_verifications_required: Some(info.num_verifications_required),
+            metadata_json: match did_metadata_from_program(&info.metadata) {
+                Ok(metadata) => Some(serde_json::to_string(&metadata)?),
+                Err(_) => None,
+            },
+            metadata: Some(info.metadata.serialized),
+            p2_puzzle_hash: Some(p2_puzzle.tree_hash().bytes),
+        },
+        None => ApiDidInfo {
+            is_did: false,
+            launcher_id: None,
+            recovery_list_hash: None,
+            num_verifications_required: None,
+            metadata: None,
+            metadata_json: None,
+            p2_puzzle_hash: None,
+        },
+    })
+}
+
+// Builds the coin spend handing the DID `coin_json`, whose current puzzle reveal is
+// `did_puzzle` and whose p2 puzzle is the standard puzzle of `public_key`, over to
+// `new_p2_puzzle_hash`. Returned as JSON.
+pub fn did_transfer_create(
+    coin_json: String,
+    did_puzzle: Vec<u8>,
+    public_key: Vec<u8>,
+    lineage_proof_json: String,
+    new_p2_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let coin: Coin =
+        serde_json::from_str(&coin_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let lineage_proof: LineageProof = serde_json::from_str(&lineage_proof_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let new_p2_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&new_p2_puzzle_hash, "puzzle hash")?.to_vec());
+    let coin_spend = create_did_transfer_spend(
+        &coin,
+        &did_info_from_puzzle(&did_puzzle)?,
+        &public_key_from_vec(&public_key)?,
+        &lineage_proof,
+        &new_p2_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// Like `did_transfer_create`, but keeps the owner and replaces the metadata with
+// `metadata_json`, a JSON object of strings.
+pub fn did_metadata_update_create(
+    coin_json: String,
+    did_puzzle: Vec<u8>,
+    public_key: Vec<u8>,
+    lineage_proof_json: String,
+    metadata_json: String,
+) -> Result<String> {
+    let coin: Coin =
+        serde_json::from_str(&coin_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let lineage_proof: LineageProof = serde_json::from_str(&lineage_proof_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let coin_spend = create_did_metadata_update_spend(
+        &coin,
+        &did_info_from_puzzle(&did_puzzle)?,
+        &public_key_from_vec(&public_key)?,
+        &lineage_proof,
+        &did_metadata_from_json(&metadata_json)?,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// Builds the DID spend announcing `nft_launcher_ids` (hex), to go in the same bundle
+// as the NFT spends assigning those NFTs to the DID.
+pub fn did_nft_assignment_create(
+    coin_json: String,
+    did_puzzle: Vec<u8>,
+    public_key: Vec<u8>,
+    lineage_proof_json: String,
+    nft_launcher_ids: Vec<String>,
+) -> Result<String> {
+    let coin: Coin =
+        serde_json::from_str(&coin_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let lineage_proof: LineageProof = serde_json::from_str(&lineage_proof_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let nft_launcher_ids = bytes32_list_from_hex(&nft_launcher_ids, "NFT launcher id")?;
+    let coin_spend = create_did_nft_assignment_spend(
+        &coin,
+        &did_info_from_puzzle(&did_puzzle)?,
+        &public_key_from_vec(&public_key)?,
+        &lineage_proof,
+        &nft_launcher_ids,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// Creates an `offer1…` string offering `offered_xch` mojos from standard XCH coins
+// (JSON as in `standard_transaction_create`) and the CATs in `offered_cats_json`, a
+// list of {"tail_hash", "amount", "coins": [{"coin", "public_key", "lineage_proof"}]}.
+// `requested_json` is a list of {"asset_id": hex or null for XCH, "puzzle_hash",
+// "amount", "memos": [hex]}. The maker's spends are signed with `secret_keys` (hex).
+#[allow(clippy::too_many_arguments)]
+pub fn offer_create(
+    coins_json: String,
+    offered_xch: u64,
+    offered_cats_json: String,
+    requested_json: String,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+    secret_keys: Vec<String>,
+    network: String,
+) -> Result<String> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let offered_cats = offered_cats_from_json(&offered_cats_json)?;
+    let requested_payments: Vec<RequestedPaymentJson> =
+        serde_json::from_str(&requested_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let mut requested: Vec<(AssetId, Vec<Payment>)> = Vec::new();
+    for payment in requested_payments {
+        let mut memos = Vec::new();
+        for memo in payment.memos {
+            memos.push(hex_to_vec(&memo)?);
+        }
+        requested.push((
+            payment.asset_id,
+            vec![Payment {
+                puzzle_hash: payment.puzzle_hash,
+                amount: payment.amount,
+                memos,
+            }],
+        ));
+    }
+    let offer = create_offer(
+        &spendable_coins,
+        offered_xch,
+        &offered_cats,
+        &requested,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    let offer = sign_offer(offer, &secret_keys, &network)?;
+    let encoded = offer
+        .to_bech32()
+        .map_err(|e| ApiError::CompressionFailed(e.to_string()))?;
+    Ok(encoded)
+}
+
+// Summarises an `offer1…` string as JSON: {"offered": {asset: amount}, "requested":
+// {asset: amount}, "fee"}, where an asset is "xch" or a CAT's TAIL hash.
+pub fn offer_summary(offer: String) -> Result<String> {
+    let offer = Offer::from_bech32(&offer).map_err(|e| ApiError::InvalidOffer(e.to_string()))?;
+    let summary = OfferSummaryJson {
+        offered: offer
+            .offered_amounts()
+            .iter()
+            .map(|(asset_id, amount)| (offer_asset_key(asset_id), *amount))
+            .collect(),
+        requested: offer
+            .requested_amounts()
+            .iter()
+            .map(|(asset_id, amount)| (offer_asset_key(asset_id), *amount))
+            .collect(),
+        fee: offer.fee(),
+    };
+    Ok(serde_json::to_string(&summary)?)
+}
+
+// Takes an `offer1…` string, paying what it requests from standard XCH coins and the
+// taker's CATs (`cat_coins_json` as in `offer_create`, without amounts) and receiving
+// what it offers, plus any surplus of the settlement coins, at `receive_puzzle_hash`.
+// Returns the final signed spend bundle as JSON.
+#[allow(clippy::too_many_arguments)]
+pub fn offer_take(
+    offer: String,
+    coins_json: String,
+    cat_coins_json: String,
+    receive_puzzle_hash: Vec<u8>,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+    secret_keys: Vec<String>,
+    network: String,
+) -> Result<String> {
+    let maker = Offer::from_bech32(&offer).map_err(|e| ApiError::InvalidOffer(e.to_string()))?;
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let cat_coins: Vec<(Bytes32, Vec<StandardCatCoin>)> = offered_cats_from_json(&cat_coins_json)?
+        .into_iter()
+        .map(|cat| (cat.tail_hash, cat.coins))
+        .collect();
+    let receive_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&receive_puzzle_hash, "receive puzzle hash")?.to_vec());
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let taker = create_taker_offer(
+        &maker,
+        &spendable_coins,
+        &cat_coins,
+        &receive_puzzle_hash,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    let taker = sign_offer(taker, &secret_keys, &network)?;
+    let spend_bundle = complete_offer(&maker, &taker, &receive_puzzle_hash)
+        .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// Compresses a JSON spend bundle with the lowest puzzle dictionary version that covers
+// its puzzles, as offer files are.
+pub fn spend_bundle_compress(spend_bundle_json: String) -> Result<Vec<u8>> {
+    let spend_bundle = spend_bundle_from_json(&spend_bundle_json)?;
+    let compressed = compress_spend_bundle(&spend_bundle, None)
+        .map_err(|e| ApiError::CompressionFailed(e.to_string()))?;
+    Ok(compressed)
+}
+
+pub fn spend_bundle_decompress(compressed: Vec<u8>) -> Result<String> {
+    let spend_bundle = decompress_spend_bundle(&compressed)
+        .map_err(|e| ApiError::CompressionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// Identifies a serialized puzzle layer by layer, e.g. singleton → NFT state → NFT
+// ownership → standard. Returns JSON {"name", "mod_hash", "params": [{"name", "atom",
+// "program"}], "inner"}; layers no driver recognises are named "unknown".
+pub fn puzzle_identify(puzzle: Vec<u8>) -> Result<String> {
+    let puzzle = program_from_vec(&puzzle)?;
+    let layer = puzzle_drivers().identify(&puzzle);
+    Ok(serde_json::to_string(&PuzzleLayerJson::from(&layer))?)
+}
+
+pub fn coin_spend_identify(coin_spend_json: String) -> Result<String> {
+    let coin_spend: CoinSpend =
+        serde_json::from_str(&coin_spend_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let puzzle = Program::new(coin_spend.puzzle_reveal.to_bytes());
+    if puzzle.tree_hash() != coin_spend.coin.puzzle_hash {
+        return Err(ApiError::PuzzleRevealMismatch {
+            coin_id: coin_spend.coin.name().to_string(),
+        }
+        .into());
+    }
+    let layer = puzzle_drivers().identify(&puzzle);
+    Ok(serde_json::to_string(&PuzzleLayerJson::from(&layer))?)
+}
+
+// Registers a driver for a third-party mod, naming its curried arguments in order.
+// `inner_puzzle_arg` names the argument holding the wrapped puzzle, if any. A mod that
+// is not curried takes no argument names and is matched on its own hash. Replaces any
+// driver already registered for `mod_hash`.
+pub fn puzzle_driver_register(
+    name: String,
+    mod_hash: Vec<u8>,
+    arg_names: Vec<String>,
+    inner_puzzle_arg: Option<String>,
+) -> Result<()> {
+    let mod_hash = Bytes32::from(fixed_bytes::<32>(&mod_hash, "mod hash")?.to_vec());
+    let inner_puzzle_arg = match inner_puzzle_arg {
+        Some(inner_puzzle_arg) => Some(
+            arg_names
+                .iter()
+                .position(|arg_name| *arg_name == inner_puzzle_arg)
+                .ok_or_else(|| {
+                    ApiError::InvalidPuzzleDriver(format!(
+                        "{:?} is not one of the argument names",
+                        inner_puzzle_arg
+                    ))
+                })?,
+        ),
+        None => None,
+    };
+    puzzle_drivers_mut().register(Box::new(CurriedArgsDriver {
+        name,
+        mod_hash,
+        arg_names,
+        inner_puzzle_arg,
+    }));
+    Ok(())
+}
+
+// Sends `amount` from standard XCH coins to `recipient_puzzle_hash` with a clawback
+// window: the recipient can claim after `time_lock` seconds, and until then
+// `sender_puzzle_hash` can claw it back. Returns the unsigned spend bundle as JSON.
+pub fn clawback_create(
+    coins_json: String,
+    sender_puzzle_hash: Vec<u8>,
+    recipient_puzzle_hash: Vec<u8>,
+    amount: u64,
+    time_lock: u64,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let metadata = ClawbackMetadata {
+        time_lock,
+        sender_puzzle_hash: Bytes32::from(
+            fixed_bytes::<32>(&sender_puzzle_hash, "sender puzzle hash")?.to_vec(),
+        ),
+        recipient_puzzle_hash: Bytes32::from(
+            fixed_bytes::<32>(&recipient_puzzle_hash, "recipient puzzle hash")?.to_vec(),
+        ),
+    };
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let spend_bundle = create_clawback_payment(
+        &spendable_coins,
+        &metadata,
+        amount,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+fn clawback_spend_from_json(
+    coin_json: &str,
+    metadata_json: &str,
+) -> Result<(Coin, ClawbackMetadata), ApiError> {
+    let coin: Coin =
+        serde_json::from_str(coin_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let metadata: ClawbackMetadataJson =
+        serde_json::from_str(metadata_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    Ok((coin, metadata.into()))
+}
+
+// The recipient claims a clawback coin to `destination_puzzle_hash`, paying `fee` from
+// it. `metadata_json` is {"time_lock", "sender_puzzle_hash", "recipient_puzzle_hash"}
+// as returned by `clawback_parse_coins`, and `public_key` the recipient's wallet key.
+// Returns the unsigned coin spend as JSON.
+pub fn clawback_claim_create(
+    coin_json: String,
+    metadata_json: String,
+    public_key: Vec<u8>,
+    destination_puzzle_hash: Vec<u8>,
+    fee: u64,
+) -> Result<String> {
+    let (coin, metadata) = clawback_spend_from_json(&coin_json, &metadata_json)?;
+    let destination_puzzle_hash = Bytes32::from(
+        fixed_bytes::<32>(&destination_puzzle_hash, "destination puzzle hash")?.to_vec(),
+    );
+    let coin_spend = create_clawback_claim_spend(
+        &coin,
+        &metadata,
+        &public_key_from_vec(&public_key)?,
+        &destination_puzzle_hash,
+        fee,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// Like `clawback_claim_create`, but the sender takes the coin back.
+pub fn clawback_spend_create(
+    coin_json: String,
+    metadata_json: String,
+    public_key: Vec<u8>,
+    destination_puzzle_hash: Vec<u8>,
+    fee: u64,
+) -> Result<String> {
+    let (coin, metadata) = clawback_spend_from_json(&coin_json, &metadata_json)?;
+    let destination_puzzle_hash = Bytes32::from(
+        fixed_bytes::<32>(&destination_puzzle_hash, "destination puzzle hash")?.to_vec(),
+    );
+    let coin_spend = create_clawback_spend(
+        &coin,
+        &metadata,
+        &public_key_from_vec(&public_key)?,
+        &destination_puzzle_hash,
+        fee,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// The clawback coins a parent coin spend created, as a JSON list of {"coin",
+// "time_lock", "sender_puzzle_hash", "recipient_puzzle_hash"}. The wallet lists those
+// whose recipient puzzle hash it owns as pending incoming clawbacks.
+pub fn clawback_parse_coins(parent_coin_spend_json: String) -> Result<String> {
+    let parent_spend: CoinSpend = serde_json::from_str(&parent_coin_spend_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let clawbacks: Vec<ClawbackCoinJson> = parse_clawback_coins(&parent_spend)
+        .map_err(|e| ApiError::ProgramFailed(e.to_string()))?
+        .into_iter()
+        .map(|(coin, metadata)| ClawbackCoinJson {
+            coin,
+            metadata: ClawbackMetadataJson {
+                time_lock: metadata.time_lock,
+                sender_puzzle_hash: metadata.sender_puzzle_hash,
+                recipient_puzzle_hash: metadata.recipient_puzzle_hash,
+            },
+        })
+        .collect();
+    Ok(serde_json::to_string(&clawbacks)?)
+}
+
+// Self-pooling when `pool_url` is None, otherwise farming to the pool at
+// `target_puzzle_hash`.
+fn pool_state_from_args(
+    owner_public_key: &[u8],
+    target_puzzle_hash: &[u8],
+    pool_url: Option<String>,
+    relative_lock_height: u32,
+) -> Result<PoolState, ApiError> {
+    let owner_pubkey = Bytes48::from(public_key_from_vec(owner_public_key)?.to_bytes().to_vec());
+    let target_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(target_puzzle_hash, "target puzzle hash")?.to_vec());
+    Ok(match pool_url {
+        None => PoolState::self_pooling(&owner_pubkey, &target_puzzle_hash),
+        Some(pool_url) => PoolState::farming_to_pool(
+            &owner_pubkey,
+            &target_puzzle_hash,
+            &pool_url,
+            relative_lock_height,
+        )
+        .map_err(|e| ApiError::TransactionFailed(e.to_string()))?,
+    })
+}
+
+fn plot_nft_from_json(plot_nft_json: &str) -> Result<PlotNft, ApiError> {
+    serde_json::from_str(plot_nft_json).map_err(|e| ApiError::InvalidJson(e.to_string()))
+}
+
+// Launches a plot NFT owned by `owner_public_key` from standard XCH coins (JSON as in
+// `standard_transaction_create`). Without a `pool_url` it self-pools, paying rewards to
+// `target_puzzle_hash`; with one it farms to the pool at `target_puzzle_hash`. Rewards
+// left unabsorbed for a week can be claimed to `delay_puzzle_hash`.
+#[allow(clippy::too_many_arguments)]
+pub fn plot_nft_create(
+    coins_json: String,
+    owner_public_key: Vec<u8>,
+    target_puzzle_hash: Vec<u8>,
+    pool_url: Option<String>,
+    relative_lock_height: u32,
+    delay_puzzle_hash: Vec<u8>,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+    network: String,
+) -> Result<ApiSingletonLaunch> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let initial_state = pool_state_from_args(
+        &owner_public_key,
+        &target_puzzle_hash,
+        pool_url,
+        relative_lock_height,
+    )?;
+    let delay_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&delay_puzzle_hash, "delay puzzle hash")?.to_vec());
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let (launcher_id, spend_bundle) = create_plot_nft_launch_bundle(
+        &spendable_coins,
+        &initial_state,
+        DEFAULT_DELAY_TIME,
+        &delay_puzzle_hash,
+        &constants_for_network(&network)?.genesis_challenge,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(ApiSingletonLaunch {
+        launcher_id: launcher_id.bytes,
+        spend_bundle: serde_json::to_string(&spend_bundle)?,
+    })
+}
+
+// The plot NFT followed through its coin spends (a JSON list, oldest first, starting
+// with the launcher spend). Returns it as JSON with its current coin, lineage proof and
+// pool state.
+pub fn plot_nft_parse(coin_spends_json: String) -> Result<String> {
+    let coin_spends: Vec<CoinSpend> = serde_json::from_str(&coin_spends_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let plot_nft =
+        parse_plot_nft(&coin_spends).map_err(|e| ApiError::ProgramFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&plot_nft)?)
+}
+
+// The puzzle hash pool rewards for the plot NFT (JSON from `plot_nft_parse`) are paid to.
+pub fn plot_nft_p2_singleton_puzzle_hash(plot_nft_json: String) -> Result<Vec<u8>> {
+    let plot_nft = plot_nft_from_json(&plot_nft_json)?;
+    Ok(plot_nft.p2_singleton_puzzle().tree_hash().bytes)
+}
+
+// Moves the plot NFT towards a new pool, or to self-pooling when `pool_url` is None.
+// A plot NFT farming to a pool only enters the waiting room; after its relative lock
+// height, a second travel spend reaches the target. Returns the coin spend as JSON, to
+// be signed by the owner key.
+pub fn plot_nft_travel_create(
+    plot_nft_json: String,
+    target_puzzle_hash: Vec<u8>,
+    pool_url: Option<String>,
+    relative_lock_height: u32,
+    network: String,
+) -> Result<String> {
+    let plot_nft = plot_nft_from_json(&plot_nft_json)?;
+    let target = pool_state_from_args(
+        &plot_nft.state.owner_pubkey.bytes,
+        &target_puzzle_hash,
+        pool_url,
+        relative_lock_height,
+    )?;
+    let (coin_spend, _) = create_plot_nft_travel_spend(
+        &plot_nft,
+        &target,
+        &constants_for_network(&network)?.genesis_challenge,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&coin_spend)?)
+}
+
+// Absorbs pool reward coins (a JSON list of coins) into the plot NFT. Returns the spend
+// bundle as JSON; it needs no signature.
+pub fn plot_nft_absorb_create(
+    plot_nft_json: String,
+    reward_coins_json: String,
+    network: String,
+) -> Result<String> {
+    let plot_nft = plot_nft_from_json(&plot_nft_json)?;
+    let reward_coins: Vec<Coin> = serde_json::from_str(&reward_coins_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let coin_spends = create_plot_nft_absorb_spends(
+        &plot_nft,
+        &reward_coins,
+        &constants_for_network(&network)?.genesis_challenge,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&SpendBundle {
+        coin_spends,
+        aggregated_signature: empty_signature(),
+    })?)
+}
+
+fn multisig_info_from_args(
+    threshold: u32,
+    public_keys: &[String],
+) -> Result<MultisigInfo, ApiError> {
+    MultisigInfo::new(threshold, public_keys_from_hex(public_keys)?)
+        .map_err(|e| ApiError::TransactionFailed(e.to_string()))
+}
+
+// Returns the serialized m-of-n puzzle any `threshold` of the hex `public_keys` can
+// spend. The order of the keys changes the puzzle.
+pub fn multisig_puzzle(threshold: u32, public_keys: Vec<String>) -> Result<Vec<u8>> {
+    Ok(multisig_info_from_args(threshold, &public_keys)?
+        .puzzle()
+        .serialized)
+}
+
+pub fn multisig_puzzle_hash(threshold: u32, public_keys: Vec<String>) -> Result<Vec<u8>> {
+    Ok(multisig_info_from_args(threshold, &public_keys)?
+        .puzzle_hash()
+        .bytes)
+}
+
+pub fn multisig_address(
+    threshold: u32,
+    public_keys: Vec<String>,
+    prefix: String,
+) -> Result<String> {
+    let puzzle_hash = multisig_info_from_args(threshold, &public_keys)?.puzzle_hash();
+    Ok(network_from_prefix(prefix)?.puzzle_hash_to_address(&puzzle_hash))
+}
+
+// Builds an unsigned spend bundle from multisig coins (a JSON list of coins), with
+// payments as in `standard_transaction_create`. `signers` are the hex public keys, exactly
+// `threshold` of them, that will sign with `multisig_sign`.
+#[allow(clippy::too_many_arguments)]
+pub fn multisig_transaction_create(
+    threshold: u32,
+    public_keys: Vec<String>,
+    coins_json: String,
+    signers: Vec<String>,
+    payments_json: String,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let info = multisig_info_from_args(threshold, &public_keys)?;
+    let coins: Vec<Coin> =
+        serde_json::from_str(&coins_json).map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let signers = public_keys_from_hex(&signers)?;
+    let payments = payments_from_json(&payments_json)?;
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let spend_bundle =
+        create_multisig_spend_bundle(&info, &coins, &signers, &payments, fee, &change_puzzle_hash)
+            .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// One signer's partial signature for a JSON multisig spend bundle, signed with their
+// secret key. Collect one from each chosen signer for `multisig_add_signatures`.
+pub fn multisig_sign(spend_bundle_json: String, sk: Vec<u8>, network: String) -> Result<Vec<u8>> {
+    let spend_bundle = spend_bundle_from_json(&spend_bundle_json)?;
+    let signature = sign_multisig_spends(
+        &spend_bundle.coin_spends,
+        &secret_key_from_vec(&sk)?,
+        constants_for_network(&network)?,
+        INFINITE_COST,
+    )
+    .map_err(|e| ApiError::SigningFailed(e.to_string()))?;
+    Ok(signature.bytes)
+}
+
+// Aggregates the hex partial signatures into the JSON spend bundle and returns it as
+// JSON. Fails unless every chosen signer has signed.
+pub fn multisig_add_signatures(
+    spend_bundle_json: String,
+    partial_signatures: Vec<String>,
+    network: String,
+) -> Result<String> {
+    let spend_bundle = spend_bundle_from_json(&spend_bundle_json)?;
+    let mut signatures = Vec::new();
+    for signature in &partial_signatures {
+        let signature = signature_from_vec(&hex_to_vec(signature)?)?;
+        signatures.push(Bytes96::from(signature.to_bytes().to_vec()));
+    }
+    let signed = add_partial_signatures(
+        &spend_bundle,
+        &signatures,
+        constants_for_network(&network)?,
+        INFINITE_COST,
+    )
+    .map_err(|e| ApiError::SigningFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&signed)?)
+}
+
+// Sends `message` with `amount` mojos to `target_puzzle_hash` from standard XCH coins
+// (JSON as in `standard_transaction_create`). Returns the unsigned spend bundle as JSON.
+pub fn notification_create(
+    coins_json: String,
+    target_puzzle_hash: Vec<u8>,
+    message: Vec<u8>,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: Vec<u8>,
+) -> Result<String> {
+    let spendable_coins = spendable_coins_from_json(&coins_json)?;
+    let target_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&target_puzzle_hash, "target puzzle hash")?.to_vec());
+    let change_puzzle_hash =
+        Bytes32::from(fixed_bytes::<32>(&change_puzzle_hash, "change puzzle hash")?.to_vec());
+    let spend_bundle = create_notification_bundle(
+        &spendable_coins,
+        &target_puzzle_hash,
+        &message,
+        amount,
+        fee,
+        &change_puzzle_hash,
+    )
+    .map_err(|e| ApiError::TransactionFailed(e.to_string()))?;
+    Ok(serde_json::to_string(&spend_bundle)?)
+}
+
+// The notification coins a parent coin spend created, as a JSON list of {"coin",
+// "target_puzzle_hash", "amount", "message"}. The wallet shows those whose target
+// puzzle hash it owns.
+pub fn notification_parse(parent_coin_spend_json: String) -> Result<String> {
+    let parent_spend: CoinSpend = serde_json::from_str(&parent_coin_spend_json)
+        .map_err(|e| ApiError::InvalidJson(e.to_string()))?;
+    let notifications: Vec<NotificationJson> = parse_notifications(&parent_spend)
+        .map_err(|e| ApiError::ProgramFailed(e.to_string()))?
+        .into_iter()
+        .map(|notification| NotificationJson {
+            coin: notification.coin,
+            target_puzzle_hash: notification.target_puzzle_hash,
+            amount: notification.amount,
+            message: hex::encode(&notification.message),
+        })
+        .collect();
+    Ok(serde_json::to_string(&notifications)?)
+}
+
+fn chip_0002_message(message: &str, is_hex: bool) -> Result<Vec<u8>, ApiError> {
+    if is_hex {
+        hex_to_vec(message)
+    } else {
+        Ok(message.as_bytes().to_vec())
+    }
+}
+
+fn check_address_key(synthetic_pk: &PublicKey, address: &str) -> Result<(), ApiError> {
+    let (_prefix, puzzle_hash) =
+        decode_puzzle_hash(address).map_err(|e| ApiError::InvalidAddress(e.to_string()))?;
+    if !synthetic_pk_matches_puzzle_hash(synthetic_pk, &puzzle_hash) {
+        return Err(ApiError::InvalidAddress(format!(
+            "{} is not the address of the public key",
+            address
+        )));
+    }
+    Ok(())
+}
+
+// Signs a message CHIP-0002 style with the synthetic key of the wallet key `sk`.
+// `message` is hex when `is_hex`, text otherwise. With an `address`, fails unless it is
+// the address of `sk`.
+pub fn message_sign(
+    sk: Vec<u8>,
+    message: String,
+    is_hex: bool,
+    address: Option<String>,
+) -> Result<ApiSignedMessage> {
+    let sk = secret_key_from_vec(&sk)?;
+    let message = chip_0002_message(&message, is_hex)?;
+    let (synthetic_pk, signature) = sign_message(&sk, &message);
+    if let Some(address) = address {
+        check_address_key(&synthetic_pk, &address)?;
+    }
+    Ok(ApiSignedMessage {
+        public_key: synthetic_pk.to_bytes().to_vec(),
+        signature: signature.to_bytes().to_vec(),
+    })
+}
+
+// Verifies a CHIP-0002 signature by the synthetic public key `pk`. With an `address`,
+// fails unless it is the address of `pk`.
+pub fn message_verify(
+    pk: Vec<u8>,
+    message: String,
+    is_hex: bool,
+    sig: Vec<u8>,
+    address: Option<String>,
+) -> Result<bool> {
+    let public_key = public_key_from_vec(&pk)?;
+    let sig = signature_from_vec(&sig)?;
+    if let Some(address) = address {
+        check_address_key(&public_key, &address)?;
+    }
+    let message = chip_0002_message(&message, is_hex)?;
+    Ok(verify_message(&public_key, &message, &sig))
 }
@@ -0,0 +1,141 @@
+use hex::FromHexError;
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the functions exported in `api.rs`.
+/// flutter_rust_bridge forwards the `Display` text to Dart as the exception message.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ApiError {
+    InvalidLength {
+        kind: &'static str,
+        expected: usize,
+        got: usize,
+    },
+    InvalidHexCharacter {
+        character: char,
+        index: usize,
+    },
+    OddHexLength(usize),
+    InvalidSecretKey(String),
+    InvalidPublicKey(String),
+    InvalidSignature(String),
+    InvalidMnemonic(String),
+    InvalidAddress(String),
+    InvalidIndexRange {
+        start: u32,
+        count: u32,
+    },
+    TooManyKeys {
+        count: u32,
+        max: u32,
+    },
+    InvalidClvm {
+        offset: u64,
+        reason: String,
+    },
+    Keyring(String),
+    UnknownNetwork(String),
+    InvalidJson(String),
+    SigningFailed(String),
+    TransactionFailed(String),
+    UnknownSelectionStrategy(String),
+    CoinSelectionFailed(String),
+    UnknownTail(String),
+    InvalidOffer(String),
+    CompressionFailed(String),
+    InvalidPuzzleDriver(String),
+    PuzzleRevealMismatch {
+        coin_id: String,
+    },
+    ProgramNotCurried,
+    ProgramNotAtom,
+    ProgramFailed(String),
+    ToolFailed(String),
+}
+
+impl ApiError {
+    pub fn from_hex_error(error: FromHexError, hex_len: usize) -> Self {
+        match error {
+            FromHexError::InvalidHexCharacter { c, index } => ApiError::InvalidHexCharacter {
+                character: c,
+                index,
+            },
+            FromHexError::OddLength => ApiError::OddHexLength(hex_len),
+            FromHexError::InvalidStringLength => ApiError::OddHexLength(hex_len),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::InvalidLength {
+                kind,
+                expected,
+                got,
+            } => write!(f, "expected {}-byte {}, got {}", expected, kind, got),
+            ApiError::InvalidHexCharacter { character, index } => {
+                write!(
+                    f,
+                    "invalid hex character {:?} at index {}",
+                    character, index
+                )
+            }
+            ApiError::OddHexLength(len) => {
+                write!(f, "hex string must have an even length, got {}", len)
+            }
+            ApiError::InvalidSecretKey(reason) => write!(f, "invalid secret key: {}", reason),
+            ApiError::InvalidPublicKey(reason) => {
+                write!(f, "invalid G1 element: {}", reason)
+            }
+            ApiError::InvalidSignature(reason) => {
+                write!(f, "invalid G2 element: {}", reason)
+            }
+            ApiError::InvalidMnemonic(reason) => write!(f, "invalid mnemonic: {}", reason),
+            ApiError::InvalidAddress(reason) => write!(f, "invalid address: {}", reason),
+            ApiError::InvalidIndexRange { start, count } => write!(
+                f,
+                "{} keys starting at index {} exceed the u32 index range",
+                count, start
+            ),
+            ApiError::TooManyKeys { count, max } => write!(
+                f,
+                "cannot derive {} keys at once, at most {} per call",
+                count, max
+            ),
+            ApiError::InvalidClvm { offset, reason } => write!(
+                f,
+                "invalid CLVM serialization at offset {}: {}",
+                offset, reason
+            ),
+            ApiError::Keyring(reason) => write!(f, "{}", reason),
+            ApiError::UnknownNetwork(network) => write!(f, "unknown network {:?}", network),
+            ApiError::InvalidJson(reason) => write!(f, "invalid JSON: {}", reason),
+            ApiError::SigningFailed(reason) => write!(f, "signing failed: {}", reason),
+            ApiError::TransactionFailed(reason) => {
+                write!(f, "could not build transaction: {}", reason)
+            }
+            ApiError::UnknownSelectionStrategy(strategy) => {
+                write!(f, "unknown coin selection strategy {:?}", strategy)
+            }
+            ApiError::CoinSelectionFailed(reason) => {
+                write!(f, "coin selection failed: {}", reason)
+            }
+            ApiError::UnknownTail(kind) => write!(f, "unknown TAIL {:?}", kind),
+            ApiError::InvalidOffer(reason) => write!(f, "invalid offer: {}", reason),
+            ApiError::CompressionFailed(reason) => write!(f, "compression failed: {}", reason),
+            ApiError::InvalidPuzzleDriver(reason) => write!(f, "invalid puzzle driver: {}", reason),
+            ApiError::PuzzleRevealMismatch { coin_id } => write!(
+                f,
+                "puzzle reveal does not hash to the puzzle hash of coin {}",
+                coin_id
+            ),
+            ApiError::ProgramNotCurried => write!(f, "program is not curried"),
+            ApiError::ProgramNotAtom => write!(f, "expected an atom, got a pair"),
+            ApiError::ProgramFailed(reason) => write!(f, "program failed: {}", reason),
+            ApiError::ToolFailed(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl Error for ApiError {}
@@ -0,0 +1,297 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use std::error::Error;
+use std::fmt;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const BECH32M_CONST: u32 = 0x2bc830a3;
+const CHECKSUM_LENGTH: usize = 6;
+const MAX_LENGTH: usize = 90;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Bech32Error {
+    MixedCase,
+    MissingSeparator,
+    InvalidLength(usize),
+    EmptyPrefix,
+    InvalidPrefixCharacter(usize),
+    InvalidCharacter { character: char, position: usize },
+    InvalidChecksum { typo_position: Option<usize> },
+    InvalidPadding,
+    WrongPrefix { expected: String, got: String },
+    WrongDataLength { expected: usize, got: usize },
+}
+
+impl fmt::Display for Bech32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bech32Error::MixedCase => write!(f, "address mixes upper and lower case"),
+            Bech32Error::MissingSeparator => write!(f, "address has no '1' separator"),
+            Bech32Error::InvalidLength(len) => {
+                write!(f, "address length {} is out of range", len)
+            }
+            Bech32Error::EmptyPrefix => write!(f, "address has an empty prefix"),
+            Bech32Error::InvalidPrefixCharacter(position) => {
+                write!(f, "invalid prefix character at position {}", position)
+            }
+            Bech32Error::InvalidCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "invalid character {:?} at position {}",
+                character, position
+            ),
+            Bech32Error::InvalidChecksum {
+                typo_position: Some(position),
+            } => write!(f, "invalid checksum, likely typo at position {}", position),
+            Bech32Error::InvalidChecksum {
+                typo_position: None,
+            } => write!(f, "invalid checksum"),
+            Bech32Error::InvalidPadding => write!(f, "invalid padding in address data"),
+            Bech32Error::WrongPrefix { expected, got } => {
+                write!(f, "expected prefix {:?}, got {:?}", expected, got)
+            }
+            Bech32Error::WrongDataLength { expected, got } => {
+                write!(
+                    f,
+                    "expected {} bytes of address data, got {}",
+                    expected, got
+                )
+            }
+        }
+    }
+}
+
+impl Error for Bech32Error {}
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let top = chk >> 25;
+        chk = (chk & 0x1ffffff) << 5 ^ *value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut expanded: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+    expanded.push(0);
+    expanded.extend(hrp.bytes().map(|b| b & 31));
+    expanded
+}
+
+fn verify_checksum(hrp: &str, data: &[u8]) -> bool {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    polymod(&values) == BECH32M_CONST
+}
+
+fn create_checksum(hrp: &str, data: &[u8]) -> Vec<u8> {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0u8; CHECKSUM_LENGTH]);
+    let polymod = polymod(&values) ^ BECH32M_CONST;
+    (0..CHECKSUM_LENGTH)
+        .map(|i| ((polymod >> (5 * (5 - i))) & 31) as u8)
+        .collect()
+}
+
+// A bech32m checksum detects any single substitution, so if exactly one
+// replacement character makes the checksum valid we can point at it.
+fn locate_typo(hrp: &str, data: &[u8]) -> Option<usize> {
+    let mut candidates = Vec::new();
+    let mut data = data.to_vec();
+    for i in 0..data.len() {
+        let original = data[i];
+        for replacement in 0..32u8 {
+            if replacement == original {
+                continue;
+            }
+            data[i] = replacement;
+            if verify_checksum(hrp, &data) {
+                candidates.push(i);
+            }
+        }
+        data[i] = original;
+    }
+    match candidates.len() {
+        1 => Some(hrp.len() + 1 + candidates[0]),
+        _ => None,
+    }
+}
+
+/// Regroups a sequence of `from_bits` wide values into `to_bits` wide values.
+pub fn convert_bits(
+    data: &[u8],
+    from_bits: u32,
+    to_bits: u32,
+    pad: bool,
+) -> Result<Vec<u8>, Bech32Error> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut ret = Vec::new();
+    let maxv: u32 = (1 << to_bits) - 1;
+    for value in data {
+        let value = *value as u32;
+        if value >> from_bits != 0 {
+            return Err(Bech32Error::InvalidPadding);
+        }
+        acc = (acc << from_bits) | value;
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            ret.push(((acc >> bits) & maxv) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            ret.push(((acc << (to_bits - bits)) & maxv) as u8);
+        }
+    } else if bits >= from_bits || ((acc << (to_bits - bits)) & maxv) != 0 {
+        return Err(Bech32Error::InvalidPadding);
+    }
+    Ok(ret)
+}
+
+/// Encodes 5-bit `data` with the human readable part `hrp`.
+pub fn bech32m_encode(hrp: &str, data: &[u8]) -> String {
+    let mut combined = data.to_vec();
+    combined.extend(create_checksum(hrp, data));
+    let mut encoded = String::with_capacity(hrp.len() + 1 + combined.len());
+    encoded.push_str(hrp);
+    encoded.push('1');
+    for value in combined {
+        encoded.push(CHARSET[value as usize] as char);
+    }
+    encoded
+}
+
+/// Decodes a bech32m string into its lowercase human readable part and 5-bit data,
+/// without the checksum.
+pub fn bech32m_decode(bech: &str) -> Result<(String, Vec<u8>), Bech32Error> {
+    bech32m_decode_with_max_length(bech, MAX_LENGTH)
+}
+
+/// Like `bech32m_decode`, for strings longer than an address, such as offers.
+pub fn bech32m_decode_with_max_length(
+    bech: &str,
+    max_length: usize,
+) -> Result<(String, Vec<u8>), Bech32Error> {
+    if bech.len() > max_length {
+        return Err(Bech32Error::InvalidLength(bech.len()));
+    }
+    if bech.to_lowercase() != bech && bech.to_uppercase() != bech {
+        return Err(Bech32Error::MixedCase);
+    }
+    let bech = bech.to_lowercase();
+    let pos = bech.rfind('1').ok_or(Bech32Error::MissingSeparator)?;
+    if pos == 0 {
+        return Err(Bech32Error::EmptyPrefix);
+    }
+    if pos + CHECKSUM_LENGTH + 1 > bech.len() {
+        return Err(Bech32Error::InvalidLength(bech.len()));
+    }
+    let hrp = &bech[..pos];
+    if let Some(position) = hrp.bytes().position(|b| !(33..=126).contains(&b)) {
+        return Err(Bech32Error::InvalidPrefixCharacter(position));
+    }
+    let mut data = Vec::with_capacity(bech.len() - pos - 1);
+    for (i, character) in bech[pos + 1..].chars().enumerate() {
+        match CHARSET.iter().position(|c| *c as char == character) {
+            Some(value) => data.push(value as u8),
+            None => {
+                return Err(Bech32Error::InvalidCharacter {
+                    character,
+                    position: pos + 1 + i,
+                })
+            }
+        }
+    }
+    if !verify_checksum(hrp, &data) {
+        return Err(Bech32Error::InvalidChecksum {
+            typo_position: locate_typo(hrp, &data),
+        });
+    }
+    data.truncate(data.len() - CHECKSUM_LENGTH);
+    Ok((hrp.to_string(), data))
+}
+
+pub fn encode_puzzle_hash(puzzle_hash: &Bytes32, prefix: &str) -> String {
+    let data = convert_bits(&puzzle_hash.bytes, 8, 5, true).expect("8-bit values always convert");
+    bech32m_encode(prefix, &data)
+}
+
+pub fn decode_puzzle_hash(address: &str) -> Result<(String, Bytes32), Bech32Error> {
+    let (hrp, data) = bech32m_decode(address)?;
+    let decoded = convert_bits(&data, 5, 8, false)?;
+    if decoded.len() != 32 {
+        return Err(Bech32Error::WrongDataLength {
+            expected: 32,
+            got: decoded.len(),
+        });
+    }
+    Ok((hrp, Bytes32::from(decoded)))
+}
+
+#[test]
+fn test_bech32m_valid_vectors() {
+    for valid in [
+        "A1LQFN3A",
+        "a1lqfn3a",
+        "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+        "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+        "?1v759aa",
+    ] {
+        assert!(bech32m_decode(valid).is_ok(), "{}", valid);
+    }
+}
+
+#[test]
+fn test_bech32m_invalid_vectors() {
+    assert_eq!(bech32m_decode("1xj0phk"), Err(Bech32Error::EmptyPrefix));
+    assert_eq!(
+        bech32m_decode("qyrz8wqd2c9m"),
+        Err(Bech32Error::MissingSeparator)
+    );
+    assert_eq!(
+        bech32m_decode("M1VUXWEZ").unwrap_err(),
+        Bech32Error::InvalidChecksum {
+            typo_position: None
+        }
+    );
+    assert_eq!(bech32m_decode("A1lQfN3A"), Err(Bech32Error::MixedCase));
+}
+
+#[test]
+fn test_puzzle_hash_round_trip() {
+    let puzzle_hash: Bytes32 =
+        "0d8ff3a3a1e6a4e0ff5d4bd4a4c3d8e1ff3a1e0bd7b4f1c1a6f0f7d4c3b2a190".into();
+    let address = encode_puzzle_hash(&puzzle_hash, "xch");
+    assert!(address.starts_with("xch1"));
+    assert_eq!(address.len(), 62);
+    let (prefix, decoded) = decode_puzzle_hash(&address).unwrap();
+    assert_eq!(prefix, "xch");
+    assert_eq!(decoded, puzzle_hash);
+}
+
+#[test]
+fn test_typo_position() {
+    let puzzle_hash: Bytes32 =
+        "0d8ff3a3a1e6a4e0ff5d4bd4a4c3d8e1ff3a1e0bd7b4f1c1a6f0f7d4c3b2a190".into();
+    let address = encode_puzzle_hash(&puzzle_hash, "txch");
+    let mut chars: Vec<char> = address.chars().collect();
+    chars[20] = if chars[20] == 'q' { 'p' } else { 'q' };
+    let typo: String = chars.into_iter().collect();
+    assert_eq!(
+        decode_puzzle_hash(&typo),
+        Err(Bech32Error::InvalidChecksum {
+            typo_position: Some(20)
+        })
+    );
+}
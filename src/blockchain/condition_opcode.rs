@@ -3,7 +3,13 @@ use std::error::Error;
 
 #[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub enum ConditionOpcode {
-    UNKNOWN = 48,
+    Remark = 1,
+    AggSigParent = 43,
+    AggSigPuzzle = 44,
+    AggSigAmount = 45,
+    AggSigPuzzleAmount = 46,
+    AggSigParentAmount = 47,
+    AggSigParentPuzzle = 48,
     AggSigUnsafe = 49,
     AggSigMe = 50,
     CreateCoin = 51,
@@ -24,7 +30,13 @@ pub enum ConditionOpcode {
 impl ConditionOpcode {
     pub fn from_u8(b: u8) -> Result<Self, Box<dyn Error>> {
         match b {
-            48u8 => Ok(ConditionOpcode::UNKNOWN),
+            1u8 => Ok(ConditionOpcode::Remark),
+            43u8 => Ok(ConditionOpcode::AggSigParent),
+            44u8 => Ok(ConditionOpcode::AggSigPuzzle),
+            45u8 => Ok(ConditionOpcode::AggSigAmount),
+            46u8 => Ok(ConditionOpcode::AggSigPuzzleAmount),
+            47u8 => Ok(ConditionOpcode::AggSigParentAmount),
+            48u8 => Ok(ConditionOpcode::AggSigParentPuzzle),
             49u8 => Ok(ConditionOpcode::AggSigUnsafe),
             50u8 => Ok(ConditionOpcode::AggSigMe),
             51u8 => Ok(ConditionOpcode::CreateCoin),
@@ -1,4 +1,5 @@
 pub mod announcement;
+pub mod bech32m;
 pub mod block_record;
 pub mod blockchain_state;
 pub mod challenge_block_info;
@@ -1,3 +1,6 @@
+use crate::blockchain::bech32m::{decode_puzzle_hash, encode_puzzle_hash, Bech32Error};
+use crate::blockchain::sized_bytes::Bytes32;
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -5,3 +8,31 @@ pub struct NetworkInfo {
     pub network_name: String,
     pub network_prefix: String,
 }
+
+impl NetworkInfo {
+    pub fn puzzle_hash_to_address(&self, puzzle_hash: &Bytes32) -> String {
+        encode_puzzle_hash(puzzle_hash, &self.network_prefix)
+    }
+
+    pub fn address_to_puzzle_hash(&self, address: &str) -> Result<Bytes32, Bech32Error> {
+        let (prefix, puzzle_hash) = decode_puzzle_hash(address)?;
+        if prefix != self.network_prefix {
+            return Err(Bech32Error::WrongPrefix {
+                expected: self.network_prefix.clone(),
+                got: prefix,
+            });
+        }
+        Ok(puzzle_hash)
+    }
+}
+
+lazy_static! {
+    pub static ref MAINNET_INFO: NetworkInfo = NetworkInfo {
+        network_name: "mainnet".to_string(),
+        network_prefix: "xch".to_string(),
+    };
+    pub static ref TESTNET10_INFO: NetworkInfo = NetworkInfo {
+        network_name: "testnet10".to_string(),
+        network_prefix: "txch".to_string(),
+    };
+}
@@ -45,6 +45,26 @@ pub fn u64_to_bytes(v: u64) -> Vec<u8> {
     }
 }
 
+/// Why bytes or hex from outside the wallet do not make a sized byte string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SizedBytesError {
+    InvalidHex(FromHexError),
+    WrongLength { expected: usize, got: usize },
+}
+
+impl fmt::Display for SizedBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SizedBytesError::InvalidHex(e) => write!(f, "invalid hex: {}", e),
+            SizedBytesError::WrongLength { expected, got } => {
+                write!(f, "expected {} bytes, got {}", expected, got)
+            }
+        }
+    }
+}
+
+impl Error for SizedBytesError {}
+
 pub trait SizedBytes<'a>: Serialize + Deserialize<'a> + fmt::Display {
     const SIZE: usize;
     fn new(bytes: Vec<u8>) -> Self;
@@ -118,6 +138,32 @@ macro_rules! impl_sized_bytes {
                 }
             }
 
+            /// Unlike `From<Vec<u8>>`, which pads or truncates, rejects any other
+            /// length. Use it for bytes read from the chain or from callers.
+            impl TryFrom<&[u8]> for $name {
+                type Error = SizedBytesError;
+
+                fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                    if 0 != Self::SIZE && bytes.len() != Self::SIZE {
+                        return Err(SizedBytesError::WrongLength {
+                            expected: Self::SIZE,
+                            got: bytes.len(),
+                        });
+                    }
+                    Ok($name::new(bytes.to_vec()))
+                }
+            }
+
+            impl $name {
+                /// Parses hex, with or without `0x`, of exactly `SIZE` bytes. The
+                /// `From<&str>` conversion is only meant for literals and panics on bad hex.
+                pub fn from_hex(hex: &str) -> Result<Self, SizedBytesError> {
+                    let bytes = decode(prep_hex_str(&hex.to_string()))
+                        .map_err(SizedBytesError::InvalidHex)?;
+                    $name::try_from(bytes.as_slice())
+                }
+            }
+
             impl Into<Vec<u8>> for $name {
                 fn into(self) -> Vec<u8> {
                     self.bytes.clone()
@@ -167,16 +213,20 @@ macro_rules! impl_sized_bytes {
 
                 fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
                 where
-                    E: Error,
+                    E: serde::de::Error,
                 {
-                    Ok(value.into())
+                    self.visit_str(&value)
                 }
 
                 fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
                 where
-                    E: Error,
+                    E: serde::de::Error,
                 {
-                    Ok(value.into())
+                    let bytes = decode(prep_hex_str(&value.to_string())).map_err(E::custom)?;
+                    if 0 != $name::SIZE && bytes.len() != $name::SIZE {
+                        return Err(E::invalid_length(bytes.len(), &self));
+                    }
+                    Ok($name::new(bytes))
                 }
             }
 
@@ -219,3 +269,37 @@ impl_sized_bytes!(
     Bytes96, 96, Bytes96Visitor;
     Bytes192, 192, Bytes192Visitor
 );
+
+#[test]
+fn test_deserialize_hex() {
+    let bytes: Bytes4 = serde_json::from_str("\"0x0102a0ff\"").unwrap();
+    assert_eq!(bytes.bytes, vec![0x01, 0x02, 0xa0, 0xff]);
+    assert!(serde_json::from_str::<Bytes4>("\"0102a0\"").is_err());
+    assert!(serde_json::from_str::<Bytes4>("\"0102a0ff00\"").is_err());
+    assert!(serde_json::from_str::<Bytes4>("\"0102a0fg\"").is_err());
+    let unsized_bytes: UnsizedBytes = serde_json::from_str("\"0102a0\"").unwrap();
+    assert_eq!(unsized_bytes.bytes, vec![0x01, 0x02, 0xa0]);
+}
+
+#[test]
+fn test_try_from_rejects_wrong_lengths() {
+    assert_eq!(
+        Bytes4::try_from([1u8, 2, 3, 4].as_slice()).unwrap().bytes,
+        vec![1, 2, 3, 4]
+    );
+    assert_eq!(
+        Bytes4::try_from([1u8, 2, 3].as_slice()),
+        Err(SizedBytesError::WrongLength {
+            expected: 4,
+            got: 3
+        })
+    );
+    assert!(Bytes4::try_from([0u8; 5].as_slice()).is_err());
+    assert_eq!(
+        Bytes4::from_hex("0x0102A0ff").unwrap().bytes,
+        vec![1, 2, 0xa0, 0xff]
+    );
+    assert!(Bytes4::from_hex("0102a0").is_err());
+    assert!(Bytes4::from_hex("0102a0fg").is_err());
+    assert_eq!(UnsizedBytes::from_hex("01").unwrap().bytes, vec![1]);
+}
@@ -1,9 +1,280 @@
+use crate::blockchain::coin::Coin;
 use crate::blockchain::coin_spend::CoinSpend;
-use crate::blockchain::sized_bytes::Bytes96;
+use crate::blockchain::constants::Constants;
+use crate::blockchain::sized_bytes::{Bytes32, Bytes48, Bytes96, SizedBytes};
+use crate::program_utils::bls_bindings::{
+    aggregate_verify_signature, is_valid_public_key, is_valid_signature,
+};
+use crate::program_utils::condition_utils::pkm_pairs_for_coin_spend;
+use crate::program_utils::serialize::serialized_length_from_bytes;
+use crate::program_utils::serialized_program::SerializedProgram;
+use crate::program_utils::utils::INFINITE_COST;
 use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct SpendBundle {
     pub coin_spends: Vec<CoinSpend>,
     pub aggregated_signature: Bytes96,
 }
+
+#[derive(Debug)]
+pub enum SignatureError {
+    InvalidConditions {
+        index: usize,
+        coin_name: Bytes32,
+        reason: String,
+    },
+    InvalidPublicKey {
+        index: usize,
+        coin_name: Bytes32,
+        public_key: Bytes48,
+    },
+    InvalidSignatureEncoding,
+    /// The signature is an aggregate, so any of `signing_spends`, the spends with AGG_SIG
+    /// conditions, may be the one it does not cover.
+    VerificationFailed {
+        signing_spends: Vec<(usize, Bytes32)>,
+    },
+}
+
+impl SignatureError {
+    pub fn coin_spend(&self) -> Option<(usize, &Bytes32)> {
+        match self {
+            SignatureError::InvalidConditions {
+                index, coin_name, ..
+            } => Some((*index, coin_name)),
+            SignatureError::InvalidPublicKey {
+                index, coin_name, ..
+            } => Some((*index, coin_name)),
+            SignatureError::InvalidSignatureEncoding => None,
+            SignatureError::VerificationFailed { signing_spends } => {
+                match signing_spends.as_slice() {
+                    [(index, coin_name)] => Some((*index, coin_name)),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::InvalidConditions {
+                index,
+                coin_name,
+                reason,
+            } => write!(
+                f,
+                "coin spend {} ({}) has invalid conditions: {}",
+                index, coin_name, reason
+            ),
+            SignatureError::InvalidPublicKey {
+                index,
+                coin_name,
+                public_key,
+            } => write!(
+                f,
+                "coin spend {} ({}) requires a signature for invalid public key {}",
+                index, coin_name, public_key
+            ),
+            SignatureError::InvalidSignatureEncoding => {
+                write!(f, "aggregated signature is not a valid G2 element")
+            }
+            SignatureError::VerificationFailed { signing_spends } => {
+                match signing_spends.as_slice() {
+                    [] => write!(
+                    f,
+                    "no coin spend requires a signature, but the aggregated signature is not empty"
+                ),
+                    [(index, coin_name)] => write!(
+                        f,
+                        "aggregated signature does not match coin spend {} ({})",
+                        index, coin_name
+                    ),
+                    _ => {
+                        let spends: Vec<String> = signing_spends
+                            .iter()
+                            .map(|(index, coin_name)| format!("{} ({})", index, coin_name))
+                            .collect();
+                        write!(
+                            f,
+                            "aggregated signature does not match coin spends {}",
+                            spends.join(", ")
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Error for SignatureError {}
+
+fn is_infinity_signature(signature: &Bytes96) -> bool {
+    let bytes = signature.to_bytes();
+    bytes[0] == 0xc0 && bytes[1..].iter().all(|b| *b == 0)
+}
+
+fn take_bytes<'a>(bytes: &mut &'a [u8], count: usize) -> Result<&'a [u8], Box<dyn Error>> {
+    if bytes.len() < count {
+        return Err("spend bundle bytes end early".into());
+    }
+    let (taken, rest) = bytes.split_at(count);
+    *bytes = rest;
+    Ok(taken)
+}
+
+fn take_program(bytes: &mut &[u8]) -> Result<SerializedProgram, Box<dyn Error>> {
+    let length = serialized_length_from_bytes(bytes)? as usize;
+    Ok(SerializedProgram::from_bytes(
+        &take_bytes(bytes, length)?.to_vec(),
+    ))
+}
+
+impl SpendBundle {
+    /// Streamable serialization: a u32 count of coin spends, each coin followed by its
+    /// serialized puzzle reveal and solution, then the aggregated signature.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.coin_spends.len() as u32).to_be_bytes().to_vec();
+        for coin_spend in &self.coin_spends {
+            bytes.extend(coin_spend.coin.to_bytes());
+            bytes.extend(coin_spend.puzzle_reveal.to_bytes());
+            bytes.extend(coin_spend.solution.to_bytes());
+        }
+        bytes.extend(self.aggregated_signature.to_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<SpendBundle, Box<dyn Error>> {
+        let mut bytes = bytes;
+        let mut count = [0u8; 4];
+        count.copy_from_slice(take_bytes(&mut bytes, 4)?);
+        let count = u32::from_be_bytes(count);
+        let mut coin_spends = Vec::new();
+        for _ in 0..count {
+            let parent_coin_info = Bytes32::from(take_bytes(&mut bytes, 32)?.to_vec());
+            let puzzle_hash = Bytes32::from(take_bytes(&mut bytes, 32)?.to_vec());
+            let mut amount = [0u8; 8];
+            amount.copy_from_slice(take_bytes(&mut bytes, 8)?);
+            let coin = Coin {
+                parent_coin_info,
+                puzzle_hash,
+                amount: u64::from_be_bytes(amount),
+            };
+            let puzzle_reveal = take_program(&mut bytes)?;
+            let solution = take_program(&mut bytes)?;
+            coin_spends.push(CoinSpend {
+                coin,
+                puzzle_reveal,
+                solution,
+            });
+        }
+        let aggregated_signature = Bytes96::from(take_bytes(&mut bytes, 96)?.to_vec());
+        if !bytes.is_empty() {
+            return Err(format!("{} unexpected bytes after spend bundle", bytes.len()).into());
+        }
+        Ok(SpendBundle {
+            coin_spends,
+            aggregated_signature,
+        })
+    }
+
+    /// Checks `aggregated_signature` against every AGG_SIG condition in the bundle,
+    /// using the augmented scheme.
+    pub fn verify_signature(&self, constants: &Constants) -> Result<(), SignatureError> {
+        let mut public_keys: Vec<Bytes48> = Vec::new();
+        let mut msgs: Vec<Vec<u8>> = Vec::new();
+        let mut signing_spends: Vec<(usize, Bytes32)> = Vec::new();
+        for (index, coin_spend) in self.coin_spends.iter().enumerate() {
+            let coin_name = coin_spend.coin.name();
+            let pairs = pkm_pairs_for_coin_spend(
+                coin_spend,
+                &constants.genesis_challenge.to_bytes(),
+                INFINITE_COST,
+            )
+            .map_err(|e| SignatureError::InvalidConditions {
+                index,
+                coin_name: coin_name.clone(),
+                reason: e.to_string(),
+            })?;
+            if !pairs.is_empty() {
+                signing_spends.push((index, coin_name.clone()));
+            }
+            for (public_key, msg) in pairs {
+                if !is_valid_public_key(&public_key) {
+                    return Err(SignatureError::InvalidPublicKey {
+                        index,
+                        coin_name,
+                        public_key,
+                    });
+                }
+                public_keys.push(public_key);
+                msgs.push(msg);
+            }
+        }
+        if !is_valid_signature(&self.aggregated_signature) {
+            return Err(SignatureError::InvalidSignatureEncoding);
+        }
+        let valid = if public_keys.is_empty() {
+            is_infinity_signature(&self.aggregated_signature)
+        } else {
+            let public_keys: Vec<&Bytes48> = public_keys.iter().collect();
+            let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_slice()).collect();
+            aggregate_verify_signature(&public_keys, &msgs, &self.aggregated_signature)
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(SignatureError::VerificationFailed { signing_spends })
+        }
+    }
+}
+
+#[test]
+fn test_verify_signature() {
+    use crate::blockchain::constants::MAINNET;
+    use crate::chia_wallet::standart::transaction::empty_signature;
+    use crate::chia_wallet::test_utils::signed_standard_spend_bundle;
+
+    let single = signed_standard_spend_bundle(&[1]);
+    assert!(single.verify_signature(&MAINNET).is_ok());
+
+    let multiple = signed_standard_spend_bundle(&[1, 2, 3]);
+    assert!(multiple.verify_signature(&MAINNET).is_ok());
+
+    let mut missing = single.clone();
+    missing.aggregated_signature = empty_signature();
+    let error = missing.verify_signature(&MAINNET).unwrap_err();
+    assert_eq!(
+        error.coin_spend(),
+        Some((0, &single.coin_spends[0].coin.name()))
+    );
+
+    // A valid signature, but for other spends.
+    let mut wrong = multiple.clone();
+    wrong.aggregated_signature = signed_standard_spend_bundle(&[4, 5, 6]).aggregated_signature;
+    match wrong.verify_signature(&MAINNET).unwrap_err() {
+        SignatureError::VerificationFailed { signing_spends } => {
+            let names: Vec<Bytes32> = multiple
+                .coin_spends
+                .iter()
+                .map(|coin_spend| coin_spend.coin.name())
+                .collect();
+            assert_eq!(
+                signing_spends,
+                names.into_iter().enumerate().collect::<Vec<_>>()
+            );
+        }
+        error => panic!("unexpected error: {}", error),
+    }
+
+    let mut garbage = single;
+    garbage.aggregated_signature = Bytes96::from(vec![0x12; 96]);
+    assert!(matches!(
+        garbage.verify_signature(&MAINNET),
+        Err(SignatureError::InvalidSignatureEncoding)
+    ));
+}
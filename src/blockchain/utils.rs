@@ -50,3 +50,12 @@ pub fn atom_to_int(bytes: &Vec<u8>) -> BigInt {
         BigInt::from_signed_bytes_be(bytes)
     }
 }
+
+/// Minimal signed big-endian encoding, as CLVM expects for integer atoms.
+pub fn int_to_bytes(value: &BigInt) -> Vec<u8> {
+    if *value == BigInt::from(0) {
+        Vec::new()
+    } else {
+        value.to_signed_bytes_be()
+    }
+}
@@ -244,6 +244,890 @@ pub extern "C" fn wire_cats_create_cat_puzzle(
     wire_cats_create_cat_puzzle_impl(port_, tail_hash, inner_puzzle_hash)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_puzzle_hash_to_address(
+    port_: i64,
+    puzzle_hash: *mut wire_uint_8_list,
+    prefix: *mut wire_uint_8_list,
+) {
+    wire_puzzle_hash_to_address_impl(port_, puzzle_hash, prefix)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_address_to_puzzle_hash(
+    port_: i64,
+    address: *mut wire_uint_8_list,
+    prefix: *mut wire_uint_8_list,
+) {
+    wire_address_to_puzzle_hash_impl(port_, address, prefix)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_derive_puzzle_hashes_from_public_key(
+    port_: i64,
+    pk: *mut wire_uint_8_list,
+    from_intermediate: bool,
+    start: u32,
+    count: u32,
+) {
+    wire_derive_puzzle_hashes_from_public_key_impl(port_, pk, from_intermediate, start, count)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_derive_puzzle_hashes_from_secret_key(
+    port_: i64,
+    sk: *mut wire_uint_8_list,
+    start: u32,
+    count: u32,
+) {
+    wire_derive_puzzle_hashes_from_secret_key_impl(port_, sk, start, count)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_create(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    hint: *mut wire_uint_8_list,
+) {
+    wire_keyring_create_impl(port_, path, passphrase, hint)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_passphrase_hint(port_: i64, path: *mut wire_uint_8_list) {
+    wire_keyring_passphrase_hint_impl(port_, path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_list_keys(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+) {
+    wire_keyring_list_keys_impl(port_, path, passphrase)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_add_mnemonic(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    mnemonic: *mut wire_uint_8_list,
+    label: *mut wire_uint_8_list,
+) {
+    wire_keyring_add_mnemonic_impl(port_, path, passphrase, mnemonic, label)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_add_secret_key(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    sk: *mut wire_uint_8_list,
+    label: *mut wire_uint_8_list,
+) {
+    wire_keyring_add_secret_key_impl(port_, path, passphrase, sk, label)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_get_secret_key(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    fingerprint: u32,
+) {
+    wire_keyring_get_secret_key_impl(port_, path, passphrase, fingerprint)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_export_mnemonic(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    fingerprint: u32,
+) {
+    wire_keyring_export_mnemonic_impl(port_, path, passphrase, fingerprint)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_set_label(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    fingerprint: u32,
+    label: *mut wire_uint_8_list,
+) {
+    wire_keyring_set_label_impl(port_, path, passphrase, fingerprint, label)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_remove_key(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    fingerprint: u32,
+) {
+    wire_keyring_remove_key_impl(port_, path, passphrase, fingerprint)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keyring_change_passphrase(
+    port_: i64,
+    path: *mut wire_uint_8_list,
+    current_passphrase: *mut wire_uint_8_list,
+    new_passphrase: *mut wire_uint_8_list,
+    hint: *mut wire_uint_8_list,
+) {
+    wire_keyring_change_passphrase_impl(port_, path, current_passphrase, new_passphrase, hint)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_mnemonic_generate(port_: i64, word_count: u32) {
+    wire_mnemonic_generate_impl(port_, word_count)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_mnemonic_to_seed(
+    port_: i64,
+    mnemonic_words: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+) {
+    wire_mnemonic_to_seed_impl(port_, mnemonic_words, passphrase)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_mnemonic_validate(port_: i64, mnemonic_words: *mut wire_uint_8_list) {
+    wire_mnemonic_validate_impl(port_, mnemonic_words)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_public_key_fingerprint(port_: i64, pk: *mut wire_uint_8_list) {
+    wire_public_key_fingerprint_impl(port_, pk)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_key_info_from_secret_key(
+    port_: i64,
+    sk: *mut wire_uint_8_list,
+    prefix: *mut wire_uint_8_list,
+) {
+    wire_key_info_from_secret_key_impl(port_, sk, prefix)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_key_info_from_public_key(
+    port_: i64,
+    pk: *mut wire_uint_8_list,
+    prefix: *mut wire_uint_8_list,
+) {
+    wire_key_info_from_public_key_impl(port_, pk, prefix)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_key_info_from_mnemonic(
+    port_: i64,
+    mnemonic_words: *mut wire_uint_8_list,
+    passphrase: *mut wire_uint_8_list,
+    prefix: *mut wire_uint_8_list,
+) {
+    wire_key_info_from_mnemonic_impl(port_, mnemonic_words, passphrase, prefix)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_spend_bundle_sign(
+    port_: i64,
+    spend_bundle_json: *mut wire_uint_8_list,
+    secret_keys: *mut wire_StringList,
+    network: *mut wire_uint_8_list,
+) {
+    wire_spend_bundle_sign_impl(port_, spend_bundle_json, secret_keys, network)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_spend_bundle_verify_signature(
+    port_: i64,
+    spend_bundle_json: *mut wire_uint_8_list,
+    network: *mut wire_uint_8_list,
+) {
+    wire_spend_bundle_verify_signature_impl(port_, spend_bundle_json, network)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_pub_calculate_synthetic_secret_key(
+    port_: i64,
+    sk: *mut wire_uint_8_list,
+    hidden_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_pub_calculate_synthetic_secret_key_impl(port_, sk, hidden_puzzle_hash)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_pub_calculate_synthetic_public_key(
+    port_: i64,
+    pk: *mut wire_uint_8_list,
+    hidden_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_pub_calculate_synthetic_public_key_impl(port_, pk, hidden_puzzle_hash)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_standard_transaction_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    payments_json: *mut wire_uint_8_list,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_standard_transaction_create_impl(port_, coins_json, payments_json, fee, change_puzzle_hash)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_coin_selection_select(
+    port_: i64,
+    coin_records_json: *mut wire_uint_8_list,
+    amount: u64,
+    strategy: *mut wire_uint_8_list,
+    max_coins: u32,
+    min_coin_amount: u64,
+    excluded_coin_ids: *mut wire_StringList,
+) {
+    wire_coin_selection_select_impl(
+        port_,
+        coin_records_json,
+        amount,
+        strategy,
+        max_coins,
+        min_coin_amount,
+        excluded_coin_ids,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cat_spend_bundle_create(
+    port_: i64,
+    spendable_cats_json: *mut wire_uint_8_list,
+) {
+    wire_cat_spend_bundle_create_impl(port_, spendable_cats_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cat_parse_child(
+    port_: i64,
+    parent_coin_spend_json: *mut wire_uint_8_list,
+    child_coin_json: *mut wire_uint_8_list,
+) {
+    wire_cat_parse_child_impl(port_, parent_coin_spend_json, child_coin_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cat_tail_puzzle(
+    port_: i64,
+    kind: *mut wire_uint_8_list,
+    argument: *mut wire_uint_8_list,
+) {
+    wire_cat_tail_puzzle_impl(port_, kind, argument)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cat_issuance_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    tail_kind: *mut wire_uint_8_list,
+    tail_argument: *mut wire_uint_8_list,
+    tail_solution: *mut wire_uint_8_list,
+    inner_public_key: *mut wire_uint_8_list,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_cat_issuance_create_impl(
+        port_,
+        coins_json,
+        tail_kind,
+        tail_argument,
+        tail_solution,
+        inner_public_key,
+        amount,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cat_melt_create(
+    port_: i64,
+    cats_json: *mut wire_uint_8_list,
+    tail_kind: *mut wire_uint_8_list,
+    tail_argument: *mut wire_uint_8_list,
+    tail_solution: *mut wire_uint_8_list,
+    melt_amount: u64,
+    output_inner_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_cat_melt_create_impl(
+        port_,
+        cats_json,
+        tail_kind,
+        tail_argument,
+        tail_solution,
+        melt_amount,
+        output_inner_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cat_reissue_create(
+    port_: i64,
+    cats_json: *mut wire_uint_8_list,
+    tail_kind: *mut wire_uint_8_list,
+    tail_argument: *mut wire_uint_8_list,
+    tail_solution: *mut wire_uint_8_list,
+    reissue_amount: u64,
+    output_inner_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_cat_reissue_create_impl(
+        port_,
+        cats_json,
+        tail_kind,
+        tail_argument,
+        tail_solution,
+        reissue_amount,
+        output_inner_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_singleton_launch_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    inner_puzzle: *mut wire_uint_8_list,
+    key_value_list: *mut wire_uint_8_list,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_singleton_launch_create_impl(
+        port_,
+        coins_json,
+        inner_puzzle,
+        key_value_list,
+        amount,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_singleton_puzzle(
+    port_: i64,
+    launcher_id: *mut wire_uint_8_list,
+    inner_puzzle: *mut wire_uint_8_list,
+) {
+    wire_singleton_puzzle_impl(port_, launcher_id, inner_puzzle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_singleton_lineage_proof(
+    port_: i64,
+    parent_coin_spend_json: *mut wire_uint_8_list,
+) {
+    wire_singleton_lineage_proof_impl(port_, parent_coin_spend_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_singleton_spend_create(
+    port_: i64,
+    coin_json: *mut wire_uint_8_list,
+    launcher_id: *mut wire_uint_8_list,
+    inner_puzzle: *mut wire_uint_8_list,
+    inner_solution: *mut wire_uint_8_list,
+    lineage_proof_json: *mut wire_uint_8_list,
+) {
+    wire_singleton_spend_create_impl(
+        port_,
+        coin_json,
+        launcher_id,
+        inner_puzzle,
+        inner_solution,
+        lineage_proof_json,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_singleton_parse(port_: i64, puzzle: *mut wire_uint_8_list) {
+    wire_singleton_parse_impl(port_, puzzle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_nft_mint_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    mint_json: *mut wire_uint_8_list,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_nft_mint_create_impl(port_, coins_json, mint_json, fee, change_puzzle_hash)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_nft_transfer_create(
+    port_: i64,
+    coin_json: *mut wire_uint_8_list,
+    nft_puzzle: *mut wire_uint_8_list,
+    public_key: *mut wire_uint_8_list,
+    lineage_proof_json: *mut wire_uint_8_list,
+    new_puzzle_hash: *mut wire_uint_8_list,
+    new_did_owner_json: *mut wire_uint_8_list,
+) {
+    wire_nft_transfer_create_impl(
+        port_,
+        coin_json,
+        nft_puzzle,
+        public_key,
+        lineage_proof_json,
+        new_puzzle_hash,
+        new_did_owner_json,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_nft_parse(port_: i64, puzzle: *mut wire_uint_8_list) {
+    wire_nft_parse_impl(port_, puzzle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_did_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    public_key: *mut wire_uint_8_list,
+    recovery_list: *mut wire_StringList,
+    num_verifications_required: u64,
+    metadata_json: *mut wire_uint_8_list,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_did_create_impl(
+        port_,
+        coins_json,
+        public_key,
+        recovery_list,
+        num_verifications_required,
+        metadata_json,
+        amount,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_did_parse(port_: i64, puzzle: *mut wire_uint_8_list) {
+    wire_did_parse_impl(port_, puzzle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_did_transfer_create(
+    port_: i64,
+    coin_json: *mut wire_uint_8_list,
+    did_puzzle: *mut wire_uint_8_list,
+    public_key: *mut wire_uint_8_list,
+    lineage_proof_json: *mut wire_uint_8_list,
+    new_p2_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_did_transfer_create_impl(
+        port_,
+        coin_json,
+        did_puzzle,
+        public_key,
+        lineage_proof_json,
+        new_p2_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_did_metadata_update_create(
+    port_: i64,
+    coin_json: *mut wire_uint_8_list,
+    did_puzzle: *mut wire_uint_8_list,
+    public_key: *mut wire_uint_8_list,
+    lineage_proof_json: *mut wire_uint_8_list,
+    metadata_json: *mut wire_uint_8_list,
+) {
+    wire_did_metadata_update_create_impl(
+        port_,
+        coin_json,
+        did_puzzle,
+        public_key,
+        lineage_proof_json,
+        metadata_json,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_did_nft_assignment_create(
+    port_: i64,
+    coin_json: *mut wire_uint_8_list,
+    did_puzzle: *mut wire_uint_8_list,
+    public_key: *mut wire_uint_8_list,
+    lineage_proof_json: *mut wire_uint_8_list,
+    nft_launcher_ids: *mut wire_StringList,
+) {
+    wire_did_nft_assignment_create_impl(
+        port_,
+        coin_json,
+        did_puzzle,
+        public_key,
+        lineage_proof_json,
+        nft_launcher_ids,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_offer_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    offered_xch: u64,
+    offered_cats_json: *mut wire_uint_8_list,
+    requested_json: *mut wire_uint_8_list,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+    secret_keys: *mut wire_StringList,
+    network: *mut wire_uint_8_list,
+) {
+    wire_offer_create_impl(
+        port_,
+        coins_json,
+        offered_xch,
+        offered_cats_json,
+        requested_json,
+        fee,
+        change_puzzle_hash,
+        secret_keys,
+        network,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_offer_summary(port_: i64, offer: *mut wire_uint_8_list) {
+    wire_offer_summary_impl(port_, offer)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_offer_take(
+    port_: i64,
+    offer: *mut wire_uint_8_list,
+    coins_json: *mut wire_uint_8_list,
+    cat_coins_json: *mut wire_uint_8_list,
+    receive_puzzle_hash: *mut wire_uint_8_list,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+    secret_keys: *mut wire_StringList,
+    network: *mut wire_uint_8_list,
+) {
+    wire_offer_take_impl(
+        port_,
+        offer,
+        coins_json,
+        cat_coins_json,
+        receive_puzzle_hash,
+        fee,
+        change_puzzle_hash,
+        secret_keys,
+        network,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_spend_bundle_compress(port_: i64, spend_bundle_json: *mut wire_uint_8_list) {
+    wire_spend_bundle_compress_impl(port_, spend_bundle_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_spend_bundle_decompress(port_: i64, compressed: *mut wire_uint_8_list) {
+    wire_spend_bundle_decompress_impl(port_, compressed)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_puzzle_identify(port_: i64, puzzle: *mut wire_uint_8_list) {
+    wire_puzzle_identify_impl(port_, puzzle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_coin_spend_identify(port_: i64, coin_spend_json: *mut wire_uint_8_list) {
+    wire_coin_spend_identify_impl(port_, coin_spend_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_puzzle_driver_register(
+    port_: i64,
+    name: *mut wire_uint_8_list,
+    mod_hash: *mut wire_uint_8_list,
+    arg_names: *mut wire_StringList,
+    inner_puzzle_arg: *mut wire_uint_8_list,
+) {
+    wire_puzzle_driver_register_impl(port_, name, mod_hash, arg_names, inner_puzzle_arg)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_clawback_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    sender_puzzle_hash: *mut wire_uint_8_list,
+    recipient_puzzle_hash: *mut wire_uint_8_list,
+    amount: u64,
+    time_lock: u64,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_clawback_create_impl(
+        port_,
+        coins_json,
+        sender_puzzle_hash,
+        recipient_puzzle_hash,
+        amount,
+        time_lock,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_clawback_claim_create(
+    port_: i64,
+    coin_json: *mut wire_uint_8_list,
+    metadata_json: *mut wire_uint_8_list,
+    public_key: *mut wire_uint_8_list,
+    destination_puzzle_hash: *mut wire_uint_8_list,
+    fee: u64,
+) {
+    wire_clawback_claim_create_impl(
+        port_,
+        coin_json,
+        metadata_json,
+        public_key,
+        destination_puzzle_hash,
+        fee,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_clawback_spend_create(
+    port_: i64,
+    coin_json: *mut wire_uint_8_list,
+    metadata_json: *mut wire_uint_8_list,
+    public_key: *mut wire_uint_8_list,
+    destination_puzzle_hash: *mut wire_uint_8_list,
+    fee: u64,
+) {
+    wire_clawback_spend_create_impl(
+        port_,
+        coin_json,
+        metadata_json,
+        public_key,
+        destination_puzzle_hash,
+        fee,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_clawback_parse_coins(
+    port_: i64,
+    parent_coin_spend_json: *mut wire_uint_8_list,
+) {
+    wire_clawback_parse_coins_impl(port_, parent_coin_spend_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_plot_nft_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    owner_public_key: *mut wire_uint_8_list,
+    target_puzzle_hash: *mut wire_uint_8_list,
+    pool_url: *mut wire_uint_8_list,
+    relative_lock_height: u32,
+    delay_puzzle_hash: *mut wire_uint_8_list,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+    network: *mut wire_uint_8_list,
+) {
+    wire_plot_nft_create_impl(
+        port_,
+        coins_json,
+        owner_public_key,
+        target_puzzle_hash,
+        pool_url,
+        relative_lock_height,
+        delay_puzzle_hash,
+        fee,
+        change_puzzle_hash,
+        network,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_plot_nft_parse(port_: i64, coin_spends_json: *mut wire_uint_8_list) {
+    wire_plot_nft_parse_impl(port_, coin_spends_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_plot_nft_p2_singleton_puzzle_hash(
+    port_: i64,
+    plot_nft_json: *mut wire_uint_8_list,
+) {
+    wire_plot_nft_p2_singleton_puzzle_hash_impl(port_, plot_nft_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_plot_nft_travel_create(
+    port_: i64,
+    plot_nft_json: *mut wire_uint_8_list,
+    target_puzzle_hash: *mut wire_uint_8_list,
+    pool_url: *mut wire_uint_8_list,
+    relative_lock_height: u32,
+    network: *mut wire_uint_8_list,
+) {
+    wire_plot_nft_travel_create_impl(
+        port_,
+        plot_nft_json,
+        target_puzzle_hash,
+        pool_url,
+        relative_lock_height,
+        network,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_plot_nft_absorb_create(
+    port_: i64,
+    plot_nft_json: *mut wire_uint_8_list,
+    reward_coins_json: *mut wire_uint_8_list,
+    network: *mut wire_uint_8_list,
+) {
+    wire_plot_nft_absorb_create_impl(port_, plot_nft_json, reward_coins_json, network)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_multisig_puzzle(
+    port_: i64,
+    threshold: u32,
+    public_keys: *mut wire_StringList,
+) {
+    wire_multisig_puzzle_impl(port_, threshold, public_keys)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_multisig_puzzle_hash(
+    port_: i64,
+    threshold: u32,
+    public_keys: *mut wire_StringList,
+) {
+    wire_multisig_puzzle_hash_impl(port_, threshold, public_keys)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_multisig_address(
+    port_: i64,
+    threshold: u32,
+    public_keys: *mut wire_StringList,
+    prefix: *mut wire_uint_8_list,
+) {
+    wire_multisig_address_impl(port_, threshold, public_keys, prefix)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_multisig_transaction_create(
+    port_: i64,
+    threshold: u32,
+    public_keys: *mut wire_StringList,
+    coins_json: *mut wire_uint_8_list,
+    signers: *mut wire_StringList,
+    payments_json: *mut wire_uint_8_list,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_multisig_transaction_create_impl(
+        port_,
+        threshold,
+        public_keys,
+        coins_json,
+        signers,
+        payments_json,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_multisig_sign(
+    port_: i64,
+    spend_bundle_json: *mut wire_uint_8_list,
+    sk: *mut wire_uint_8_list,
+    network: *mut wire_uint_8_list,
+) {
+    wire_multisig_sign_impl(port_, spend_bundle_json, sk, network)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_multisig_add_signatures(
+    port_: i64,
+    spend_bundle_json: *mut wire_uint_8_list,
+    partial_signatures: *mut wire_StringList,
+    network: *mut wire_uint_8_list,
+) {
+    wire_multisig_add_signatures_impl(port_, spend_bundle_json, partial_signatures, network)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_notification_create(
+    port_: i64,
+    coins_json: *mut wire_uint_8_list,
+    target_puzzle_hash: *mut wire_uint_8_list,
+    message: *mut wire_uint_8_list,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: *mut wire_uint_8_list,
+) {
+    wire_notification_create_impl(
+        port_,
+        coins_json,
+        target_puzzle_hash,
+        message,
+        amount,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn wire_notification_parse(
+    port_: i64,
+    parent_coin_spend_json: *mut wire_uint_8_list,
+) {
+    wire_notification_parse_impl(port_, parent_coin_spend_json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_message_sign(
+    port_: i64,
+    sk: *mut wire_uint_8_list,
+    message: *mut wire_uint_8_list,
+    is_hex: bool,
+    address: *mut wire_uint_8_list,
+) {
+    wire_message_sign_impl(port_, sk, message, is_hex, address)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_message_verify(
+    port_: i64,
+    pk: *mut wire_uint_8_list,
+    message: *mut wire_uint_8_list,
+    is_hex: bool,
+    sig: *mut wire_uint_8_list,
+    address: *mut wire_uint_8_list,
+) {
+    wire_message_verify_impl(port_, pk, message, is_hex, sig, address)
+}
+
 // Section: allocate functions
 
 #[no_mangle]
@@ -30,7 +30,7 @@ fn wire_secret_key_from_seed_impl(port_: MessagePort, seed: impl Wire2Api<Vec<u8
         },
         move || {
             let api_seed = seed.wire2api();
-            move |task_callback| Ok(secret_key_from_seed(api_seed))
+            move |task_callback| secret_key_from_seed(api_seed)
         },
     )
 }
@@ -43,7 +43,7 @@ fn wire_secret_key_public_key_impl(port_: MessagePort, sk: impl Wire2Api<Vec<u8>
         },
         move || {
             let api_sk = sk.wire2api();
-            move |task_callback| Ok(secret_key_public_key(api_sk))
+            move |task_callback| secret_key_public_key(api_sk)
         },
     )
 }
@@ -61,7 +61,7 @@ fn wire_secret_key_derive_path_hardened_impl(
         move || {
             let api_sk = sk.wire2api();
             let api_path = path.wire2api();
-            move |task_callback| Ok(secret_key_derive_path_hardened(api_sk, api_path))
+            move |task_callback| secret_key_derive_path_hardened(api_sk, api_path)
         },
     )
 }
@@ -79,7 +79,7 @@ fn wire_secret_key_derive_path_unhardened_impl(
         move || {
             let api_sk = sk.wire2api();
             let api_path = path.wire2api();
-            move |task_callback| Ok(secret_key_derive_path_unhardened(api_sk, api_path))
+            move |task_callback| secret_key_derive_path_unhardened(api_sk, api_path)
         },
     )
 }
@@ -97,7 +97,7 @@ fn wire_public_key_derive_path_unhardened_impl(
         move || {
             let api_sk = sk.wire2api();
             let api_path = path.wire2api();
-            move |task_callback| Ok(public_key_derive_path_unhardened(api_sk, api_path))
+            move |task_callback| public_key_derive_path_unhardened(api_sk, api_path)
         },
     )
 }
@@ -115,7 +115,7 @@ fn wire_signature_sign_impl(
         move || {
             let api_sk = sk.wire2api();
             let api_msg = msg.wire2api();
-            move |task_callback| Ok(signature_sign(api_sk, api_msg))
+            move |task_callback| signature_sign(api_sk, api_msg)
         },
     )
 }
@@ -128,7 +128,7 @@ fn wire_signature_is_valid_impl(port_: MessagePort, sig: impl Wire2Api<Vec<u8>>
         },
         move || {
             let api_sig = sig.wire2api();
-            move |task_callback| Ok(signature_is_valid(api_sig))
+            move |task_callback| signature_is_valid(api_sig)
         },
     )
 }
@@ -146,7 +146,7 @@ fn wire_signature_aggregate_impl(
         move || {
             let api_sigs_stream = sigs_stream.wire2api();
             let api_length = length.wire2api();
-            move |task_callback| Ok(signature_aggregate(api_sigs_stream, api_length))
+            move |task_callback| signature_aggregate(api_sigs_stream, api_length)
         },
     )
 }
@@ -166,7 +166,7 @@ fn wire_signature_verify_impl(
             let api_pk = pk.wire2api();
             let api_msg = msg.wire2api();
             let api_sig = sig.wire2api();
-            move |task_callback| Ok(signature_verify(api_pk, api_msg, api_sig))
+            move |task_callback| signature_verify(api_pk, api_msg, api_sig)
         },
     )
 }
@@ -182,7 +182,7 @@ fn wire_pub_mnemonic_to_entropy_impl(
         },
         move || {
             let api_mnemonic_words = mnemonic_words.wire2api();
-            move |task_callback| Ok(pub_mnemonic_to_entropy(api_mnemonic_words))
+            move |task_callback| pub_mnemonic_to_entropy(api_mnemonic_words)
         },
     )
 }
@@ -198,7 +198,7 @@ fn wire_pub_entropy_to_mnemonic_impl(
         },
         move || {
             let api_entropy = entropy.wire2api();
-            move |task_callback| Ok(pub_entropy_to_mnemonic(api_entropy))
+            move |task_callback| pub_entropy_to_mnemonic(api_entropy)
         },
     )
 }
@@ -211,7 +211,7 @@ fn wire_pub_entropy_to_seed_impl(port_: MessagePort, entropy: impl Wire2Api<Vec<
         },
         move || {
             let api_entropy = entropy.wire2api();
-            move |task_callback| Ok(pub_entropy_to_seed(api_entropy))
+            move |task_callback| pub_entropy_to_seed(api_entropy)
         },
     )
 }
@@ -237,7 +237,7 @@ fn wire_hex_to_bytes_impl(port_: MessagePort, hex: impl Wire2Api<String> + Unwin
         },
         move || {
             let api_hex = hex.wire2api();
-            move |task_callback| Ok(hex_to_bytes(api_hex))
+            move |task_callback| hex_to_bytes(api_hex)
         },
     )
 }
@@ -266,7 +266,7 @@ fn wire_pub_master_to_wallet_unhardened_intermediate_impl(
         },
         move || {
             let api_master = master.wire2api();
-            move |task_callback| Ok(pub_master_to_wallet_unhardened_intermediate(api_master))
+            move |task_callback| pub_master_to_wallet_unhardened_intermediate(api_master)
         },
     )
 }
@@ -284,7 +284,7 @@ fn wire_pub_master_to_wallet_unhardened_impl(
         move || {
             let api_master = master.wire2api();
             let api_idx = idx.wire2api();
-            move |task_callback| Ok(pub_master_to_wallet_unhardened(api_master, api_idx))
+            move |task_callback| pub_master_to_wallet_unhardened(api_master, api_idx)
         },
     )
 }
@@ -300,7 +300,7 @@ fn wire_pub_master_to_wallet_hardened_intermediate_impl(
         },
         move || {
             let api_master = master.wire2api();
-            move |task_callback| Ok(pub_master_to_wallet_hardened_intermediate(api_master))
+            move |task_callback| pub_master_to_wallet_hardened_intermediate(api_master)
         },
     )
 }
@@ -318,7 +318,7 @@ fn wire_pub_master_to_wallet_hardened_impl(
         move || {
             let api_master = master.wire2api();
             let api_idx = idx.wire2api();
-            move |task_callback| Ok(pub_master_to_wallet_hardened(api_master, api_idx))
+            move |task_callback| pub_master_to_wallet_hardened(api_master, api_idx)
         },
     )
 }
@@ -336,12 +336,7 @@ fn wire_pub_master_to_pool_singleton_impl(
         move || {
             let api_master = master.wire2api();
             let api_pool_wallet_idx = pool_wallet_idx.wire2api();
-            move |task_callback| {
-                Ok(pub_master_to_pool_singleton(
-                    api_master,
-                    api_pool_wallet_idx,
-                ))
-            }
+            move |task_callback| pub_master_to_pool_singleton(api_master, api_pool_wallet_idx)
         },
     )
 }
@@ -362,11 +357,7 @@ fn wire_pub_master_to_pool_authentication_impl(
             let api_pool_wallet_idx = pool_wallet_idx.wire2api();
             let api_idx = idx.wire2api();
             move |task_callback| {
-                Ok(pub_master_to_pool_authentication(
-                    api_sk,
-                    api_pool_wallet_idx,
-                    api_idx,
-                ))
+                pub_master_to_pool_authentication(api_sk, api_pool_wallet_idx, api_idx)
             }
         },
     )
@@ -380,7 +371,7 @@ fn wire_cmds_program_run_impl(port_: MessagePort, args: impl Wire2Api<Vec<String
         },
         move || {
             let api_args = args.wire2api();
-            move |task_callback| Ok(cmds_program_run(api_args))
+            move |task_callback| cmds_program_run(api_args)
         },
     )
 }
@@ -393,7 +384,7 @@ fn wire_cmds_program_brun_impl(port_: MessagePort, args: impl Wire2Api<Vec<Strin
         },
         move || {
             let api_args = args.wire2api();
-            move |task_callback| Ok(cmds_program_brun(api_args))
+            move |task_callback| cmds_program_brun(api_args)
         },
     )
 }
@@ -406,7 +397,7 @@ fn wire_cmd_program_opc_impl(port_: MessagePort, args: impl Wire2Api<Vec<String>
         },
         move || {
             let api_args = args.wire2api();
-            move |task_callback| Ok(cmd_program_opc(api_args))
+            move |task_callback| cmd_program_opc(api_args)
         },
     )
 }
@@ -419,7 +410,7 @@ fn wire_cmd_program_opd_impl(port_: MessagePort, args: impl Wire2Api<Vec<String>
         },
         move || {
             let api_args = args.wire2api();
-            move |task_callback| Ok(cmd_program_opd(api_args))
+            move |task_callback| cmd_program_opd(api_args)
         },
     )
 }
@@ -432,7 +423,7 @@ fn wire_cmd_program_cldb_impl(port_: MessagePort, args: impl Wire2Api<Vec<String
         },
         move || {
             let api_args = args.wire2api();
-            move |task_callback| Ok(cmd_program_cldb(api_args))
+            move |task_callback| cmd_program_cldb(api_args)
         },
     )
 }
@@ -448,7 +439,7 @@ fn wire_program_tree_hash_impl(
         },
         move || {
             let api_ser_program_bytes = ser_program_bytes.wire2api();
-            move |task_callback| Ok(program_tree_hash(api_ser_program_bytes))
+            move |task_callback| program_tree_hash(api_ser_program_bytes)
         },
     )
 }
@@ -466,7 +457,7 @@ fn wire_program_curry_impl(
         move || {
             let api_ser_program_bytes = ser_program_bytes.wire2api();
             let api_args_str = args_str.wire2api();
-            move |task_callback| Ok(program_curry(api_ser_program_bytes, api_args_str))
+            move |task_callback| program_curry(api_ser_program_bytes, api_args_str)
         },
     )
 }
@@ -482,7 +473,7 @@ fn wire_program_uncurry_impl(
         },
         move || {
             let api_ser_program_bytes = ser_program_bytes.wire2api();
-            move |task_callback| Ok(program_uncurry(api_ser_program_bytes))
+            move |task_callback| program_uncurry(api_ser_program_bytes)
         },
     )
 }
@@ -498,7 +489,7 @@ fn wire_program_from_list_impl(
         },
         move || {
             let api_program_list = program_list.wire2api();
-            move |task_callback| Ok(program_from_list(api_program_list))
+            move |task_callback| program_from_list(api_program_list)
         },
     )
 }
@@ -514,7 +505,7 @@ fn wire_program_disassemble_impl(
         },
         move || {
             let api_ser_program_bytes = ser_program_bytes.wire2api();
-            move |task_callback| Ok(program_disassemble(api_ser_program_bytes))
+            move |task_callback| program_disassemble(api_ser_program_bytes)
         },
     )
 }
@@ -532,7 +523,7 @@ fn wire_program_run_impl(
         move || {
             let api_ser_program_bytes = ser_program_bytes.wire2api();
             let api_args_str = args_str.wire2api();
-            move |task_callback| Ok(program_run(api_ser_program_bytes, api_args_str))
+            move |task_callback| program_run(api_ser_program_bytes, api_args_str)
         },
     )
 }
@@ -564,7 +555,7 @@ fn wire_program_to_atom_bytes_impl(
         },
         move || {
             let api_ser_program_bytes = ser_program_bytes.wire2api();
-            move |task_callback| Ok(program_to_atom_bytes(api_ser_program_bytes))
+            move |task_callback| program_to_atom_bytes(api_ser_program_bytes)
         },
     )
 }
@@ -580,7 +571,7 @@ fn wire_get_puzzle_from_public_key_impl(
         },
         move || {
             let api_pk = pk.wire2api();
-            move |task_callback| Ok(get_puzzle_from_public_key(api_pk))
+            move |task_callback| get_puzzle_from_public_key(api_pk)
         },
     )
 }
@@ -598,64 +589,1931 @@ fn wire_cats_create_cat_puzzle_impl(
         move || {
             let api_tail_hash = tail_hash.wire2api();
             let api_inner_puzzle_hash = inner_puzzle_hash.wire2api();
-            move |task_callback| Ok(cats_create_cat_puzzle(api_tail_hash, api_inner_puzzle_hash))
+            move |task_callback| cats_create_cat_puzzle(api_tail_hash, api_inner_puzzle_hash)
         },
     )
 }
-// Section: wrapper structs
-
-// Section: static checks
-
-// Section: allocate functions
-
-// Section: related functions
-
-// Section: impl Wire2Api
-
-pub trait Wire2Api<T> {
-    fn wire2api(self) -> T;
+fn wire_puzzle_hash_to_address_impl(
+    port_: MessagePort,
+    puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    prefix: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "puzzle_hash_to_address",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_puzzle_hash = puzzle_hash.wire2api();
+            let api_prefix = prefix.wire2api();
+            move |task_callback| puzzle_hash_to_address(api_puzzle_hash, api_prefix)
+        },
+    )
 }
-
-impl<T, S> Wire2Api<Option<T>> for *mut S
-where
-    *mut S: Wire2Api<T>,
-{
-    fn wire2api(self) -> Option<T> {
-        (!self.is_null()).then(|| self.wire2api())
-    }
+fn wire_address_to_puzzle_hash_impl(
+    port_: MessagePort,
+    address: impl Wire2Api<String> + UnwindSafe,
+    prefix: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "address_to_puzzle_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_address = address.wire2api();
+            let api_prefix = prefix.wire2api();
+            move |task_callback| address_to_puzzle_hash(api_address, api_prefix)
+        },
+    )
 }
-
-impl Wire2Api<u32> for u32 {
-    fn wire2api(self) -> u32 {
-        self
-    }
+fn wire_derive_puzzle_hashes_from_public_key_impl(
+    port_: MessagePort,
+    pk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    from_intermediate: impl Wire2Api<bool> + UnwindSafe,
+    start: impl Wire2Api<u32> + UnwindSafe,
+    count: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "derive_puzzle_hashes_from_public_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pk = pk.wire2api();
+            let api_from_intermediate = from_intermediate.wire2api();
+            let api_start = start.wire2api();
+            let api_count = count.wire2api();
+            move |task_callback| {
+                derive_puzzle_hashes_from_public_key(
+                    api_pk,
+                    api_from_intermediate,
+                    api_start,
+                    api_count,
+                )
+            }
+        },
+    )
 }
-impl Wire2Api<u8> for u8 {
-    fn wire2api(self) -> u8 {
-        self
-    }
+fn wire_derive_puzzle_hashes_from_secret_key_impl(
+    port_: MessagePort,
+    sk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    start: impl Wire2Api<u32> + UnwindSafe,
+    count: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "derive_puzzle_hashes_from_secret_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_sk = sk.wire2api();
+            let api_start = start.wire2api();
+            let api_count = count.wire2api();
+            move |task_callback| derive_puzzle_hashes_from_secret_key(api_sk, api_start, api_count)
+        },
+    )
 }
-
-impl Wire2Api<usize> for usize {
-    fn wire2api(self) -> usize {
-        self
-    }
+fn wire_keyring_create_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+    hint: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_hint = hint.wire2api();
+            move |task_callback| keyring_create(api_path, api_passphrase, api_hint)
+        },
+    )
 }
-// Section: impl IntoDart
-
-impl support::IntoDart for ApiOutputProgram {
-    fn into_dart(self) -> support::DartAbi {
-        vec![self.program.into_dart(), self.cost.into_dart()].into_dart()
-    }
+fn wire_keyring_passphrase_hint_impl(port_: MessagePort, path: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_passphrase_hint",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            move |task_callback| keyring_passphrase_hint(api_path)
+        },
+    )
 }
-impl support::IntoDartExceptPrimitive for ApiOutputProgram {}
-
-impl support::IntoDart for UncurriedProgramToDart {
-    fn into_dart(self) -> support::DartAbi {
-        vec![
-            self.program.into_dart(),
-            self.args.into_dart(),
-            self.error.into_dart(),
+fn wire_keyring_list_keys_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_list_keys",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            move |task_callback| keyring_list_keys(api_path, api_passphrase)
+        },
+    )
+}
+fn wire_keyring_add_mnemonic_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+    mnemonic: impl Wire2Api<String> + UnwindSafe,
+    label: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_add_mnemonic",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_mnemonic = mnemonic.wire2api();
+            let api_label = label.wire2api();
+            move |task_callback| {
+                keyring_add_mnemonic(api_path, api_passphrase, api_mnemonic, api_label)
+            }
+        },
+    )
+}
+fn wire_keyring_add_secret_key_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+    sk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    label: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_add_secret_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_sk = sk.wire2api();
+            let api_label = label.wire2api();
+            move |task_callback| keyring_add_secret_key(api_path, api_passphrase, api_sk, api_label)
+        },
+    )
+}
+fn wire_keyring_get_secret_key_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+    fingerprint: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_get_secret_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_fingerprint = fingerprint.wire2api();
+            move |task_callback| keyring_get_secret_key(api_path, api_passphrase, api_fingerprint)
+        },
+    )
+}
+fn wire_keyring_export_mnemonic_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+    fingerprint: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_export_mnemonic",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_fingerprint = fingerprint.wire2api();
+            move |task_callback| keyring_export_mnemonic(api_path, api_passphrase, api_fingerprint)
+        },
+    )
+}
+fn wire_keyring_set_label_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+    fingerprint: impl Wire2Api<u32> + UnwindSafe,
+    label: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_set_label",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_fingerprint = fingerprint.wire2api();
+            let api_label = label.wire2api();
+            move |task_callback| {
+                keyring_set_label(api_path, api_passphrase, api_fingerprint, api_label)
+            }
+        },
+    )
+}
+fn wire_keyring_remove_key_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<String> + UnwindSafe,
+    fingerprint: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_remove_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_fingerprint = fingerprint.wire2api();
+            move |task_callback| keyring_remove_key(api_path, api_passphrase, api_fingerprint)
+        },
+    )
+}
+fn wire_keyring_change_passphrase_impl(
+    port_: MessagePort,
+    path: impl Wire2Api<String> + UnwindSafe,
+    current_passphrase: impl Wire2Api<String> + UnwindSafe,
+    new_passphrase: impl Wire2Api<String> + UnwindSafe,
+    hint: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "keyring_change_passphrase",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.wire2api();
+            let api_current_passphrase = current_passphrase.wire2api();
+            let api_new_passphrase = new_passphrase.wire2api();
+            let api_hint = hint.wire2api();
+            move |task_callback| {
+                keyring_change_passphrase(
+                    api_path,
+                    api_current_passphrase,
+                    api_new_passphrase,
+                    api_hint,
+                )
+            }
+        },
+    )
+}
+fn wire_mnemonic_generate_impl(port_: MessagePort, word_count: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "mnemonic_generate",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_word_count = word_count.wire2api();
+            move |task_callback| mnemonic_generate(api_word_count)
+        },
+    )
+}
+fn wire_mnemonic_to_seed_impl(
+    port_: MessagePort,
+    mnemonic_words: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "mnemonic_to_seed",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_mnemonic_words = mnemonic_words.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            move |task_callback| mnemonic_to_seed(api_mnemonic_words, api_passphrase)
+        },
+    )
+}
+fn wire_mnemonic_validate_impl(
+    port_: MessagePort,
+    mnemonic_words: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "mnemonic_validate",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_mnemonic_words = mnemonic_words.wire2api();
+            move |task_callback| Ok(mnemonic_validate(api_mnemonic_words))
+        },
+    )
+}
+fn wire_public_key_fingerprint_impl(port_: MessagePort, pk: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "public_key_fingerprint",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pk = pk.wire2api();
+            move |task_callback| public_key_fingerprint(api_pk)
+        },
+    )
+}
+fn wire_key_info_from_secret_key_impl(
+    port_: MessagePort,
+    sk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    prefix: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "key_info_from_secret_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_sk = sk.wire2api();
+            let api_prefix = prefix.wire2api();
+            move |task_callback| key_info_from_secret_key(api_sk, api_prefix)
+        },
+    )
+}
+fn wire_key_info_from_public_key_impl(
+    port_: MessagePort,
+    pk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    prefix: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "key_info_from_public_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pk = pk.wire2api();
+            let api_prefix = prefix.wire2api();
+            move |task_callback| key_info_from_public_key(api_pk, api_prefix)
+        },
+    )
+}
+fn wire_key_info_from_mnemonic_impl(
+    port_: MessagePort,
+    mnemonic_words: impl Wire2Api<String> + UnwindSafe,
+    passphrase: impl Wire2Api<Option<String>> + UnwindSafe,
+    prefix: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "key_info_from_mnemonic",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_mnemonic_words = mnemonic_words.wire2api();
+            let api_passphrase = passphrase.wire2api();
+            let api_prefix = prefix.wire2api();
+            move |task_callback| {
+                key_info_from_mnemonic(api_mnemonic_words, api_passphrase, api_prefix)
+            }
+        },
+    )
+}
+fn wire_spend_bundle_sign_impl(
+    port_: MessagePort,
+    spend_bundle_json: impl Wire2Api<String> + UnwindSafe,
+    secret_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "spend_bundle_sign",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_spend_bundle_json = spend_bundle_json.wire2api();
+            let api_secret_keys = secret_keys.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                spend_bundle_sign(api_spend_bundle_json, api_secret_keys, api_network)
+            }
+        },
+    )
+}
+fn wire_spend_bundle_verify_signature_impl(
+    port_: MessagePort,
+    spend_bundle_json: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "spend_bundle_verify_signature",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_spend_bundle_json = spend_bundle_json.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| spend_bundle_verify_signature(api_spend_bundle_json, api_network)
+        },
+    )
+}
+fn wire_pub_calculate_synthetic_secret_key_impl(
+    port_: MessagePort,
+    sk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    hidden_puzzle_hash: impl Wire2Api<Option<Vec<u8>>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "pub_calculate_synthetic_secret_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_sk = sk.wire2api();
+            let api_hidden_puzzle_hash = hidden_puzzle_hash.wire2api();
+            move |task_callback| pub_calculate_synthetic_secret_key(api_sk, api_hidden_puzzle_hash)
+        },
+    )
+}
+fn wire_pub_calculate_synthetic_public_key_impl(
+    port_: MessagePort,
+    pk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    hidden_puzzle_hash: impl Wire2Api<Option<Vec<u8>>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "pub_calculate_synthetic_public_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pk = pk.wire2api();
+            let api_hidden_puzzle_hash = hidden_puzzle_hash.wire2api();
+            move |task_callback| pub_calculate_synthetic_public_key(api_pk, api_hidden_puzzle_hash)
+        },
+    )
+}
+fn wire_standard_transaction_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    payments_json: impl Wire2Api<String> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "standard_transaction_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_payments_json = payments_json.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                standard_transaction_create(
+                    api_coins_json,
+                    api_payments_json,
+                    api_fee,
+                    api_change_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_coin_selection_select_impl(
+    port_: MessagePort,
+    coin_records_json: impl Wire2Api<String> + UnwindSafe,
+    amount: impl Wire2Api<u64> + UnwindSafe,
+    strategy: impl Wire2Api<String> + UnwindSafe,
+    max_coins: impl Wire2Api<u32> + UnwindSafe,
+    min_coin_amount: impl Wire2Api<u64> + UnwindSafe,
+    excluded_coin_ids: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "coin_selection_select",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_records_json = coin_records_json.wire2api();
+            let api_amount = amount.wire2api();
+            let api_strategy = strategy.wire2api();
+            let api_max_coins = max_coins.wire2api();
+            let api_min_coin_amount = min_coin_amount.wire2api();
+            let api_excluded_coin_ids = excluded_coin_ids.wire2api();
+            move |task_callback| {
+                coin_selection_select(
+                    api_coin_records_json,
+                    api_amount,
+                    api_strategy,
+                    api_max_coins,
+                    api_min_coin_amount,
+                    api_excluded_coin_ids,
+                )
+            }
+        },
+    )
+}
+fn wire_cat_spend_bundle_create_impl(
+    port_: MessagePort,
+    spendable_cats_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "cat_spend_bundle_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_spendable_cats_json = spendable_cats_json.wire2api();
+            move |task_callback| cat_spend_bundle_create(api_spendable_cats_json)
+        },
+    )
+}
+fn wire_cat_parse_child_impl(
+    port_: MessagePort,
+    parent_coin_spend_json: impl Wire2Api<String> + UnwindSafe,
+    child_coin_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "cat_parse_child",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_parent_coin_spend_json = parent_coin_spend_json.wire2api();
+            let api_child_coin_json = child_coin_json.wire2api();
+            move |task_callback| cat_parse_child(api_parent_coin_spend_json, api_child_coin_json)
+        },
+    )
+}
+fn wire_cat_tail_puzzle_impl(
+    port_: MessagePort,
+    kind: impl Wire2Api<String> + UnwindSafe,
+    argument: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "cat_tail_puzzle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_kind = kind.wire2api();
+            let api_argument = argument.wire2api();
+            move |task_callback| cat_tail_puzzle(api_kind, api_argument)
+        },
+    )
+}
+fn wire_cat_issuance_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    tail_kind: impl Wire2Api<String> + UnwindSafe,
+    tail_argument: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    tail_solution: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    inner_public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    amount: impl Wire2Api<u64> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "cat_issuance_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_tail_kind = tail_kind.wire2api();
+            let api_tail_argument = tail_argument.wire2api();
+            let api_tail_solution = tail_solution.wire2api();
+            let api_inner_public_key = inner_public_key.wire2api();
+            let api_amount = amount.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                cat_issuance_create(
+                    api_coins_json,
+                    api_tail_kind,
+                    api_tail_argument,
+                    api_tail_solution,
+                    api_inner_public_key,
+                    api_amount,
+                    api_fee,
+                    api_change_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_cat_melt_create_impl(
+    port_: MessagePort,
+    cats_json: impl Wire2Api<String> + UnwindSafe,
+    tail_kind: impl Wire2Api<String> + UnwindSafe,
+    tail_argument: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    tail_solution: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    melt_amount: impl Wire2Api<u64> + UnwindSafe,
+    output_inner_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "cat_melt_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_cats_json = cats_json.wire2api();
+            let api_tail_kind = tail_kind.wire2api();
+            let api_tail_argument = tail_argument.wire2api();
+            let api_tail_solution = tail_solution.wire2api();
+            let api_melt_amount = melt_amount.wire2api();
+            let api_output_inner_puzzle_hash = output_inner_puzzle_hash.wire2api();
+            move |task_callback| {
+                cat_melt_create(
+                    api_cats_json,
+                    api_tail_kind,
+                    api_tail_argument,
+                    api_tail_solution,
+                    api_melt_amount,
+                    api_output_inner_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_cat_reissue_create_impl(
+    port_: MessagePort,
+    cats_json: impl Wire2Api<String> + UnwindSafe,
+    tail_kind: impl Wire2Api<String> + UnwindSafe,
+    tail_argument: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    tail_solution: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    reissue_amount: impl Wire2Api<u64> + UnwindSafe,
+    output_inner_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "cat_reissue_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_cats_json = cats_json.wire2api();
+            let api_tail_kind = tail_kind.wire2api();
+            let api_tail_argument = tail_argument.wire2api();
+            let api_tail_solution = tail_solution.wire2api();
+            let api_reissue_amount = reissue_amount.wire2api();
+            let api_output_inner_puzzle_hash = output_inner_puzzle_hash.wire2api();
+            move |task_callback| {
+                cat_reissue_create(
+                    api_cats_json,
+                    api_tail_kind,
+                    api_tail_argument,
+                    api_tail_solution,
+                    api_reissue_amount,
+                    api_output_inner_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_singleton_launch_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    inner_puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    key_value_list: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    amount: impl Wire2Api<u64> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "singleton_launch_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_inner_puzzle = inner_puzzle.wire2api();
+            let api_key_value_list = key_value_list.wire2api();
+            let api_amount = amount.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                singleton_launch_create(
+                    api_coins_json,
+                    api_inner_puzzle,
+                    api_key_value_list,
+                    api_amount,
+                    api_fee,
+                    api_change_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_singleton_puzzle_impl(
+    port_: MessagePort,
+    launcher_id: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    inner_puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "singleton_puzzle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_launcher_id = launcher_id.wire2api();
+            let api_inner_puzzle = inner_puzzle.wire2api();
+            move |task_callback| singleton_puzzle(api_launcher_id, api_inner_puzzle)
+        },
+    )
+}
+fn wire_singleton_lineage_proof_impl(
+    port_: MessagePort,
+    parent_coin_spend_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "singleton_lineage_proof",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_parent_coin_spend_json = parent_coin_spend_json.wire2api();
+            move |task_callback| singleton_lineage_proof(api_parent_coin_spend_json)
+        },
+    )
+}
+fn wire_singleton_spend_create_impl(
+    port_: MessagePort,
+    coin_json: impl Wire2Api<String> + UnwindSafe,
+    launcher_id: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    inner_puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    inner_solution: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    lineage_proof_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "singleton_spend_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_json = coin_json.wire2api();
+            let api_launcher_id = launcher_id.wire2api();
+            let api_inner_puzzle = inner_puzzle.wire2api();
+            let api_inner_solution = inner_solution.wire2api();
+            let api_lineage_proof_json = lineage_proof_json.wire2api();
+            move |task_callback| {
+                singleton_spend_create(
+                    api_coin_json,
+                    api_launcher_id,
+                    api_inner_puzzle,
+                    api_inner_solution,
+                    api_lineage_proof_json,
+                )
+            }
+        },
+    )
+}
+fn wire_singleton_parse_impl(port_: MessagePort, puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "singleton_parse",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_puzzle = puzzle.wire2api();
+            move |task_callback| singleton_parse(api_puzzle)
+        },
+    )
+}
+fn wire_nft_mint_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    mint_json: impl Wire2Api<String> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "nft_mint_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_mint_json = mint_json.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                nft_mint_create(api_coins_json, api_mint_json, api_fee, api_change_puzzle_hash)
+            }
+        },
+    )
+}
+fn wire_nft_transfer_create_impl(
+    port_: MessagePort,
+    coin_json: impl Wire2Api<String> + UnwindSafe,
+    nft_puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    lineage_proof_json: impl Wire2Api<String> + UnwindSafe,
+    new_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    new_did_owner_json: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "nft_transfer_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_json = coin_json.wire2api();
+            let api_nft_puzzle = nft_puzzle.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_lineage_proof_json = lineage_proof_json.wire2api();
+            let api_new_puzzle_hash = new_puzzle_hash.wire2api();
+            let api_new_did_owner_json = new_did_owner_json.wire2api();
+            move |task_callback| {
+                nft_transfer_create(
+                    api_coin_json,
+                    api_nft_puzzle,
+                    api_public_key,
+                    api_lineage_proof_json,
+                    api_new_puzzle_hash,
+                    api_new_did_owner_json,
+                )
+            }
+        },
+    )
+}
+fn wire_nft_parse_impl(port_: MessagePort, puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "nft_parse",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_puzzle = puzzle.wire2api();
+            move |task_callback| nft_parse(api_puzzle)
+        },
+    )
+}
+fn wire_did_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    recovery_list: impl Wire2Api<Vec<String>> + UnwindSafe,
+    num_verifications_required: impl Wire2Api<u64> + UnwindSafe,
+    metadata_json: impl Wire2Api<String> + UnwindSafe,
+    amount: impl Wire2Api<u64> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "did_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_recovery_list = recovery_list.wire2api();
+            let api_num_verifications_required = num_verifications_required.wire2api();
+            let api_metadata_json = metadata_json.wire2api();
+            let api_amount = amount.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                did_create(
+                    api_coins_json,
+                    api_public_key,
+                    api_recovery_list,
+                    api_num_verifications_required,
+                    api_metadata_json,
+                    api_amount,
+                    api_fee,
+                    api_change_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_did_parse_impl(port_: MessagePort, puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "did_parse",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_puzzle = puzzle.wire2api();
+            move |task_callback| did_parse(api_puzzle)
+        },
+    )
+}
+fn wire_did_transfer_create_impl(
+    port_: MessagePort,
+    coin_json: impl Wire2Api<String> + UnwindSafe,
+    did_puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    lineage_proof_json: impl Wire2Api<String> + UnwindSafe,
+    new_p2_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "did_transfer_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_json = coin_json.wire2api();
+            let api_did_puzzle = did_puzzle.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_lineage_proof_json = lineage_proof_json.wire2api();
+            let api_new_p2_puzzle_hash = new_p2_puzzle_hash.wire2api();
+            move |task_callback| {
+                did_transfer_create(
+                    api_coin_json,
+                    api_did_puzzle,
+                    api_public_key,
+                    api_lineage_proof_json,
+                    api_new_p2_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_did_metadata_update_create_impl(
+    port_: MessagePort,
+    coin_json: impl Wire2Api<String> + UnwindSafe,
+    did_puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    lineage_proof_json: impl Wire2Api<String> + UnwindSafe,
+    metadata_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "did_metadata_update_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_json = coin_json.wire2api();
+            let api_did_puzzle = did_puzzle.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_lineage_proof_json = lineage_proof_json.wire2api();
+            let api_metadata_json = metadata_json.wire2api();
+            move |task_callback| {
+                did_metadata_update_create(
+                    api_coin_json,
+                    api_did_puzzle,
+                    api_public_key,
+                    api_lineage_proof_json,
+                    api_metadata_json,
+                )
+            }
+        },
+    )
+}
+fn wire_did_nft_assignment_create_impl(
+    port_: MessagePort,
+    coin_json: impl Wire2Api<String> + UnwindSafe,
+    did_puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    lineage_proof_json: impl Wire2Api<String> + UnwindSafe,
+    nft_launcher_ids: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "did_nft_assignment_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_json = coin_json.wire2api();
+            let api_did_puzzle = did_puzzle.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_lineage_proof_json = lineage_proof_json.wire2api();
+            let api_nft_launcher_ids = nft_launcher_ids.wire2api();
+            move |task_callback| {
+                did_nft_assignment_create(
+                    api_coin_json,
+                    api_did_puzzle,
+                    api_public_key,
+                    api_lineage_proof_json,
+                    api_nft_launcher_ids,
+                )
+            }
+        },
+    )
+}
+fn wire_offer_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    offered_xch: impl Wire2Api<u64> + UnwindSafe,
+    offered_cats_json: impl Wire2Api<String> + UnwindSafe,
+    requested_json: impl Wire2Api<String> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    secret_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "offer_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_offered_xch = offered_xch.wire2api();
+            let api_offered_cats_json = offered_cats_json.wire2api();
+            let api_requested_json = requested_json.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            let api_secret_keys = secret_keys.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                offer_create(
+                    api_coins_json,
+                    api_offered_xch,
+                    api_offered_cats_json,
+                    api_requested_json,
+                    api_fee,
+                    api_change_puzzle_hash,
+                    api_secret_keys,
+                    api_network,
+                )
+            }
+        },
+    )
+}
+fn wire_offer_summary_impl(port_: MessagePort, offer: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "offer_summary",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_offer = offer.wire2api();
+            move |task_callback| offer_summary(api_offer)
+        },
+    )
+}
+fn wire_offer_take_impl(
+    port_: MessagePort,
+    offer: impl Wire2Api<String> + UnwindSafe,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    cat_coins_json: impl Wire2Api<String> + UnwindSafe,
+    receive_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    secret_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "offer_take",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_offer = offer.wire2api();
+            let api_coins_json = coins_json.wire2api();
+            let api_cat_coins_json = cat_coins_json.wire2api();
+            let api_receive_puzzle_hash = receive_puzzle_hash.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            let api_secret_keys = secret_keys.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                offer_take(
+                    api_offer,
+                    api_coins_json,
+                    api_cat_coins_json,
+                    api_receive_puzzle_hash,
+                    api_fee,
+                    api_change_puzzle_hash,
+                    api_secret_keys,
+                    api_network,
+                )
+            }
+        },
+    )
+}
+fn wire_spend_bundle_compress_impl(
+    port_: MessagePort,
+    spend_bundle_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "spend_bundle_compress",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_spend_bundle_json = spend_bundle_json.wire2api();
+            move |task_callback| spend_bundle_compress(api_spend_bundle_json)
+        },
+    )
+}
+fn wire_spend_bundle_decompress_impl(
+    port_: MessagePort,
+    compressed: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "spend_bundle_decompress",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_compressed = compressed.wire2api();
+            move |task_callback| spend_bundle_decompress(api_compressed)
+        },
+    )
+}
+fn wire_puzzle_identify_impl(port_: MessagePort, puzzle: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "puzzle_identify",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_puzzle = puzzle.wire2api();
+            move |task_callback| puzzle_identify(api_puzzle)
+        },
+    )
+}
+fn wire_coin_spend_identify_impl(
+    port_: MessagePort,
+    coin_spend_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "coin_spend_identify",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_spend_json = coin_spend_json.wire2api();
+            move |task_callback| coin_spend_identify(api_coin_spend_json)
+        },
+    )
+}
+fn wire_puzzle_driver_register_impl(
+    port_: MessagePort,
+    name: impl Wire2Api<String> + UnwindSafe,
+    mod_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    arg_names: impl Wire2Api<Vec<String>> + UnwindSafe,
+    inner_puzzle_arg: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "puzzle_driver_register",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_name = name.wire2api();
+            let api_mod_hash = mod_hash.wire2api();
+            let api_arg_names = arg_names.wire2api();
+            let api_inner_puzzle_arg = inner_puzzle_arg.wire2api();
+            move |task_callback| {
+                puzzle_driver_register(api_name, api_mod_hash, api_arg_names, api_inner_puzzle_arg)
+            }
+        },
+    )
+}
+fn wire_clawback_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    sender_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    recipient_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    amount: impl Wire2Api<u64> + UnwindSafe,
+    time_lock: impl Wire2Api<u64> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "clawback_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_sender_puzzle_hash = sender_puzzle_hash.wire2api();
+            let api_recipient_puzzle_hash = recipient_puzzle_hash.wire2api();
+            let api_amount = amount.wire2api();
+            let api_time_lock = time_lock.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                clawback_create(
+                    api_coins_json,
+                    api_sender_puzzle_hash,
+                    api_recipient_puzzle_hash,
+                    api_amount,
+                    api_time_lock,
+                    api_fee,
+                    api_change_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_clawback_claim_create_impl(
+    port_: MessagePort,
+    coin_json: impl Wire2Api<String> + UnwindSafe,
+    metadata_json: impl Wire2Api<String> + UnwindSafe,
+    public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    destination_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "clawback_claim_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_json = coin_json.wire2api();
+            let api_metadata_json = metadata_json.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_destination_puzzle_hash = destination_puzzle_hash.wire2api();
+            let api_fee = fee.wire2api();
+            move |task_callback| {
+                clawback_claim_create(
+                    api_coin_json,
+                    api_metadata_json,
+                    api_public_key,
+                    api_destination_puzzle_hash,
+                    api_fee,
+                )
+            }
+        },
+    )
+}
+fn wire_clawback_spend_create_impl(
+    port_: MessagePort,
+    coin_json: impl Wire2Api<String> + UnwindSafe,
+    metadata_json: impl Wire2Api<String> + UnwindSafe,
+    public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    destination_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "clawback_spend_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_json = coin_json.wire2api();
+            let api_metadata_json = metadata_json.wire2api();
+            let api_public_key = public_key.wire2api();
+            let api_destination_puzzle_hash = destination_puzzle_hash.wire2api();
+            let api_fee = fee.wire2api();
+            move |task_callback| {
+                clawback_spend_create(
+                    api_coin_json,
+                    api_metadata_json,
+                    api_public_key,
+                    api_destination_puzzle_hash,
+                    api_fee,
+                )
+            }
+        },
+    )
+}
+fn wire_clawback_parse_coins_impl(
+    port_: MessagePort,
+    parent_coin_spend_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "clawback_parse_coins",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_parent_coin_spend_json = parent_coin_spend_json.wire2api();
+            move |task_callback| clawback_parse_coins(api_parent_coin_spend_json)
+        },
+    )
+}
+fn wire_plot_nft_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    owner_public_key: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    target_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    pool_url: impl Wire2Api<Option<String>> + UnwindSafe,
+    relative_lock_height: impl Wire2Api<u32> + UnwindSafe,
+    delay_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "plot_nft_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_owner_public_key = owner_public_key.wire2api();
+            let api_target_puzzle_hash = target_puzzle_hash.wire2api();
+            let api_pool_url = pool_url.wire2api();
+            let api_relative_lock_height = relative_lock_height.wire2api();
+            let api_delay_puzzle_hash = delay_puzzle_hash.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                plot_nft_create(
+                    api_coins_json,
+                    api_owner_public_key,
+                    api_target_puzzle_hash,
+                    api_pool_url,
+                    api_relative_lock_height,
+                    api_delay_puzzle_hash,
+                    api_fee,
+                    api_change_puzzle_hash,
+                    api_network,
+                )
+            }
+        },
+    )
+}
+fn wire_plot_nft_parse_impl(
+    port_: MessagePort,
+    coin_spends_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "plot_nft_parse",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coin_spends_json = coin_spends_json.wire2api();
+            move |task_callback| plot_nft_parse(api_coin_spends_json)
+        },
+    )
+}
+fn wire_plot_nft_p2_singleton_puzzle_hash_impl(
+    port_: MessagePort,
+    plot_nft_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "plot_nft_p2_singleton_puzzle_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_plot_nft_json = plot_nft_json.wire2api();
+            move |task_callback| plot_nft_p2_singleton_puzzle_hash(api_plot_nft_json)
+        },
+    )
+}
+fn wire_plot_nft_travel_create_impl(
+    port_: MessagePort,
+    plot_nft_json: impl Wire2Api<String> + UnwindSafe,
+    target_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    pool_url: impl Wire2Api<Option<String>> + UnwindSafe,
+    relative_lock_height: impl Wire2Api<u32> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "plot_nft_travel_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_plot_nft_json = plot_nft_json.wire2api();
+            let api_target_puzzle_hash = target_puzzle_hash.wire2api();
+            let api_pool_url = pool_url.wire2api();
+            let api_relative_lock_height = relative_lock_height.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                plot_nft_travel_create(
+                    api_plot_nft_json,
+                    api_target_puzzle_hash,
+                    api_pool_url,
+                    api_relative_lock_height,
+                    api_network,
+                )
+            }
+        },
+    )
+}
+fn wire_plot_nft_absorb_create_impl(
+    port_: MessagePort,
+    plot_nft_json: impl Wire2Api<String> + UnwindSafe,
+    reward_coins_json: impl Wire2Api<String> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "plot_nft_absorb_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_plot_nft_json = plot_nft_json.wire2api();
+            let api_reward_coins_json = reward_coins_json.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                plot_nft_absorb_create(api_plot_nft_json, api_reward_coins_json, api_network)
+            }
+        },
+    )
+}
+fn wire_multisig_puzzle_impl(
+    port_: MessagePort,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+    public_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "multisig_puzzle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_threshold = threshold.wire2api();
+            let api_public_keys = public_keys.wire2api();
+            move |task_callback| multisig_puzzle(api_threshold, api_public_keys)
+        },
+    )
+}
+fn wire_multisig_puzzle_hash_impl(
+    port_: MessagePort,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+    public_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "multisig_puzzle_hash",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_threshold = threshold.wire2api();
+            let api_public_keys = public_keys.wire2api();
+            move |task_callback| multisig_puzzle_hash(api_threshold, api_public_keys)
+        },
+    )
+}
+fn wire_multisig_address_impl(
+    port_: MessagePort,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+    public_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    prefix: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "multisig_address",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_threshold = threshold.wire2api();
+            let api_public_keys = public_keys.wire2api();
+            let api_prefix = prefix.wire2api();
+            move |task_callback| multisig_address(api_threshold, api_public_keys, api_prefix)
+        },
+    )
+}
+fn wire_multisig_transaction_create_impl(
+    port_: MessagePort,
+    threshold: impl Wire2Api<u32> + UnwindSafe,
+    public_keys: impl Wire2Api<Vec<String>> + UnwindSafe,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    signers: impl Wire2Api<Vec<String>> + UnwindSafe,
+    payments_json: impl Wire2Api<String> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "multisig_transaction_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_threshold = threshold.wire2api();
+            let api_public_keys = public_keys.wire2api();
+            let api_coins_json = coins_json.wire2api();
+            let api_signers = signers.wire2api();
+            let api_payments_json = payments_json.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                multisig_transaction_create(
+                    api_threshold,
+                    api_public_keys,
+                    api_coins_json,
+                    api_signers,
+                    api_payments_json,
+                    api_fee,
+                    api_change_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_multisig_sign_impl(
+    port_: MessagePort,
+    spend_bundle_json: impl Wire2Api<String> + UnwindSafe,
+    sk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "multisig_sign",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_spend_bundle_json = spend_bundle_json.wire2api();
+            let api_sk = sk.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| multisig_sign(api_spend_bundle_json, api_sk, api_network)
+        },
+    )
+}
+fn wire_multisig_add_signatures_impl(
+    port_: MessagePort,
+    spend_bundle_json: impl Wire2Api<String> + UnwindSafe,
+    partial_signatures: impl Wire2Api<Vec<String>> + UnwindSafe,
+    network: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "multisig_add_signatures",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_spend_bundle_json = spend_bundle_json.wire2api();
+            let api_partial_signatures = partial_signatures.wire2api();
+            let api_network = network.wire2api();
+            move |task_callback| {
+                multisig_add_signatures(api_spend_bundle_json, api_partial_signatures, api_network)
+            }
+        },
+    )
+}
+fn wire_notification_create_impl(
+    port_: MessagePort,
+    coins_json: impl Wire2Api<String> + UnwindSafe,
+    target_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    message: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    amount: impl Wire2Api<u64> + UnwindSafe,
+    fee: impl Wire2Api<u64> + UnwindSafe,
+    change_puzzle_hash: impl Wire2Api<Vec<u8>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "notification_create",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_coins_json = coins_json.wire2api();
+            let api_target_puzzle_hash = target_puzzle_hash.wire2api();
+            let api_message = message.wire2api();
+            let api_amount = amount.wire2api();
+            let api_fee = fee.wire2api();
+            let api_change_puzzle_hash = change_puzzle_hash.wire2api();
+            move |task_callback| {
+                notification_create(
+                    api_coins_json,
+                    api_target_puzzle_hash,
+                    api_message,
+                    api_amount,
+                    api_fee,
+                    api_change_puzzle_hash,
+                )
+            }
+        },
+    )
+}
+fn wire_notification_parse_impl(
+    port_: MessagePort,
+    parent_coin_spend_json: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "notification_parse",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_parent_coin_spend_json = parent_coin_spend_json.wire2api();
+            move |task_callback| notification_parse(api_parent_coin_spend_json)
+        },
+    )
+}
+fn wire_message_sign_impl(
+    port_: MessagePort,
+    sk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    message: impl Wire2Api<String> + UnwindSafe,
+    is_hex: impl Wire2Api<bool> + UnwindSafe,
+    address: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "message_sign",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_sk = sk.wire2api();
+            let api_message = message.wire2api();
+            let api_is_hex = is_hex.wire2api();
+            let api_address = address.wire2api();
+            move |task_callback| message_sign(api_sk, api_message, api_is_hex, api_address)
+        },
+    )
+}
+fn wire_message_verify_impl(
+    port_: MessagePort,
+    pk: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    message: impl Wire2Api<String> + UnwindSafe,
+    is_hex: impl Wire2Api<bool> + UnwindSafe,
+    sig: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    address: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "message_verify",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_pk = pk.wire2api();
+            let api_message = message.wire2api();
+            let api_is_hex = is_hex.wire2api();
+            let api_sig = sig.wire2api();
+            let api_address = address.wire2api();
+            move |task_callback| {
+                message_verify(api_pk, api_message, api_is_hex, api_sig, api_address)
+            }
+        },
+    )
+}
+// Section: wrapper structs
+
+// Section: static checks
+
+// Section: allocate functions
+
+// Section: related functions
+
+// Section: impl Wire2Api
+
+pub trait Wire2Api<T> {
+    fn wire2api(self) -> T;
+}
+
+impl<T, S> Wire2Api<Option<T>> for *mut S
+where
+    *mut S: Wire2Api<T>,
+{
+    fn wire2api(self) -> Option<T> {
+        (!self.is_null()).then(|| self.wire2api())
+    }
+}
+
+impl Wire2Api<bool> for bool {
+    fn wire2api(self) -> bool {
+        self
+    }
+}
+
+impl Wire2Api<u32> for u32 {
+    fn wire2api(self) -> u32 {
+        self
+    }
+}
+impl Wire2Api<u64> for u64 {
+    fn wire2api(self) -> u64 {
+        self
+    }
+}
+impl Wire2Api<u8> for u8 {
+    fn wire2api(self) -> u8 {
+        self
+    }
+}
+
+impl Wire2Api<usize> for usize {
+    fn wire2api(self) -> usize {
+        self
+    }
+}
+
+// Section: impl IntoDart
+
+impl support::IntoDart for ApiCatInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.is_cat.into_dart(),
+            self.is_cat1.into_dart(),
+            self.tail_hash.into_dart(),
+            self.parent_inner_puzzle.into_dart(),
+            self.inner_puzzle_hash.into_dart(),
+            self.lineage_proof.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiCatInfo {}
+
+impl support::IntoDart for ApiDidInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.is_did.into_dart(),
+            self.launcher_id.into_dart(),
+            self.recovery_list_hash.into_dart(),
+            self.num_verifications_required.into_dart(),
+            self.metadata.into_dart(),
+            self.metadata_json.into_dart(),
+            self.p2_puzzle_hash.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiDidInfo {}
+
+impl support::IntoDart for ApiKeyInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.fingerprint.into_dart(),
+            self.master_public_key.into_dart(),
+            self.farmer_public_key.into_dart(),
+            self.pool_public_key.into_dart(),
+            self.first_wallet_address.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiKeyInfo {}
+
+impl support::IntoDart for ApiNftInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.is_nft.into_dart(),
+            self.launcher_id.into_dart(),
+            self.metadata.into_dart(),
+            self.metadata_json.into_dart(),
+            self.metadata_updater_puzzle_hash.into_dart(),
+            self.current_owner.into_dart(),
+            self.royalty_puzzle_hash.into_dart(),
+            self.royalty_percentage.into_dart(),
+            self.p2_puzzle_hash.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiNftInfo {}
+
+impl support::IntoDart for ApiOutputProgram {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.program.into_dart(), self.cost.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiOutputProgram {}
+
+impl support::IntoDart for ApiSignedMessage {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.public_key.into_dart(), self.signature.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiSignedMessage {}
+
+impl support::IntoDart for ApiSingletonInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.is_singleton.into_dart(),
+            self.launcher_id.into_dart(),
+            self.inner_puzzle.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiSingletonInfo {}
+
+impl support::IntoDart for ApiSingletonLaunch {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.launcher_id.into_dart(), self.spend_bundle.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ApiSingletonLaunch {}
+
+impl support::IntoDart for DerivedPuzzleHash {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.index.into_dart(),
+            self.public_key.into_dart(),
+            self.puzzle_hash.into_dart(),
+            self.hardened.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DerivedPuzzleHash {}
+
+impl support::IntoDart for KeyringKey {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.fingerprint.into_dart(),
+            self.label.into_dart(),
+            self.public_key.into_dart(),
+            self.has_mnemonic.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for KeyringKey {}
+
+impl support::IntoDart for MnemonicValidation {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.valid.into_dart(),
+            self.invalid_word_index.into_dart(),
+            self.checksum_failed.into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MnemonicValidation {}
+
+impl support::IntoDart for SignatureVerification {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.valid.into_dart(),
+            self.coin_spend_index.into_dart(),
+            self.coin_name.into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SignatureVerification {}
+
+impl support::IntoDart for UncurriedProgramToDart {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.program.into_dart(),
+            self.args.into_dart(),
         ]
         .into_dart()
     }
@@ -0,0 +1,364 @@
+use std::error::Error;
+
+use num_bigint::BigInt;
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::condition_opcode::ConditionOpcode;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::blockchain::utils::atom_to_int;
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::puzzle_utils::{int_program, signed_int_program};
+use crate::chia_wallet::standart::transaction::empty_signature;
+use crate::program_utils::condition_utils::conditions_for_solution;
+use crate::program_utils::program::Program;
+use crate::program_utils::utils::INFINITE_COST;
+
+use super::puzzles::cat_puzzle_program::{CAT1_MOD_HASH_STR, CAT_MOD_HASH};
+use super::puzzles::create_cat_outer_puzzle::construct_cat_puzzle;
+use super::puzzles::curry_and_treehash::{atom_hash, curried_puzzle_hash};
+
+/// A CREATE_COIN with this amount reveals the TAIL instead of creating a coin.
+pub const TAIL_REVEAL_AMOUNT: i64 = -113;
+
+pub struct SpendableCat {
+    pub coin: Coin,
+    pub limitations_program_hash: Bytes32,
+    pub inner_puzzle: Program,
+    pub inner_solution: Program,
+    pub lineage_proof: LineageProof,
+    /// Amount minted (positive) or melted (negative) by the TAIL in this spend.
+    pub extra_delta: i64,
+}
+
+/// `(parent_coin_info puzzle_hash amount)`.
+pub fn coin_as_program(coin: &Coin) -> Program {
+    Program::from(vec![
+        Program::from(coin.parent_coin_info.bytes.clone()),
+        Program::from(coin.puzzle_hash.bytes.clone()),
+        int_program(coin.amount),
+    ])
+}
+
+fn next_info_for_spendable_cat(cat: &SpendableCat) -> Program {
+    Program::from(vec![
+        Program::from(cat.coin.parent_coin_info.bytes.clone()),
+        Program::from(cat.inner_puzzle.tree_hash().bytes),
+        int_program(cat.coin.amount),
+    ])
+}
+
+/// Input minus output of each spend, from the CREATE_COINs of its inner puzzle.
+fn cat_delta(cat: &SpendableCat) -> Result<BigInt, Box<dyn Error>> {
+    let (conditions, _cost) = conditions_for_solution(
+        &cat.inner_puzzle.clone().into(),
+        &cat.inner_solution.clone().into(),
+        INFINITE_COST,
+    )?;
+    let mut total = -BigInt::from(cat.extra_delta);
+    for condition in conditions {
+        if condition.opcode != ConditionOpcode::CreateCoin {
+            continue;
+        }
+        let amount = condition
+            .vars
+            .get(1)
+            .ok_or("CREATE_COIN condition without an amount")?;
+        let amount = atom_to_int(amount);
+        if amount != BigInt::from(TAIL_REVEAL_AMOUNT) {
+            total += amount;
+        }
+    }
+    Ok(BigInt::from(cat.coin.amount) - total)
+}
+
+/// Running totals of `deltas`, shifted so the smallest is zero.
+pub fn subtotals_for_deltas(deltas: &[BigInt]) -> Vec<BigInt> {
+    let mut subtotals = Vec::with_capacity(deltas.len());
+    let mut subtotal = BigInt::from(0);
+    for delta in deltas {
+        subtotals.push(subtotal.clone());
+        subtotal += delta;
+    }
+    let offset = subtotals.iter().min().cloned().unwrap_or_default();
+    subtotals.into_iter().map(|s| s - &offset).collect()
+}
+
+/// Builds the ring of CAT2 spends: each coin names its predecessor and the next coin
+/// so the CAT announcements tie every spend together, and the subtotals prove
+/// that value is neither created nor destroyed outside `extra_delta`.
+pub fn unsigned_spend_bundle_for_spendable_cats(
+    cats: &[SpendableCat],
+) -> Result<SpendBundle, Box<dyn Error>> {
+    if cats.is_empty() {
+        return Err("no CAT coins to spend".into());
+    }
+    let mut deltas = Vec::with_capacity(cats.len());
+    for cat in cats {
+        deltas.push(cat_delta(cat)?);
+    }
+    let total: BigInt = deltas.iter().sum();
+    if total != BigInt::from(0) {
+        return Err(format!("CAT inputs and outputs differ by {}", total).into());
+    }
+    let subtotals = subtotals_for_deltas(&deltas);
+
+    let ids: Vec<Bytes32> = cats.iter().map(|cat| cat.coin.name()).collect();
+    let count = cats.len();
+    let mut coin_spends = Vec::with_capacity(count);
+    for (index, cat) in cats.iter().enumerate() {
+        let prev_index = (index + count - 1) % count;
+        let next_index = (index + 1) % count;
+        let puzzle_reveal = construct_cat_puzzle(&cat.limitations_program_hash, &cat.inner_puzzle);
+        if puzzle_reveal.tree_hash() != cat.coin.puzzle_hash {
+            return Err(format!(
+                "inner puzzle does not match the puzzle hash of CAT coin {}",
+                ids[index]
+            )
+            .into());
+        }
+        let solution = Program::from(vec![
+            cat.inner_solution.clone(),
+            cat.lineage_proof.to_program(),
+            Program::from(ids[prev_index].bytes.clone()),
+            coin_as_program(&cat.coin),
+            next_info_for_spendable_cat(&cats[next_index]),
+            signed_int_program(&subtotals[index]),
+            signed_int_program(&BigInt::from(cat.extra_delta)),
+        ]);
+        coin_spends.push(CoinSpend {
+            coin: cat.coin.clone(),
+            puzzle_reveal: puzzle_reveal.into(),
+            solution: solution.into(),
+        });
+    }
+    Ok(SpendBundle {
+        coin_spends,
+        aggregated_signature: empty_signature(),
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CatVersion {
+    /// The original CAT puzzle, retired after its vulnerability; these coins can
+    /// no longer be spent with CAT2 wallets.
+    Cat1,
+    Cat2,
+}
+
+pub struct CatInfo {
+    pub version: CatVersion,
+    pub tail_hash: Bytes32,
+    /// The parent's inner puzzle, revealed by its spend.
+    pub parent_inner_puzzle: Program,
+    /// Inner puzzle hash of the child coin.
+    pub inner_puzzle_hash: Bytes32,
+    /// Lineage proof for spending the child.
+    pub lineage_proof: LineageProof,
+}
+
+/// Splits a CAT puzzle into its version, TAIL hash and inner puzzle.
+pub fn match_cat_puzzle(puzzle: &Program) -> Option<(CatVersion, Bytes32, Program)> {
+    let uncurried = puzzle.try_uncurry()?;
+    let mod_hash = uncurried.program.tree_hash();
+    let version = if mod_hash.bytes == *CAT_MOD_HASH {
+        CatVersion::Cat2
+    } else if mod_hash == Bytes32::from(CAT1_MOD_HASH_STR) {
+        CatVersion::Cat1
+    } else {
+        return None;
+    };
+    if uncurried.args.len() != 3 {
+        return None;
+    }
+    let tail_hash = uncurried.args[1].as_vec()?;
+    if tail_hash.len() != 32 {
+        return None;
+    }
+    Some((version, Bytes32::from(tail_hash), uncurried.args[2].clone()))
+}
+
+/// Recovers the CAT details of `child` from its parent's spend, or `None` when
+/// the parent is not a CAT.
+pub fn parse_cat_from_parent(
+    parent_spend: &CoinSpend,
+    child: &Coin,
+) -> Result<Option<CatInfo>, Box<dyn Error>> {
+    if child.parent_coin_info != parent_spend.coin.name() {
+        return Err("coin is not a child of this coin spend".into());
+    }
+    let puzzle = Program::new(parent_spend.puzzle_reveal.to_bytes());
+    let (version, tail_hash, parent_inner_puzzle) = match match_cat_puzzle(&puzzle) {
+        Some(matched) => matched,
+        None => return Ok(None),
+    };
+    let mod_hash = puzzle.uncurry()?.program.tree_hash();
+    let inner_solution = Program::new(parent_spend.solution.to_bytes()).first()?;
+    let (conditions, _cost) = conditions_for_solution(
+        &parent_inner_puzzle.clone().into(),
+        &inner_solution.into(),
+        INFINITE_COST,
+    )?;
+    let child_amount = BigInt::from(child.amount);
+    for condition in conditions {
+        if condition.opcode != ConditionOpcode::CreateCoin || condition.vars.len() < 2 {
+            continue;
+        }
+        if atom_to_int(&condition.vars[1]) != child_amount || condition.vars[0].len() != 32 {
+            continue;
+        }
+        let inner_puzzle_hash = Bytes32::from(condition.vars[0].clone());
+        let outer_puzzle_hash = curried_puzzle_hash(
+            &mod_hash,
+            &[
+                atom_hash(&mod_hash.bytes),
+                atom_hash(&tail_hash.bytes),
+                inner_puzzle_hash.clone(),
+            ],
+        );
+        if outer_puzzle_hash == child.puzzle_hash {
+            let lineage_proof = LineageProof {
+                parent_name: Some(parent_spend.coin.parent_coin_info.clone()),
+                inner_puzzle_hash: Some(parent_inner_puzzle.tree_hash()),
+                amount: Some(parent_spend.coin.amount),
+            };
+            return Ok(Some(CatInfo {
+                version,
+                tail_hash,
+                parent_inner_puzzle,
+                inner_puzzle_hash,
+                lineage_proof,
+            }));
+        }
+    }
+    Err("CAT spend does not create this coin".into())
+}
+
+#[test]
+fn test_subtotals_for_deltas() {
+    let deltas: Vec<BigInt> = [5, -10, 3, 2].iter().map(|d| BigInt::from(*d)).collect();
+    let subtotals: Vec<BigInt> = [5, 10, 0, 3].iter().map(|s| BigInt::from(*s)).collect();
+    assert_eq!(subtotals_for_deltas(&deltas), subtotals);
+}
+
+#[test]
+fn test_cat_puzzle_hash_and_match() {
+    let tail_hash = Bytes32::from(vec![0x11; 32]);
+    let inner_puzzle = Program::from(vec![Program::from(1), Program::from(vec![0x22; 4])]);
+    let puzzle = construct_cat_puzzle(&tail_hash, &inner_puzzle);
+    let mod_hash = Bytes32::from(CAT_MOD_HASH.clone());
+    assert_eq!(
+        curried_puzzle_hash(
+            &mod_hash,
+            &[
+                atom_hash(&mod_hash.bytes),
+                atom_hash(&tail_hash.bytes),
+                inner_puzzle.tree_hash()
+            ]
+        ),
+        puzzle.tree_hash()
+    );
+    let (version, matched_tail, matched_inner) = match_cat_puzzle(&puzzle).unwrap();
+    assert_eq!(version, CatVersion::Cat2);
+    assert_eq!(matched_tail, tail_hash);
+    assert_eq!(matched_inner.serialized, inner_puzzle.serialized);
+    assert!(match_cat_puzzle(&inner_puzzle).is_none());
+}
+
+#[test]
+fn test_spend_ring() {
+    use crate::blockchain::announcement::Announcement;
+    use crate::chia_wallet::puzzle_utils::make_create_coin_condition;
+
+    let tail_hash = Bytes32::from(vec![0x11; 32]);
+    // Inner puzzles that quote their conditions: the first coin keeps 70 of its 100
+    // mojos back for the second, which pays out 120 from its 50.
+    let payments = [(100, 30, 0xaa), (50, 120, 0xbb)];
+    let cats: Vec<SpendableCat> = payments
+        .iter()
+        .enumerate()
+        .map(|(index, (amount, paid, destination))| {
+            let inner_puzzle =
+                Program::from(1).cons(&Program::from(vec![make_create_coin_condition(
+                    &Bytes32::from(vec![*destination; 32]),
+                    *paid,
+                    &[],
+                )]));
+            SpendableCat {
+                coin: Coin {
+                    parent_coin_info: Bytes32::from(vec![index as u8 + 1; 32]),
+                    puzzle_hash: construct_cat_puzzle(&tail_hash, &inner_puzzle).tree_hash(),
+                    amount: *amount,
+                },
+                limitations_program_hash: tail_hash.clone(),
+                inner_puzzle,
+                inner_solution: Program::null(),
+                lineage_proof: LineageProof {
+                    parent_name: Some(Bytes32::from(vec![0x33; 32])),
+                    inner_puzzle_hash: Some(Bytes32::from(vec![0x44; 32])),
+                    amount: Some(*amount),
+                },
+                extra_delta: 0,
+            }
+        })
+        .collect();
+    let spend_bundle = unsigned_spend_bundle_for_spendable_cats(&cats).unwrap();
+    assert_eq!(spend_bundle.coin_spends.len(), 2);
+
+    let subtotals = [0, 70];
+    let mut created = Vec::new();
+    let mut asserted = Vec::new();
+    for (index, coin_spend) in spend_bundle.coin_spends.iter().enumerate() {
+        let other = &cats[1 - index];
+        let solution: Vec<Program> = Program::new(coin_spend.solution.to_bytes())
+            .iter()
+            .collect();
+        assert_eq!(solution[2].as_vec().unwrap(), other.coin.name().bytes);
+        assert_eq!(solution[3], coin_as_program(&cats[index].coin));
+        assert_eq!(solution[4], next_info_for_spendable_cat(other));
+        assert_eq!(
+            solution[5].as_int().unwrap(),
+            BigInt::from(subtotals[index])
+        );
+
+        let (conditions, _cost) = conditions_for_solution(
+            &coin_spend.puzzle_reveal,
+            &coin_spend.solution,
+            INFINITE_COST,
+        )
+        .unwrap();
+        for condition in conditions {
+            match condition.opcode {
+                ConditionOpcode::CreateCoinAnnouncement => created.push(
+                    Announcement {
+                        origin_info: coin_spend.coin.name(),
+                        message: condition.vars[0].clone(),
+                    }
+                    .name(),
+                ),
+                ConditionOpcode::AssertCoinAnnouncement => {
+                    asserted.push(Bytes32::from(condition.vars[0].clone()))
+                }
+                _ => {}
+            }
+        }
+    }
+    // Each coin asserts the announcement of the next one, closing the ring.
+    assert_eq!(asserted.len(), 2);
+    for announcement in &asserted {
+        assert!(created.contains(announcement));
+    }
+
+    let additions: Vec<Coin> = spend_bundle
+        .coin_spends
+        .iter()
+        .flat_map(|coin_spend| coin_spend.additions())
+        .collect();
+    let amounts: Vec<u64> = additions.iter().map(|coin| coin.amount).collect();
+    assert_eq!(amounts, vec![30, 120]);
+
+    let mut unbalanced = cats;
+    unbalanced[1].extra_delta = 1;
+    assert!(unsigned_spend_bundle_for_spendable_cats(&unbalanced).is_err());
+}
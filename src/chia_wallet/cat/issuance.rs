@@ -0,0 +1,278 @@
+use std::error::Error;
+
+use chia_bls::public_key::PublicKey;
+use num_bigint::BigInt;
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::condition_opcode::ConditionOpcode;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::puzzle_utils::{
+    make_condition, make_create_coin_condition, signed_int_program,
+};
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    get_puzzle_from_pk, solutionForConditions,
+};
+use crate::chia_wallet::standart::transaction::{
+    create_standard_spend_bundle, Payment, SpendableCoin,
+};
+use crate::program_utils::program::Program;
+
+use super::cat_utils::{
+    unsigned_spend_bundle_for_spendable_cats, SpendableCat, TAIL_REVEAL_AMOUNT,
+};
+use super::puzzles::cat_puzzle_program::CAT_MOD_HASH;
+use super::puzzles::curry_and_treehash::{atom_hash, curried_puzzle_hash};
+use super::puzzles::tails::{
+    DELEGATED_TAIL_MOD, EVERYTHING_WITH_SIGNATURE_MOD, GENESIS_BY_COIN_ID_MOD,
+};
+
+pub enum Tail {
+    GenesisByCoinId(Bytes32),
+    EverythingWithSignature(PublicKey),
+    DelegatedTail(PublicKey),
+}
+
+impl Tail {
+    pub fn puzzle(&self) -> Program {
+        match self {
+            Tail::GenesisByCoinId(genesis_coin_id) => {
+                GENESIS_BY_COIN_ID_MOD.curry(vec![Program::from(genesis_coin_id.bytes.clone())])
+            }
+            Tail::EverythingWithSignature(public_key) => EVERYTHING_WITH_SIGNATURE_MOD
+                .curry(vec![Program::from(public_key.to_bytes().to_vec())]),
+            Tail::DelegatedTail(public_key) => {
+                DELEGATED_TAIL_MOD.curry(vec![Program::from(public_key.to_bytes().to_vec())])
+            }
+        }
+    }
+
+    /// The asset id of CATs issued with this TAIL.
+    pub fn tail_hash(&self) -> Bytes32 {
+        self.puzzle().tree_hash()
+    }
+
+    /// A genesis-by-coin-id TAIL only runs once, at issuance.
+    pub fn allows_supply_change(&self) -> bool {
+        !matches!(self, Tail::GenesisByCoinId(_))
+    }
+}
+
+/// Solution for a delegated TAIL: the signed TAIL to run and its own solution.
+pub fn delegated_tail_solution(
+    delegated_puzzle: &Program,
+    delegated_solution: &Program,
+) -> Program {
+    Program::from(vec![delegated_puzzle.clone(), delegated_solution.clone()])
+}
+
+pub fn make_tail_reveal_condition(tail: &Program, tail_solution: &Program) -> Program {
+    make_condition(
+        ConditionOpcode::CreateCoin,
+        vec![
+            Program::from(Vec::<u8>::new()),
+            signed_int_program(&BigInt::from(TAIL_REVEAL_AMOUNT)),
+            tail.clone(),
+            tail_solution.clone(),
+        ],
+    )
+}
+
+pub fn cat_puzzle_hash(tail_hash: &Bytes32, inner_puzzle_hash: &Bytes32) -> Bytes32 {
+    let mod_hash = Bytes32::from(CAT_MOD_HASH.clone());
+    curried_puzzle_hash(
+        &mod_hash,
+        &[
+            atom_hash(&mod_hash.bytes),
+            atom_hash(&tail_hash.bytes),
+            inner_puzzle_hash.clone(),
+        ],
+    )
+}
+
+/// A CAT coin whose inner puzzle is the standard puzzle of `public_key`.
+#[derive(Clone)]
+pub struct StandardCatCoin {
+    pub coin: Coin,
+    pub public_key: PublicKey,
+    pub lineage_proof: LineageProof,
+}
+
+/// Mints `amount` mojos of a new CAT owned by `inner_public_key`. The first of `coins`
+/// creates the eve coin, which is spent in the same bundle to reveal the TAIL.
+pub fn create_cat_issuance_bundle(
+    coins: &[SpendableCoin],
+    tail: &Tail,
+    tail_solution: &Program,
+    inner_public_key: &PublicKey,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let origin = coins.first().ok_or("no coins to issue from")?;
+    if let Tail::GenesisByCoinId(genesis_coin_id) = tail {
+        if *genesis_coin_id != origin.coin.name() {
+            return Err("genesis coin id must be the first coin being spent".into());
+        }
+    }
+    if amount == 0 {
+        return Err("cannot issue zero mojos".into());
+    }
+    let tail_program = tail.puzzle();
+    let tail_hash = tail_program.tree_hash();
+    let inner_puzzle = get_puzzle_from_pk(inner_public_key.clone());
+    let inner_puzzle_hash = inner_puzzle.tree_hash();
+    let eve_puzzle_hash = cat_puzzle_hash(&tail_hash, &inner_puzzle_hash);
+
+    let payment = Payment {
+        puzzle_hash: eve_puzzle_hash.clone(),
+        amount,
+        memos: Vec::new(),
+    };
+    let xch_bundle = create_standard_spend_bundle(coins, &[payment], fee, change_puzzle_hash)?;
+
+    let eve_coin = Coin {
+        parent_coin_info: origin.coin.name(),
+        puzzle_hash: eve_puzzle_hash,
+        amount,
+    };
+    let inner_solution = solutionForConditions(Program::from(vec![
+        make_create_coin_condition(
+            &inner_puzzle_hash,
+            amount,
+            &[inner_puzzle_hash.bytes.clone()],
+        ),
+        make_tail_reveal_condition(&tail_program, tail_solution),
+    ]));
+    let eve_bundle = unsigned_spend_bundle_for_spendable_cats(&[SpendableCat {
+        coin: eve_coin,
+        limitations_program_hash: tail_hash,
+        inner_puzzle,
+        inner_solution,
+        lineage_proof: LineageProof::default(),
+        extra_delta: 0,
+    }])?;
+
+    let mut coin_spends = xch_bundle.coin_spends;
+    coin_spends.extend(eve_bundle.coin_spends);
+    Ok(SpendBundle {
+        coin_spends,
+        aggregated_signature: xch_bundle.aggregated_signature,
+    })
+}
+
+/// Spends `cats` into a single coin for `output_inner_puzzle_hash`, changing the
+/// supply by `extra_delta` with the TAIL revealed in the first spend.
+pub fn create_cat_supply_change_bundle(
+    cats: &[StandardCatCoin],
+    tail: &Tail,
+    tail_solution: &Program,
+    extra_delta: i64,
+    output_inner_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    if cats.is_empty() {
+        return Err("no CAT coins to spend".into());
+    }
+    if !tail.allows_supply_change() {
+        return Err("this TAIL does not allow melting or reissuing".into());
+    }
+    let total: i128 = cats.iter().map(|cat| cat.coin.amount as i128).sum();
+    let output = total + extra_delta as i128;
+    if output < 0 {
+        return Err(format!("cannot melt more than the {} mojos being spent", total).into());
+    }
+    let output = u64::try_from(output).map_err(|_| "CAT output amount overflows")?;
+
+    let tail_program = tail.puzzle();
+    let tail_hash = tail_program.tree_hash();
+    let mut spendable_cats = Vec::with_capacity(cats.len());
+    for (index, cat) in cats.iter().enumerate() {
+        let mut conditions = Vec::new();
+        let mut cat_extra_delta = 0;
+        if index == 0 {
+            if output > 0 {
+                conditions.push(make_create_coin_condition(
+                    output_inner_puzzle_hash,
+                    output,
+                    &[output_inner_puzzle_hash.bytes.clone()],
+                ));
+            }
+            conditions.push(make_tail_reveal_condition(&tail_program, tail_solution));
+            cat_extra_delta = extra_delta;
+        }
+        spendable_cats.push(SpendableCat {
+            coin: cat.coin.clone(),
+            limitations_program_hash: tail_hash.clone(),
+            inner_puzzle: get_puzzle_from_pk(cat.public_key.clone()),
+            inner_solution: solutionForConditions(Program::from(conditions)),
+            lineage_proof: cat.lineage_proof.clone(),
+            extra_delta: cat_extra_delta,
+        });
+    }
+    unsigned_spend_bundle_for_spendable_cats(&spendable_cats)
+}
+
+/// Destroys `melt_amount` mojos of CAT. The XCH they held is left to the bundle's
+/// fee unless another spend in the bundle claims it.
+pub fn create_cat_melt_bundle(
+    cats: &[StandardCatCoin],
+    tail: &Tail,
+    tail_solution: &Program,
+    melt_amount: u64,
+    output_inner_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let extra_delta = i64::try_from(melt_amount).map_err(|_| "melt amount is too large")?;
+    create_cat_supply_change_bundle(
+        cats,
+        tail,
+        tail_solution,
+        -extra_delta,
+        output_inner_puzzle_hash,
+    )
+}
+
+/// Mints `reissue_amount` more mojos of an existing CAT. The extra XCH backing them
+/// must come from another spend in the bundle.
+pub fn create_cat_reissue_bundle(
+    cats: &[StandardCatCoin],
+    tail: &Tail,
+    tail_solution: &Program,
+    reissue_amount: u64,
+    output_inner_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let extra_delta = i64::try_from(reissue_amount).map_err(|_| "reissue amount is too large")?;
+    create_cat_supply_change_bundle(
+        cats,
+        tail,
+        tail_solution,
+        extra_delta,
+        output_inner_puzzle_hash,
+    )
+}
+
+#[test]
+fn test_tail_hashes() {
+    let genesis: Bytes32 =
+        "493afb89eed93ab86741b2aa61b8f5de495d33ff9b781dfc8919e602b2afa150".into();
+    assert_eq!(GENESIS_BY_COIN_ID_MOD.tree_hash(), genesis);
+    let everything: Bytes32 =
+        "1720d13250a7c16988eaf530331cefa9dd57a76b2c82236bec8bbbff91499b89".into();
+    assert_eq!(EVERYTHING_WITH_SIGNATURE_MOD.tree_hash(), everything);
+    let delegated: Bytes32 =
+        "999c3696e167f8a79d938adc11feba3a3dcb39ccff69a426d570706e7b8ec399".into();
+    assert_eq!(DELEGATED_TAIL_MOD.tree_hash(), delegated);
+
+    let tail = Tail::GenesisByCoinId(Bytes32::from(vec![0x42; 32]));
+    let inner_puzzle_hash = Bytes32::from(vec![0x24; 32]);
+    assert_eq!(
+        cat_puzzle_hash(&tail.tail_hash(), &inner_puzzle_hash),
+        Bytes32::from(
+            super::puzzles::create_cat_outer_puzzle::create_cat_outer_puzzlehash(
+                tail.tail_hash().bytes,
+                inner_puzzle_hash.bytes.clone()
+            )
+            .unwrap()
+        )
+    );
+}
@@ -1 +1,3 @@
+pub(crate) mod cat_utils;
+pub(crate) mod issuance;
 pub(crate) mod puzzles;
\ No newline at end of file
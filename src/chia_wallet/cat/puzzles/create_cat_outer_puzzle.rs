@@ -1,3 +1,6 @@
+use std::error::Error;
+
+use crate::blockchain::sized_bytes::Bytes32;
 use crate::{
     api::{bytes_to_hex, hex_to_bytes},
     program_utils::{program::Program, serialized_program::SerializedProgram},
@@ -27,7 +30,10 @@ pub fn create_cat_puzzle(tail_hash: Vec<u8>, inner_puzzle_hash: Vec<u8>) -> Prog
     return cat_program.curry(args.clone());
 }
 // bytes tailhash,  bytes innerpuzhash
-pub fn create_cat_outer_puzzlehash(tail_hash: Vec<u8>, inner_puzzle_hash: Vec<u8>) -> Vec<u8> {
+pub fn create_cat_outer_puzzlehash(
+    tail_hash: Vec<u8>,
+    inner_puzzle_hash: Vec<u8>,
+) -> Result<Vec<u8>, Box<dyn Error>> {
    // let cat_program = CAT_MOD.clone();
     let cat_hash_program = CAT_MOD_HASH_PROGRAM.clone();
     let tail_hash_program = Program::from(&tail_hash.clone());
@@ -40,8 +46,21 @@ pub fn create_cat_outer_puzzlehash(tail_hash: Vec<u8>, inner_puzzle_hash: Vec<u8
     ]
     .to_vec();
 
-    let result = CURRY_AND_TREEHASH_MOD.clone().run(Program::from(solution));
-    let atom = result.program.as_atom().unwrap();
-    let puzzlehash  = atom.serialized.clone();
-    return puzzlehash;
+    let result = CURRY_AND_TREEHASH_MOD
+        .clone()
+        .run(Program::from(solution))?;
+    let atom = result
+        .program
+        .as_atom()
+        .ok_or("puzzle hash is not an atom")?;
+    Ok(atom.serialized.clone())
+}
+
+/// The full CAT2 puzzle wrapping `inner_puzzle`, as revealed when spending.
+pub fn construct_cat_puzzle(tail_hash: &Bytes32, inner_puzzle: &Program) -> Program {
+    CAT_MOD.curry(vec![
+        CAT_MOD_HASH_PROGRAM.clone(),
+        Program::from(tail_hash.bytes.clone()),
+        inner_puzzle.clone(),
+    ])
 }
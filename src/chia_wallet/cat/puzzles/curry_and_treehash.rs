@@ -1,3 +1,5 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::utils::hash_256;
 use crate::program_utils::{serialized_program::SerializedProgram, program::Program};
 
  
@@ -14,3 +16,38 @@ pub static CURRY_AND_TREEHASH_MOD_STR : &str = "ff02ffff01ff02ff1effff04ff02ffff
     };
  
 }
+
+pub fn atom_hash(atom: &[u8]) -> Bytes32 {
+    let mut to_hash = vec![1u8];
+    to_hash.extend_from_slice(atom);
+    Bytes32::from(hash_256(to_hash))
+}
+
+pub fn pair_hash(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+    let mut to_hash = vec![2u8];
+    to_hash.extend_from_slice(&left.bytes);
+    to_hash.extend_from_slice(&right.bytes);
+    Bytes32::from(hash_256(to_hash))
+}
+
+/// Native equivalent of `CURRY_AND_TREEHASH_MOD`: the tree hash of `mod_hash`'s
+/// puzzle curried with arguments whose tree hashes are `arg_hashes`.
+pub fn curried_puzzle_hash(mod_hash: &Bytes32, arg_hashes: &[Bytes32]) -> Bytes32 {
+    let nil_hash = atom_hash(&[]);
+    let quote_hash = atom_hash(&[1]);
+    let cons_hash = atom_hash(&[4]);
+    let apply_hash = atom_hash(&[2]);
+    let mut env_hash = atom_hash(&[1]);
+    for arg_hash in arg_hashes.iter().rev() {
+        let quoted_arg = pair_hash(&quote_hash, arg_hash);
+        env_hash = pair_hash(
+            &cons_hash,
+            &pair_hash(&quoted_arg, &pair_hash(&env_hash, &nil_hash)),
+        );
+    }
+    let quoted_mod = pair_hash(&quote_hash, mod_hash);
+    pair_hash(
+        &apply_hash,
+        &pair_hash(&quoted_mod, &pair_hash(&env_hash, &nil_hash)),
+    )
+}
@@ -1,3 +1,4 @@
 pub(crate) mod cat_puzzle_program;
 pub(crate) mod create_cat_outer_puzzle;
-pub(crate) mod curry_and_treehash;
\ No newline at end of file
+pub(crate) mod curry_and_treehash;
+pub(crate) mod tails;
\ No newline at end of file
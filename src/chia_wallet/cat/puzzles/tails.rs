@@ -0,0 +1,30 @@
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Allows a single issuance, from the coin whose id is curried in.
+pub static GENESIS_BY_COIN_ID_STR: &str =
+    "ff02ffff03ff2fffff01ff0880ffff01ff02ffff03ffff09ff2dff0280ff80ffff01ff088080ff018080ff0180";
+
+/// Allows any supply change signed (AGG_SIG_ME over the delta) by the curried key.
+pub static EVERYTHING_WITH_SIGNATURE_STR: &str =
+    "ff02ffff01ff04ffff04ff02ffff04ff05ffff04ff5fff80808080ff8080ffff04ffff0132ff018080";
+
+/// Runs any TAIL whose tree hash is signed (AGG_SIG_UNSAFE) by the curried key.
+pub static DELEGATED_TAIL_STR: &str = "ff02ffff01ff04ffff04ff04ffff04ff05ffff04ffff02ff06ffff04ff02ffff04ff82027fff80808080ff80808080ffff02ff82027fffff04ff0bffff04ff17ffff04ff2fffff04ff5fffff04ff81bfff82057f80808080808080ffff04ffff01ff31ff02ffff03ffff07ff0580ffff01ff0bffff0102ffff02ff06ffff04ff02ffff04ff09ff80808080ffff02ff06ffff04ff02ffff04ff0dff8080808080ffff01ff0bffff0101ff058080ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref GENESIS_BY_COIN_ID_MOD: Program = {
+        SerializedProgram::from_hex(GENESIS_BY_COIN_ID_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref EVERYTHING_WITH_SIGNATURE_MOD: Program = {
+        SerializedProgram::from_hex(EVERYTHING_WITH_SIGNATURE_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref DELEGATED_TAIL_MOD: Program = {
+        SerializedProgram::from_hex(DELEGATED_TAIL_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+}
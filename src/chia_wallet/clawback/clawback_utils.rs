@@ -0,0 +1,375 @@
+use std::error::Error;
+
+use chia_bls::public_key::PublicKey;
+use num_bigint::BigInt;
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::condition_opcode::ConditionOpcode;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::blockchain::utils::atom_to_int;
+use crate::chia_wallet::cat::puzzles::curry_and_treehash::{
+    atom_hash, curried_puzzle_hash, pair_hash,
+};
+use crate::chia_wallet::puzzle_utils::{
+    int_program, make_condition, make_create_coin_condition, make_reserve_fee_condition,
+};
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    get_puzzle_from_pk, solutionForConditions,
+};
+use crate::chia_wallet::standart::transaction::{
+    create_standard_spend_bundle_with_conditions, Payment, SpendableCoin,
+};
+use crate::program_utils::condition_utils::{condition_programs_for_solution, create_coin_memos};
+use crate::program_utils::program::Program;
+use crate::program_utils::utils::INFINITE_COST;
+
+use super::puzzles::augmented_condition::{AUGMENTED_CONDITION_MOD, AUGMENTED_CONDITION_MOD_HASH};
+use super::puzzles::p2_1_of_n::P2_1_OF_N_MOD;
+use super::puzzles::p2_puzzle_hash::P2_PUZZLE_HASH_MOD;
+
+/// `RemarkDataType.CLAWBACK`: the first argument of the REMARK condition that carries
+/// the clawback metadata next to the CREATE_COIN.
+pub const CLAWBACK_REMARK_TYPE: u8 = 2;
+
+/// A clawback coin pays `recipient_puzzle_hash` once `time_lock` seconds have passed
+/// since its creation, and `sender_puzzle_hash` can take it back before anyone claims it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClawbackMetadata {
+    pub time_lock: u64,
+    pub sender_puzzle_hash: Bytes32,
+    pub recipient_puzzle_hash: Bytes32,
+}
+
+impl ClawbackMetadata {
+    /// Streamable bytes, as carried in the REMARK condition.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.time_lock.to_be_bytes().to_vec();
+        bytes.extend(&self.sender_puzzle_hash.bytes);
+        bytes.extend(&self.recipient_puzzle_hash.bytes);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<ClawbackMetadata, Box<dyn Error>> {
+        if bytes.len() != 72 {
+            return Err(format!("clawback metadata is {} bytes, expected 72", bytes.len()).into());
+        }
+        let mut time_lock = [0u8; 8];
+        time_lock.copy_from_slice(&bytes[..8]);
+        Ok(ClawbackMetadata {
+            time_lock: u64::from_be_bytes(time_lock),
+            sender_puzzle_hash: Bytes32::from(bytes[8..40].to_vec()),
+            recipient_puzzle_hash: Bytes32::from(bytes[40..].to_vec()),
+        })
+    }
+
+    fn time_lock_condition(&self) -> Program {
+        make_condition(
+            ConditionOpcode::AssertSecondsRelative,
+            vec![int_program(self.time_lock)],
+        )
+    }
+
+    /// The recipient's puzzle with the time lock prepended to its conditions.
+    fn claim_puzzle(&self, recipient_puzzle: &Program) -> Program {
+        AUGMENTED_CONDITION_MOD.curry(vec![self.time_lock_condition(), recipient_puzzle.clone()])
+    }
+
+    fn claim_puzzle_hash(&self) -> Bytes32 {
+        curried_puzzle_hash(
+            &AUGMENTED_CONDITION_MOD_HASH,
+            &[
+                self.time_lock_condition().tree_hash(),
+                self.recipient_puzzle_hash.clone(),
+            ],
+        )
+    }
+
+    fn clawback_puzzle(&self) -> Program {
+        P2_PUZZLE_HASH_MOD.curry(vec![Program::from(self.sender_puzzle_hash.bytes.clone())])
+    }
+
+    /// The claim and clawback puzzle hashes, in the merkle tree's leaf order.
+    fn leaves(&self) -> [Bytes32; 2] {
+        [self.claim_puzzle_hash(), self.clawback_puzzle().tree_hash()]
+    }
+
+    fn merkle_root(&self) -> Bytes32 {
+        let [claim, clawback] = self.leaves();
+        pair_hash(&atom_hash(&claim.bytes), &atom_hash(&clawback.bytes))
+    }
+
+    /// `(bitpath sibling_hash)` for one of the two leaves.
+    fn merkle_proof(&self, leaf: &Bytes32) -> Result<Program, Box<dyn Error>> {
+        let [claim, clawback] = self.leaves();
+        let (bitpath, sibling) = if *leaf == claim {
+            (0, clawback)
+        } else if *leaf == clawback {
+            (1, claim)
+        } else {
+            return Err("puzzle is not in the clawback merkle tree".into());
+        };
+        Ok(Program::from(vec![
+            int_program(bitpath),
+            Program::from(atom_hash(&sibling.bytes).bytes),
+        ]))
+    }
+
+    pub fn puzzle(&self) -> Program {
+        P2_1_OF_N_MOD.curry(vec![Program::from(self.merkle_root().bytes)])
+    }
+
+    pub fn puzzle_hash(&self) -> Bytes32 {
+        self.puzzle().tree_hash()
+    }
+
+    pub fn remark_condition(&self) -> Program {
+        make_condition(
+            ConditionOpcode::Remark,
+            vec![
+                int_program(CLAWBACK_REMARK_TYPE as u64),
+                Program::from(self.to_bytes()),
+            ],
+        )
+    }
+
+    /// Spends the clawback coin through `inner_puzzle`, which must be the sender's or
+    /// the recipient's puzzle, with `inner_solution`.
+    pub fn solution(
+        &self,
+        inner_puzzle: &Program,
+        inner_solution: &Program,
+    ) -> Result<Program, Box<dyn Error>> {
+        let inner_puzzle_hash = inner_puzzle.tree_hash();
+        let (path_puzzle, path_solution) = if inner_puzzle_hash == self.sender_puzzle_hash {
+            (
+                self.clawback_puzzle(),
+                Program::from(vec![inner_puzzle.clone(), inner_solution.clone()]),
+            )
+        } else if inner_puzzle_hash == self.recipient_puzzle_hash {
+            (
+                self.claim_puzzle(inner_puzzle),
+                Program::from(vec![inner_solution.clone()]),
+            )
+        } else {
+            return Err("puzzle is neither the clawback sender's nor recipient's".into());
+        };
+        Ok(Program::from(vec![
+            self.merkle_proof(&path_puzzle.tree_hash())?,
+            path_puzzle,
+            path_solution,
+        ]))
+    }
+}
+
+/// Sends `amount` from standard XCH `coins` to a clawback coin for `metadata`, hinted to
+/// the recipient and announced with the metadata REMARK, as the reference wallet does.
+pub fn create_clawback_payment(
+    coins: &[SpendableCoin],
+    metadata: &ClawbackMetadata,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    if metadata.time_lock < 1 {
+        return Err("the clawback time lock must be at least 1 second".into());
+    }
+    let payment = Payment {
+        puzzle_hash: metadata.puzzle_hash(),
+        amount,
+        memos: vec![metadata.recipient_puzzle_hash.bytes.clone()],
+    };
+    create_standard_spend_bundle_with_conditions(
+        coins,
+        &[payment],
+        fee,
+        change_puzzle_hash,
+        vec![metadata.remark_condition()],
+    )
+}
+
+fn spend_clawback_coin(
+    coin: &Coin,
+    metadata: &ClawbackMetadata,
+    public_key: &PublicKey,
+    destination_puzzle_hash: &Bytes32,
+    fee: u64,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    if metadata.puzzle_hash() != coin.puzzle_hash {
+        return Err(format!("coin {} is not locked by this clawback", coin.name()).into());
+    }
+    let amount = coin
+        .amount
+        .checked_sub(fee)
+        .ok_or("the fee is larger than the clawback coin")?;
+    let mut conditions = vec![make_create_coin_condition(
+        destination_puzzle_hash,
+        amount,
+        &[destination_puzzle_hash.bytes.clone()],
+    )];
+    if fee > 0 {
+        conditions.push(make_reserve_fee_condition(fee));
+    }
+    let inner_puzzle = get_puzzle_from_pk(public_key.clone());
+    let solution = metadata.solution(
+        &inner_puzzle,
+        &solutionForConditions(Program::from(conditions)),
+    )?;
+    Ok(CoinSpend {
+        coin: coin.clone(),
+        puzzle_reveal: metadata.puzzle().into(),
+        solution: solution.into(),
+    })
+}
+
+/// The recipient, owner of `public_key`'s standard puzzle, claims the clawback `coin`
+/// to `destination_puzzle_hash`. Only valid once the time lock has passed.
+pub fn create_clawback_claim_spend(
+    coin: &Coin,
+    metadata: &ClawbackMetadata,
+    public_key: &PublicKey,
+    destination_puzzle_hash: &Bytes32,
+    fee: u64,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    if get_puzzle_from_pk(public_key.clone()).tree_hash() != metadata.recipient_puzzle_hash {
+        return Err("public key is not the clawback recipient's".into());
+    }
+    spend_clawback_coin(coin, metadata, public_key, destination_puzzle_hash, fee)
+}
+
+/// The sender, owner of `public_key`'s standard puzzle, takes the clawback `coin` back
+/// to `destination_puzzle_hash`.
+pub fn create_clawback_spend(
+    coin: &Coin,
+    metadata: &ClawbackMetadata,
+    public_key: &PublicKey,
+    destination_puzzle_hash: &Bytes32,
+    fee: u64,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    if get_puzzle_from_pk(public_key.clone()).tree_hash() != metadata.sender_puzzle_hash {
+        return Err("public key is not the clawback sender's".into());
+    }
+    spend_clawback_coin(coin, metadata, public_key, destination_puzzle_hash, fee)
+}
+
+/// The clawback coins created by `parent_spend`: CREATE_COINs of the puzzle described by
+/// the spend's clawback REMARK, hinted to its recipient.
+pub fn parse_clawback_coins(
+    parent_spend: &CoinSpend,
+) -> Result<Vec<(Coin, ClawbackMetadata)>, Box<dyn Error>> {
+    let (conditions, _cost) = condition_programs_for_solution(
+        &parent_spend.puzzle_reveal,
+        &parent_spend.solution,
+        INFINITE_COST,
+    )?;
+    let mut metadata = Vec::new();
+    for (condition, _program) in &conditions {
+        if condition.opcode == ConditionOpcode::Remark
+            && condition.vars.len() == 2
+            && atom_to_int(&condition.vars[0]) == BigInt::from(CLAWBACK_REMARK_TYPE)
+        {
+            if let Ok(parsed) = ClawbackMetadata::from_bytes(&condition.vars[1]) {
+                metadata.push(parsed);
+            }
+        }
+    }
+    let mut clawbacks = Vec::new();
+    for (condition, program) in &conditions {
+        if condition.opcode != ConditionOpcode::CreateCoin || condition.vars.len() < 2 {
+            continue;
+        }
+        // The first memo is the recipient hint.
+        let hint = match create_coin_memos(program).into_iter().next() {
+            Some(hint) => hint,
+            None => continue,
+        };
+        let puzzle_hash = Bytes32::from(condition.vars[0].clone());
+        let matched = metadata.iter().find(|metadata| {
+            metadata.puzzle_hash() == puzzle_hash && metadata.recipient_puzzle_hash.bytes == hint
+        });
+        if let Some(matched) = matched {
+            let coin = Coin {
+                parent_coin_info: parent_spend.coin.name(),
+                puzzle_hash,
+                amount: atom_to_int(&condition.vars[1]).try_into()?,
+            };
+            clawbacks.push((coin, matched.clone()));
+        }
+    }
+    Ok(clawbacks)
+}
+
+#[test]
+fn test_clawback_puzzles() {
+    use crate::chia_wallet::clawback::puzzles::p2_1_of_n::P2_1_OF_N_MOD_HASH;
+    use crate::chia_wallet::clawback::puzzles::p2_puzzle_hash::P2_PUZZLE_HASH_MOD_HASH;
+    use crate::program_utils::condition_utils::conditions_for_solution;
+    use chia_bls::secret_key::SecretKey;
+
+    let p2_1_of_n: Bytes32 =
+        "46b29fd87fbeb6737600c4543931222a6c1ed3db6fa5601a3ca284a9f4efe780".into();
+    let p2_puzzle_hash: Bytes32 =
+        "13e29a62b42cd2ef72a79e4bacdc59733ca6310d65af83d349360d36ec622363".into();
+    let augmented_condition: Bytes32 =
+        "d303eafa617bedf0bc05850dd014e10fbddf622187dc07891a2aacba9d8a93f6".into();
+    assert_eq!(*P2_1_OF_N_MOD_HASH, p2_1_of_n);
+    assert_eq!(*P2_PUZZLE_HASH_MOD_HASH, p2_puzzle_hash);
+    assert_eq!(*AUGMENTED_CONDITION_MOD_HASH, augmented_condition);
+
+    let sender = SecretKey::from_seed(&[1u8; 64]).public_key();
+    let recipient = SecretKey::from_seed(&[2u8; 64]).public_key();
+    let metadata = ClawbackMetadata {
+        time_lock: 3600,
+        sender_puzzle_hash: get_puzzle_from_pk(sender.clone()).tree_hash(),
+        recipient_puzzle_hash: get_puzzle_from_pk(recipient.clone()).tree_hash(),
+    };
+    assert_eq!(
+        ClawbackMetadata::from_bytes(&metadata.to_bytes()).unwrap(),
+        metadata
+    );
+
+    let coins = vec![SpendableCoin {
+        coin: Coin {
+            parent_coin_info: Bytes32::from(vec![0x01; 32]),
+            puzzle_hash: metadata.sender_puzzle_hash.clone(),
+            amount: 1000,
+        },
+        public_key: sender.clone(),
+    }];
+    let spend_bundle =
+        create_clawback_payment(&coins, &metadata, 600, 0, &metadata.sender_puzzle_hash).unwrap();
+    let clawbacks = parse_clawback_coins(&spend_bundle.coin_spends[0]).unwrap();
+    assert_eq!(clawbacks.len(), 1);
+    let (coin, parsed) = &clawbacks[0];
+    assert_eq!(coin.amount, 600);
+    assert_eq!(*parsed, metadata);
+
+    // The claim path prepends the time lock to the recipient's conditions.
+    let claim = create_clawback_claim_spend(
+        coin,
+        &metadata,
+        &recipient,
+        &metadata.recipient_puzzle_hash,
+        0,
+    )
+    .unwrap();
+    let (conditions, _cost) =
+        conditions_for_solution(&claim.puzzle_reveal, &claim.solution, INFINITE_COST).unwrap();
+    assert_eq!(conditions[0].opcode, ConditionOpcode::AssertSecondsRelative);
+    assert_eq!(atom_to_int(&conditions[0].vars[0]), BigInt::from(3600));
+    assert_eq!(
+        claim.additions()[0].puzzle_hash,
+        metadata.recipient_puzzle_hash
+    );
+
+    let clawback =
+        create_clawback_spend(coin, &metadata, &sender, &metadata.sender_puzzle_hash, 10).unwrap();
+    let additions = clawback.additions();
+    assert_eq!(additions[0].puzzle_hash, metadata.sender_puzzle_hash);
+    assert_eq!(additions[0].amount, 590);
+    assert!(
+        create_clawback_spend(coin, &metadata, &recipient, &metadata.sender_puzzle_hash, 0)
+            .is_err()
+    );
+}
@@ -0,0 +1,2 @@
+pub(crate) mod clawback_utils;
+pub(crate) mod puzzles;
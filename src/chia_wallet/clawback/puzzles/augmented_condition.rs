@@ -0,0 +1,15 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Curried with a condition and an inner puzzle, and prepends the condition to the
+/// inner puzzle's output. Its solution is `(inner_solution)`.
+pub static AUGMENTED_CONDITION_STR: &str = "ff04ff02ffff02ff05ff0b8080";
+
+lazy_static::lazy_static! {
+    pub static ref AUGMENTED_CONDITION_MOD: Program = {
+        SerializedProgram::from_hex(AUGMENTED_CONDITION_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref AUGMENTED_CONDITION_MOD_HASH: Bytes32 = AUGMENTED_CONDITION_MOD.tree_hash();
+}
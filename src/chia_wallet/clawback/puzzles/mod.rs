@@ -0,0 +1,3 @@
+pub(crate) mod augmented_condition;
+pub(crate) mod p2_1_of_n;
+pub(crate) mod p2_puzzle_hash;
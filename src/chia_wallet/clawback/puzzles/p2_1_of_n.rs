@@ -0,0 +1,15 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Curried with a merkle root. Its solution is a merkle proof, a puzzle whose tree hash
+/// the proof places under the root, and that puzzle's solution.
+pub static P2_1_OF_N_STR: &str = "ff02ffff01ff02ffff03ffff09ff05ffff02ff06ffff04ff02ffff04ffff0bffff0101ffff02ff04ffff04ff02ffff04ff17ff8080808080ffff04ff0bff808080808080ffff01ff02ff17ff2f80ffff01ff088080ff0180ffff04ffff01ffff02ffff03ffff07ff0580ffff01ff0bffff0102ffff02ff04ffff04ff02ffff04ff09ff80808080ffff02ff04ffff04ff02ffff04ff0dff8080808080ffff01ff0bffff0101ff058080ff0180ff02ffff03ff1bffff01ff02ff06ffff04ff02ffff04ffff02ffff03ffff18ffff0101ff1380ffff01ff0bffff0102ff2bff0580ffff01ff0bffff0102ff05ff2b8080ff0180ffff04ffff04ffff17ff13ffff0181ff80ff3b80ff8080808080ffff010580ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref P2_1_OF_N_MOD: Program = {
+        SerializedProgram::from_hex(P2_1_OF_N_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref P2_1_OF_N_MOD_HASH: Bytes32 = P2_1_OF_N_MOD.tree_hash();
+}
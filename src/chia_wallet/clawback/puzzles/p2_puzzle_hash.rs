@@ -0,0 +1,15 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Curried with a puzzle hash. Its solution is `(inner_puzzle inner_solution)` for any
+/// inner puzzle with that hash.
+pub static P2_PUZZLE_HASH_STR: &str = "ff02ffff01ff02ffff03ffff09ff05ffff02ff02ffff04ff02ffff04ff0bff8080808080ffff01ff02ff0bff1780ffff01ff088080ff0180ffff04ffff01ff02ffff03ffff07ff0580ffff01ff0bffff0102ffff02ff02ffff04ff02ffff04ff09ff80808080ffff02ff02ffff04ff02ffff04ff0dff8080808080ffff01ff0bffff0101ff058080ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref P2_PUZZLE_HASH_MOD: Program = {
+        SerializedProgram::from_hex(P2_PUZZLE_HASH_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref P2_PUZZLE_HASH_MOD_HASH: Bytes32 = P2_PUZZLE_HASH_MOD.tree_hash();
+}
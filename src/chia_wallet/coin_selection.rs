@@ -0,0 +1,326 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use rand::Rng;
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_record::CoinRecord;
+use crate::blockchain::sized_bytes::Bytes32;
+
+const KNAPSACK_ITERATIONS: usize = 1000;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SelectionStrategy {
+    /// A single coin worth exactly the amount.
+    Exact,
+    /// The smallest single coin covering the amount, falling back to largest-first.
+    SmallestSufficient,
+    LargestFirst,
+    /// Randomised search for the inputs that overshoot the amount the least.
+    Knapsack,
+}
+
+pub struct CoinSelectionConfig {
+    pub max_coins: usize,
+    /// Coins worth less than this are treated as dust and never selected.
+    pub min_coin_amount: u64,
+    /// Coins already used by pending transactions.
+    pub excluded_coin_ids: HashSet<Bytes32>,
+}
+
+impl Default for CoinSelectionConfig {
+    fn default() -> Self {
+        CoinSelectionConfig {
+            max_coins: 500,
+            min_coin_amount: 0,
+            excluded_coin_ids: HashSet::new(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CoinSelectionError {
+    InsufficientFunds {
+        requested: u64,
+        available: u128,
+    },
+    /// Enough funds only when counting coins below `min_coin_amount`.
+    Dust {
+        requested: u64,
+        available: u128,
+        min_coin_amount: u64,
+    },
+    TooManyCoins {
+        requested: u64,
+        max_coins: usize,
+    },
+    NoExactMatch(u64),
+}
+
+impl fmt::Display for CoinSelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoinSelectionError::InsufficientFunds {
+                requested,
+                available,
+            } => write!(
+                f,
+                "insufficient funds: requested {} but only {} is spendable",
+                requested, available
+            ),
+            CoinSelectionError::Dust {
+                requested,
+                available,
+                min_coin_amount,
+            } => write!(
+                f,
+                "requested {} but only {} is spendable in coins of at least {}, the rest is dust",
+                requested, available, min_coin_amount
+            ),
+            CoinSelectionError::TooManyCoins {
+                requested,
+                max_coins,
+            } => write!(
+                f,
+                "paying {} would take more than {} coins",
+                requested, max_coins
+            ),
+            CoinSelectionError::NoExactMatch(amount) => {
+                write!(f, "no coin is worth exactly {}", amount)
+            }
+        }
+    }
+}
+
+impl Error for CoinSelectionError {}
+
+fn is_unspent(record: &CoinRecord) -> bool {
+    !record.spent && record.spent_block_index == 0
+}
+
+fn sum(coins: &[&Coin]) -> u128 {
+    coins.iter().map(|coin| coin.amount as u128).sum()
+}
+
+/// Picks coins worth at least `amount` from `records`. Selection only looks at
+/// amounts, so the same records work for XCH and for a single CAT's coins.
+pub fn select_coins(
+    records: &[CoinRecord],
+    amount: u64,
+    strategy: SelectionStrategy,
+    config: &CoinSelectionConfig,
+) -> Result<Vec<Coin>, CoinSelectionError> {
+    let mut seen = HashSet::new();
+    let unspent: Vec<&Coin> = records
+        .iter()
+        .filter(|record| is_unspent(record))
+        .map(|record| &record.coin)
+        .filter(|coin| {
+            let name = coin.name();
+            !config.excluded_coin_ids.contains(&name) && seen.insert(name)
+        })
+        .collect();
+    let mut spendable: Vec<&Coin> = unspent
+        .iter()
+        .filter(|coin| coin.amount >= config.min_coin_amount)
+        .copied()
+        .collect();
+    spendable.sort_by_key(|coin| std::cmp::Reverse(coin.amount));
+
+    let target = amount as u128;
+    let available = sum(&spendable);
+    if available < target {
+        let with_dust = sum(&unspent);
+        if with_dust >= target {
+            return Err(CoinSelectionError::Dust {
+                requested: amount,
+                available,
+                min_coin_amount: config.min_coin_amount,
+            });
+        }
+        return Err(CoinSelectionError::InsufficientFunds {
+            requested: amount,
+            available: with_dust,
+        });
+    }
+    if amount == 0 {
+        return Ok(Vec::new());
+    }
+    let largest = &spendable[..spendable.len().min(config.max_coins)];
+    if sum(largest) < target {
+        return Err(CoinSelectionError::TooManyCoins {
+            requested: amount,
+            max_coins: config.max_coins,
+        });
+    }
+
+    let selected = match strategy {
+        SelectionStrategy::Exact => vec![spendable
+            .iter()
+            .find(|coin| coin.amount == amount)
+            .copied()
+            .ok_or(CoinSelectionError::NoExactMatch(amount))?],
+        SelectionStrategy::SmallestSufficient => {
+            match spendable.iter().rev().find(|coin| coin.amount >= amount) {
+                Some(coin) => vec![*coin],
+                None => largest_first(&spendable, target),
+            }
+        }
+        SelectionStrategy::LargestFirst => largest_first(&spendable, target),
+        SelectionStrategy::Knapsack => knapsack(&spendable, target, config.max_coins)
+            .unwrap_or_else(|| largest_first(&spendable, target)),
+    };
+    Ok(selected.into_iter().cloned().collect())
+}
+
+fn largest_first<'a>(sorted: &[&'a Coin], target: u128) -> Vec<&'a Coin> {
+    let mut selected = Vec::new();
+    let mut total: u128 = 0;
+    for coin in sorted {
+        if total >= target {
+            break;
+        }
+        total += coin.amount as u128;
+        selected.push(*coin);
+    }
+    selected
+}
+
+// Same approximation as the reference wallet: include coins at random, then
+// deterministically, backing off the last coin whenever the target is passed
+// and remembering the smallest overshoot that fits in `max_coins`.
+fn knapsack<'a>(sorted: &[&'a Coin], target: u128, max_coins: usize) -> Option<Vec<&'a Coin>> {
+    let mut rng = rand::thread_rng();
+    let mut best: Option<(u128, Vec<bool>)> = None;
+    for _ in 0..KNAPSACK_ITERATIONS {
+        let mut included = vec![false; sorted.len()];
+        let mut total: u128 = 0;
+        let mut count = 0;
+        let mut target_reached = false;
+        for pass in 0..2 {
+            if target_reached {
+                break;
+            }
+            for (i, coin) in sorted.iter().enumerate() {
+                let take = if pass == 0 {
+                    rng.gen_bool(0.5)
+                } else {
+                    !included[i]
+                };
+                if !take || included[i] {
+                    continue;
+                }
+                included[i] = true;
+                total += coin.amount as u128;
+                count += 1;
+                if total >= target {
+                    target_reached = true;
+                    let better = match &best {
+                        Some((best_total, _)) => total < *best_total,
+                        None => true,
+                    };
+                    if count <= max_coins && better {
+                        best = Some((total, included.clone()));
+                        if total == target {
+                            break;
+                        }
+                    }
+                    included[i] = false;
+                    total -= coin.amount as u128;
+                    count -= 1;
+                }
+            }
+        }
+        if let Some((best_total, _)) = &best {
+            if *best_total == target {
+                break;
+            }
+        }
+    }
+    best.map(|(_, included)| {
+        sorted
+            .iter()
+            .zip(included)
+            .filter(|(_, included)| *included)
+            .map(|(coin, _)| *coin)
+            .collect()
+    })
+}
+
+#[cfg(test)]
+fn test_records(amounts: &[u64]) -> Vec<CoinRecord> {
+    amounts
+        .iter()
+        .enumerate()
+        .map(|(i, amount)| CoinRecord {
+            coin: Coin {
+                parent_coin_info: Bytes32::from(vec![i as u8; 32]),
+                puzzle_hash: Bytes32::from(vec![0xee; 32]),
+                amount: *amount,
+            },
+            confirmed_block_index: 1,
+            spent_block_index: 0,
+            timestamp: 0,
+            coinbase: false,
+            spent: false,
+        })
+        .collect()
+}
+
+#[test]
+fn test_select_coins_strategies() {
+    let records = test_records(&[1, 5, 20, 50, 100]);
+    let config = CoinSelectionConfig::default();
+    let amounts = |coins: Vec<Coin>| coins.iter().map(|coin| coin.amount).collect::<Vec<u64>>();
+
+    let exact = select_coins(&records, 20, SelectionStrategy::Exact, &config).unwrap();
+    assert_eq!(amounts(exact), vec![20]);
+    assert_eq!(
+        select_coins(&records, 21, SelectionStrategy::Exact, &config),
+        Err(CoinSelectionError::NoExactMatch(21))
+    );
+    let smallest =
+        select_coins(&records, 21, SelectionStrategy::SmallestSufficient, &config).unwrap();
+    assert_eq!(amounts(smallest), vec![50]);
+    let largest = select_coins(&records, 120, SelectionStrategy::LargestFirst, &config).unwrap();
+    assert_eq!(amounts(largest), vec![100, 50]);
+    let knapsack = select_coins(&records, 121, SelectionStrategy::Knapsack, &config).unwrap();
+    assert_eq!(amounts(knapsack), vec![100, 20, 1]);
+}
+
+#[test]
+fn test_select_coins_errors() {
+    let mut records = test_records(&[1, 2, 3, 40]);
+    records[3].spent = true;
+    let mut config = CoinSelectionConfig {
+        max_coins: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        select_coins(&records, 7, SelectionStrategy::LargestFirst, &config),
+        Err(CoinSelectionError::InsufficientFunds {
+            requested: 7,
+            available: 6
+        })
+    );
+    assert_eq!(
+        select_coins(&records, 6, SelectionStrategy::LargestFirst, &config),
+        Err(CoinSelectionError::TooManyCoins {
+            requested: 6,
+            max_coins: 2
+        })
+    );
+    config.max_coins = 10;
+    config.min_coin_amount = 2;
+    assert_eq!(
+        select_coins(&records, 6, SelectionStrategy::LargestFirst, &config),
+        Err(CoinSelectionError::Dust {
+            requested: 6,
+            available: 5,
+            min_coin_amount: 2
+        })
+    );
+    config.excluded_coin_ids.insert(records[2].coin.name());
+    assert!(select_coins(&records, 3, SelectionStrategy::LargestFirst, &config).is_err());
+}
@@ -0,0 +1,412 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use chia_bls::public_key::PublicKey;
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::cat::puzzles::curry_and_treehash::curried_puzzle_hash;
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::puzzle_utils::{
+    bytes32_atom, int_program, make_create_coin_condition, make_create_puzzle_announcement,
+};
+use crate::chia_wallet::singleton::singleton_utils::{
+    create_eve_spend, create_singleton_launch_bundle, generate_launcher_coin,
+    match_singleton_puzzle, puzzle_for_singleton, singleton_struct, spend_singleton,
+};
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    get_puzzle_from_pk, solutionForConditions,
+};
+use crate::chia_wallet::standart::transaction::SpendableCoin;
+use crate::program_utils::program::Program;
+
+use super::puzzles::did_innerpuz::{DID_INNERPUZ_MOD, DID_INNERPUZ_MOD_HASH};
+
+/// Everything curried into a DID inner puzzle, except the p2 puzzle that owns it.
+#[derive(Clone, Debug)]
+pub struct DidInfo {
+    pub launcher_id: Bytes32,
+    /// Tree hash of the list of DIDs allowed to approve a recovery.
+    pub recovery_list_hash: Bytes32,
+    pub num_verifications_required: u64,
+    pub metadata: Program,
+}
+
+impl DidInfo {
+    fn curried_args(&self) -> Vec<Program> {
+        vec![
+            Program::from(self.recovery_list_hash.bytes.clone()),
+            int_program(self.num_verifications_required),
+            singleton_struct(&self.launcher_id),
+            self.metadata.clone(),
+        ]
+    }
+
+    pub fn inner_puzzle(&self, p2_puzzle: &Program) -> Program {
+        let mut args = vec![p2_puzzle.clone()];
+        args.extend(self.curried_args());
+        DID_INNERPUZ_MOD.curry(args)
+    }
+
+    /// Inner puzzle hash for a p2 puzzle known only by its hash, as when transferring.
+    pub fn inner_puzzle_hash(&self, p2_puzzle_hash: &Bytes32) -> Bytes32 {
+        let mut arg_hashes = vec![p2_puzzle_hash.clone()];
+        arg_hashes.extend(self.curried_args().iter().map(|arg| arg.tree_hash()));
+        curried_puzzle_hash(&DID_INNERPUZ_MOD_HASH, &arg_hashes)
+    }
+
+    pub fn puzzle(&self, p2_puzzle: &Program) -> Program {
+        puzzle_for_singleton(&self.launcher_id, &self.inner_puzzle(p2_puzzle))
+    }
+}
+
+pub fn recovery_list_hash(recovery_list: &[Bytes32]) -> Bytes32 {
+    Program::from(
+        recovery_list
+            .iter()
+            .map(|did_id| Program::from(did_id.bytes.clone()))
+            .collect::<Vec<Program>>(),
+    )
+    .tree_hash()
+}
+
+/// DID metadata is a list of `(key . value)` string pairs.
+pub fn did_metadata_program(metadata: &BTreeMap<String, String>) -> Program {
+    Program::from(
+        metadata
+            .iter()
+            .map(|(key, value)| {
+                Program::from(key.as_bytes().to_vec())
+                    .cons(&Program::from(value.as_bytes().to_vec()))
+            })
+            .collect::<Vec<Program>>(),
+    )
+}
+
+pub fn did_metadata_from_program(
+    program: &Program,
+) -> Result<BTreeMap<String, String>, Box<dyn Error>> {
+    let mut metadata = BTreeMap::new();
+    for item in program.iter() {
+        let (key, value) = item.as_pair().ok_or("DID metadata entry is not a pair")?;
+        let key = key.as_vec().ok_or("DID metadata key is not an atom")?;
+        let value = value.as_vec().ok_or("DID metadata value is not an atom")?;
+        metadata.insert(String::from_utf8(key)?, String::from_utf8(value)?);
+    }
+    Ok(metadata)
+}
+
+/// Mode 1 runs the p2 puzzle. Its conditions pass through unchanged, so the DID is
+/// recreated by a CREATE_COIN of the new DID inner puzzle hash.
+pub fn did_inner_solution(p2_solution: Program) -> Program {
+    Program::from(vec![int_program(1), p2_solution])
+}
+
+fn recreate_inner_solution(
+    new_inner_puzzle_hash: &Bytes32,
+    new_p2_puzzle_hash: &Bytes32,
+    amount: u64,
+    extra_conditions: Vec<Program>,
+) -> Program {
+    let mut conditions = vec![make_create_coin_condition(
+        new_inner_puzzle_hash,
+        amount,
+        &[new_p2_puzzle_hash.bytes.clone()],
+    )];
+    conditions.extend(extra_conditions);
+    did_inner_solution(solutionForConditions(Program::from(conditions)))
+}
+
+/// Creates a DID owned by the standard puzzle of `public_key` from standard XCH `coins`,
+/// including the eve spend. Returns the launcher id and the unsigned bundle.
+#[allow(clippy::too_many_arguments)]
+pub fn create_did_launch_bundle(
+    coins: &[SpendableCoin],
+    public_key: &PublicKey,
+    recovery_list: &[Bytes32],
+    num_verifications_required: u64,
+    metadata: &Program,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<(Bytes32, SpendBundle), Box<dyn Error>> {
+    if num_verifications_required as usize > recovery_list.len() {
+        return Err("more recovery verifications required than recovery DIDs".into());
+    }
+    let origin = coins.first().ok_or("no coins to create the DID from")?;
+    let info = DidInfo {
+        launcher_id: generate_launcher_coin(&origin.coin, amount).name(),
+        recovery_list_hash: recovery_list_hash(recovery_list),
+        num_verifications_required,
+        metadata: metadata.clone(),
+    };
+    let p2_puzzle = get_puzzle_from_pk(public_key.clone());
+    let inner_puzzle = info.inner_puzzle(&p2_puzzle);
+    let (launcher_id, mut spend_bundle) = create_singleton_launch_bundle(
+        coins,
+        &inner_puzzle,
+        &Program::from(Vec::<u8>::new()),
+        amount,
+        fee,
+        change_puzzle_hash,
+    )?;
+    let launcher_spend = spend_bundle
+        .coin_spends
+        .last()
+        .ok_or("launch bundle has no launcher spend")?
+        .clone();
+    let eve_spend = create_eve_spend(
+        &launcher_spend,
+        &inner_puzzle,
+        &recreate_inner_solution(
+            &inner_puzzle.tree_hash(),
+            &p2_puzzle.tree_hash(),
+            amount,
+            Vec::new(),
+        ),
+    )?;
+    spend_bundle.coin_spends.push(eve_spend);
+    Ok((launcher_id, spend_bundle))
+}
+
+/// Spends the DID `coin`, owned by the standard puzzle of `public_key`, recreating it
+/// as `new_info` owned by `new_p2_puzzle_hash`, with `extra_conditions` added.
+pub fn create_did_update_spend(
+    coin: &Coin,
+    info: &DidInfo,
+    public_key: &PublicKey,
+    lineage_proof: &LineageProof,
+    new_info: &DidInfo,
+    new_p2_puzzle_hash: &Bytes32,
+    extra_conditions: Vec<Program>,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    if new_info.launcher_id != info.launcher_id {
+        return Err("a DID cannot change its launcher id".into());
+    }
+    let inner_solution = recreate_inner_solution(
+        &new_info.inner_puzzle_hash(new_p2_puzzle_hash),
+        new_p2_puzzle_hash,
+        coin.amount,
+        extra_conditions,
+    );
+    spend_singleton(
+        coin,
+        &info.launcher_id,
+        &info.inner_puzzle(&get_puzzle_from_pk(public_key.clone())),
+        &inner_solution,
+        lineage_proof,
+    )
+}
+
+/// Hands the DID over to `new_p2_puzzle_hash`, keeping its recovery list and metadata.
+pub fn create_did_transfer_spend(
+    coin: &Coin,
+    info: &DidInfo,
+    public_key: &PublicKey,
+    lineage_proof: &LineageProof,
+    new_p2_puzzle_hash: &Bytes32,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    create_did_update_spend(
+        coin,
+        info,
+        public_key,
+        lineage_proof,
+        info,
+        new_p2_puzzle_hash,
+        Vec::new(),
+    )
+}
+
+pub fn create_did_metadata_update_spend(
+    coin: &Coin,
+    info: &DidInfo,
+    public_key: &PublicKey,
+    lineage_proof: &LineageProof,
+    new_metadata: &Program,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    let new_info = DidInfo {
+        metadata: new_metadata.clone(),
+        ..info.clone()
+    };
+    let p2_puzzle_hash = get_puzzle_from_pk(public_key.clone()).tree_hash();
+    create_did_update_spend(
+        coin,
+        info,
+        public_key,
+        lineage_proof,
+        &new_info,
+        &p2_puzzle_hash,
+        Vec::new(),
+    )
+}
+
+/// Spends the DID unchanged, announcing each NFT launcher id. An NFT's transfer program
+/// requires this announcement when the NFT is assigned to the DID in the same bundle.
+pub fn create_did_nft_assignment_spend(
+    coin: &Coin,
+    info: &DidInfo,
+    public_key: &PublicKey,
+    lineage_proof: &LineageProof,
+    nft_launcher_ids: &[Bytes32],
+) -> Result<CoinSpend, Box<dyn Error>> {
+    let announcements = nft_launcher_ids
+        .iter()
+        .map(|launcher_id| make_create_puzzle_announcement(&launcher_id.bytes))
+        .collect();
+    let p2_puzzle_hash = get_puzzle_from_pk(public_key.clone()).tree_hash();
+    create_did_update_spend(
+        coin,
+        info,
+        public_key,
+        lineage_proof,
+        info,
+        &p2_puzzle_hash,
+        announcements,
+    )
+}
+
+/// Recognises a DID singleton puzzle reveal, returning its info and p2 puzzle.
+pub fn parse_did_puzzle(puzzle: &Program) -> Option<(DidInfo, Program)> {
+    let (launcher_id, inner_puzzle) = match_singleton_puzzle(puzzle)?;
+    let inner_puzzle = inner_puzzle.try_uncurry()?;
+    if inner_puzzle.program.tree_hash() != *DID_INNERPUZ_MOD_HASH || inner_puzzle.args.len() != 5 {
+        return None;
+    }
+    let num_verifications_required = u64::try_from(inner_puzzle.args[2].as_int().ok()?).ok()?;
+    let info = DidInfo {
+        launcher_id,
+        recovery_list_hash: bytes32_atom(&inner_puzzle.args[1])?,
+        num_verifications_required,
+        metadata: inner_puzzle.args[4].clone(),
+    };
+    Some((info, inner_puzzle.args[0].clone()))
+}
+
+#[test]
+fn test_parse_did_puzzle() {
+    let did_innerpuz: Bytes32 =
+        "33143d2bef64f14036742673afd158126b94284b4530a28c354fac202b0c910e".into();
+    assert_eq!(*DID_INNERPUZ_MOD_HASH, did_innerpuz);
+
+    let mut metadata = BTreeMap::new();
+    metadata.insert("name".to_string(), "Alice".to_string());
+    let info = DidInfo {
+        launcher_id: Bytes32::from(vec![0x5a; 32]),
+        recovery_list_hash: recovery_list_hash(&[Bytes32::from(vec![0x11; 32])]),
+        num_verifications_required: 1,
+        metadata: did_metadata_program(&metadata),
+    };
+    let p2_puzzle = Program::from(vec![Program::from(1), Program::from(vec![0x33; 8])]);
+    assert_eq!(
+        info.inner_puzzle_hash(&p2_puzzle.tree_hash()),
+        info.inner_puzzle(&p2_puzzle).tree_hash()
+    );
+    let (parsed, parsed_p2) = parse_did_puzzle(&info.puzzle(&p2_puzzle)).unwrap();
+    assert_eq!(parsed.launcher_id, info.launcher_id);
+    assert_eq!(parsed.recovery_list_hash, info.recovery_list_hash);
+    assert_eq!(parsed.num_verifications_required, 1);
+    assert_eq!(
+        did_metadata_from_program(&parsed.metadata).unwrap(),
+        metadata
+    );
+    assert_eq!(parsed_p2, p2_puzzle);
+
+    // A recovery list hash that is not 32 bytes is not a DID.
+    let mut args = vec![p2_puzzle, Program::from(vec![0x11; 31])];
+    args.extend(info.curried_args().into_iter().skip(1));
+    let short_hash = puzzle_for_singleton(&info.launcher_id, &DID_INNERPUZ_MOD.curry(args));
+    assert!(parse_did_puzzle(&short_hash).is_none());
+}
+
+#[test]
+fn test_did_spends() {
+    use crate::blockchain::condition_opcode::ConditionOpcode;
+    use crate::chia_wallet::singleton::singleton_utils::singleton_puzzle_hash;
+    use crate::program_utils::condition_utils::conditions_for_solution;
+    use crate::program_utils::utils::INFINITE_COST;
+    use chia_bls::secret_key::SecretKey;
+
+    let public_key = SecretKey::from_seed(&[0x44; 64]).public_key();
+    let p2_puzzle = get_puzzle_from_pk(public_key.clone());
+    let mut metadata = BTreeMap::new();
+    metadata.insert("name".to_string(), "Alice".to_string());
+    let info = DidInfo {
+        launcher_id: Bytes32::from(vec![0x5a; 32]),
+        recovery_list_hash: recovery_list_hash(&[]),
+        num_verifications_required: 0,
+        metadata: did_metadata_program(&metadata),
+    };
+    let coin = Coin {
+        parent_coin_info: Bytes32::from(vec![0x66; 32]),
+        puzzle_hash: info.puzzle(&p2_puzzle).tree_hash(),
+        amount: 1,
+    };
+    let lineage_proof = LineageProof {
+        parent_name: Some(Bytes32::from(vec![0x77; 32])),
+        inner_puzzle_hash: Some(Bytes32::from(vec![0x88; 32])),
+        amount: Some(1),
+    };
+    let recreated = |puzzle_hash: Bytes32| {
+        vec![Coin {
+            parent_coin_info: coin.name(),
+            puzzle_hash,
+            amount: 1,
+        }]
+    };
+
+    let new_p2_puzzle_hash = Bytes32::from(vec![0xbb; 32]);
+    let transfer = create_did_transfer_spend(
+        &coin,
+        &info,
+        &public_key,
+        &lineage_proof,
+        &new_p2_puzzle_hash,
+    )
+    .unwrap();
+    assert_eq!(
+        transfer.additions(),
+        recreated(singleton_puzzle_hash(
+            &info.launcher_id,
+            &info.inner_puzzle_hash(&new_p2_puzzle_hash)
+        ))
+    );
+
+    metadata.insert("twitter".to_string(), "@alice".to_string());
+    let new_metadata = did_metadata_program(&metadata);
+    let update =
+        create_did_metadata_update_spend(&coin, &info, &public_key, &lineage_proof, &new_metadata)
+            .unwrap();
+    let new_info = DidInfo {
+        metadata: new_metadata,
+        ..info.clone()
+    };
+    assert_eq!(
+        update.additions(),
+        recreated(new_info.puzzle(&p2_puzzle).tree_hash())
+    );
+
+    let nft_launcher_ids = vec![Bytes32::from(vec![0xc1; 32]), Bytes32::from(vec![0xc2; 32])];
+    let assignment = create_did_nft_assignment_spend(
+        &coin,
+        &info,
+        &public_key,
+        &lineage_proof,
+        &nft_launcher_ids,
+    )
+    .unwrap();
+    assert_eq!(assignment.additions(), recreated(coin.puzzle_hash.clone()));
+    let (conditions, _cost) = conditions_for_solution(
+        &assignment.puzzle_reveal,
+        &assignment.solution,
+        INFINITE_COST,
+    )
+    .unwrap();
+    let announced: Vec<Bytes32> = conditions
+        .iter()
+        .filter(|condition| condition.opcode == ConditionOpcode::CreatePuzzleAnnouncement)
+        .map(|condition| Bytes32::from(condition.vars[0].clone()))
+        .collect();
+    assert_eq!(announced, nft_launcher_ids);
+}
@@ -0,0 +1,2 @@
+pub(crate) mod did_utils;
+pub(crate) mod puzzles;
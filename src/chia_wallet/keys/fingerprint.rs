@@ -0,0 +1,26 @@
+use chia_bls::public_key::PublicKey;
+use sha2::{Digest, Sha256};
+
+/// Chia key fingerprint: the first four bytes of sha256(G1 element), big endian.
+pub fn fingerprint(pk: &PublicKey) -> u32 {
+    let mut hasher = Sha256::new();
+    hasher.update(pk.to_bytes());
+    let hash = hasher.finalize();
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
+
+#[test]
+fn test_fingerprint_vectors() {
+    use chia_bls::secret_key::SecretKey;
+
+    // bls-signatures src/test.cpp, "Chia test vectors 1 (Basic)": KeyGen of 32 bytes of
+    // 0x00 and of 0x01.
+    assert_eq!(
+        fingerprint(&SecretKey::from_seed(&[0u8; 32]).public_key()),
+        0xb40dd58a
+    );
+    assert_eq!(
+        fingerprint(&SecretKey::from_seed(&[1u8; 32]).public_key()),
+        0xb839add1
+    );
+}
@@ -0,0 +1,75 @@
+use chia_bls::derivable_key::DerivableKey;
+use chia_bls::derive_keys::{master_to_farmer, master_to_pool};
+use chia_bls::public_key::PublicKey;
+use chia_bls::secret_key::SecretKey;
+
+use crate::blockchain::network_info::NetworkInfo;
+use crate::chia_wallet::standart::derivation::master_pk_to_wallet_unhardened_intermediate;
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::get_puzzle_from_pk;
+
+use super::fingerprint::fingerprint;
+
+/// The identifiers `chia keys show` prints for a key. Farmer and pool keys are
+/// hardened derivations, so they are only known when the secret key is.
+pub struct KeyInfo {
+    pub fingerprint: u32,
+    pub master_public_key: PublicKey,
+    pub farmer_public_key: Option<PublicKey>,
+    pub pool_public_key: Option<PublicKey>,
+    pub first_wallet_address: String,
+}
+
+impl KeyInfo {
+    pub fn from_secret_key(sk: &SecretKey, network: &NetworkInfo) -> KeyInfo {
+        let mut info = KeyInfo::from_public_key(&sk.public_key(), network);
+        info.farmer_public_key = Some(master_to_farmer(sk).public_key());
+        info.pool_public_key = Some(master_to_pool(sk).public_key());
+        info
+    }
+
+    pub fn from_public_key(pk: &PublicKey, network: &NetworkInfo) -> KeyInfo {
+        let wallet_pk = master_pk_to_wallet_unhardened_intermediate(pk).derive_unhardened(0);
+        let puzzle_hash = get_puzzle_from_pk(wallet_pk).tree_hash();
+        KeyInfo {
+            fingerprint: fingerprint(pk),
+            master_public_key: pk.clone(),
+            farmer_public_key: None,
+            pool_public_key: None,
+            first_wallet_address: network.puzzle_hash_to_address(&puzzle_hash),
+        }
+    }
+}
+
+#[test]
+fn test_key_info_vectors() {
+    use crate::blockchain::network_info::MAINNET_INFO;
+    use crate::chia_wallet::keys::mnemonic::secret_key_from_mnemonic;
+
+    let mnemonic = [vec!["abandon"; 23], vec!["art"]].concat().join(" ");
+    let sk = secret_key_from_mnemonic(&mnemonic, "").unwrap();
+    let info = KeyInfo::from_secret_key(&sk, &MAINNET_INFO);
+    // Computed with the independent Python implementation behind the derivation
+    // vectors in `standart::derivation`, not taken from chia-blockchain.
+    let hex_key = |pk: &PublicKey| hex::encode(pk.to_bytes());
+    assert_eq!(info.fingerprint, 1532878573);
+    assert_eq!(
+        hex_key(&info.master_public_key),
+        "827af93158c0542a234c76fcdfd54766dc39405b259c25f6fc90ca47fb0c73a8f5c745a4489b0a0ed7662044021bac53"
+    );
+    assert_eq!(
+        hex_key(info.farmer_public_key.as_ref().unwrap()),
+        "87243b0f11873c90c3b17d01391de8728c29d0337ae3815e24850a31cb6be56e14d678f9d9a837a02f465bd09c870b25"
+    );
+    assert_eq!(
+        hex_key(info.pool_public_key.as_ref().unwrap()),
+        "924a25596d5ccbe667f9614e3624fefaa42c6fbd685e0f54fdfd866e3f390c2b1bb936307973596345f255ecda79a3ea"
+    );
+    assert_eq!(
+        info.first_wallet_address,
+        "xch16grurcglcwcv6arjarr720yd9wqhp9gkx3k8h25lhwg8pl7vl6ysuax0gy"
+    );
+
+    let observer = KeyInfo::from_public_key(&sk.public_key(), &MAINNET_INFO);
+    assert_eq!(observer.first_wallet_address, info.first_wallet_address);
+    assert!(observer.farmer_public_key.is_none());
+}
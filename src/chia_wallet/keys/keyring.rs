@@ -0,0 +1,361 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use chia_bls::secret_key::SecretKey;
+use hmac::Hmac;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::fingerprint::fingerprint;
+use super::mnemonic::secret_key_from_mnemonic;
+
+pub const KEYRING_VERSION: u32 = 1;
+const KDF_ITERATIONS: u32 = 100_000;
+/// Keyrings are written with `KDF_ITERATIONS`. A file asking for fewer would weaken the
+/// key, and one asking for many more would stall the unlock, so both are rejected.
+const MAX_KDF_ITERATIONS: u32 = 10 * KDF_ITERATIONS;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Debug)]
+pub enum KeyringError {
+    Io(std::io::Error),
+    Format(String),
+    UnsupportedVersion(u32),
+    AlreadyExists(PathBuf),
+    BadPassphrase,
+    InvalidKey(String),
+    KeyNotFound(u32),
+    DuplicateKey(u32),
+    NoMnemonic(u32),
+}
+
+impl fmt::Display for KeyringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyringError::Io(e) => write!(f, "keyring io error: {}", e),
+            KeyringError::Format(reason) => write!(f, "malformed keyring: {}", reason),
+            KeyringError::UnsupportedVersion(version) => {
+                write!(f, "unsupported keyring version {}", version)
+            }
+            KeyringError::AlreadyExists(path) => {
+                write!(f, "keyring already exists at {}", path.display())
+            }
+            KeyringError::BadPassphrase => write!(f, "incorrect keyring passphrase"),
+            KeyringError::InvalidKey(reason) => write!(f, "invalid key: {}", reason),
+            KeyringError::KeyNotFound(fp) => write!(f, "no key with fingerprint {}", fp),
+            KeyringError::DuplicateKey(fp) => {
+                write!(f, "key with fingerprint {} is already stored", fp)
+            }
+            KeyringError::NoMnemonic(fp) => {
+                write!(
+                    f,
+                    "key with fingerprint {} was stored without a mnemonic",
+                    fp
+                )
+            }
+        }
+    }
+}
+
+impl Error for KeyringError {}
+
+impl From<std::io::Error> for KeyringError {
+    fn from(e: std::io::Error) -> Self {
+        KeyringError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for KeyringError {
+    fn from(e: serde_json::Error) -> Self {
+        KeyringError::Format(e.to_string())
+    }
+}
+
+/// The on-disk file. Only `data` is encrypted; the hint is readable without the passphrase.
+#[derive(Serialize, Deserialize, Debug)]
+struct KeyringFile {
+    version: u32,
+    kdf_iterations: u32,
+    salt: String,
+    nonce: String,
+    data: String,
+    passphrase_hint: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct KeyringEntry {
+    pub fingerprint: u32,
+    pub label: Option<String>,
+    pub mnemonic: Option<String>,
+    pub secret_key: String,
+}
+
+impl KeyringEntry {
+    pub fn secret_key(&self) -> Result<SecretKey, KeyringError> {
+        secret_key_from_hex(&self.secret_key)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct KeyringContents {
+    keys: Vec<KeyringEntry>,
+}
+
+pub struct Keyring {
+    path: PathBuf,
+    passphrase: String,
+    passphrase_hint: Option<String>,
+    contents: KeyringContents,
+}
+
+impl Keyring {
+    /// Creates an empty keyring at `path`, refusing to overwrite an existing file.
+    pub fn create(
+        path: &Path,
+        passphrase: &str,
+        passphrase_hint: Option<String>,
+    ) -> Result<Keyring, KeyringError> {
+        if path.exists() {
+            return Err(KeyringError::AlreadyExists(path.to_path_buf()));
+        }
+        let keyring = Keyring {
+            path: path.to_path_buf(),
+            passphrase: passphrase.to_string(),
+            passphrase_hint,
+            contents: KeyringContents::default(),
+        };
+        keyring.save()?;
+        Ok(keyring)
+    }
+
+    pub fn open(path: &Path, passphrase: &str) -> Result<Keyring, KeyringError> {
+        let file = read_keyring_file(path)?;
+        let salt = hex_field(&file.salt, "salt")?;
+        let nonce = hex_field(&file.nonce, "nonce")?;
+        let data = hex_field(&file.data, "data")?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(KeyringError::Format("nonce must be 12 bytes".to_string()));
+        }
+        if !(KDF_ITERATIONS..=MAX_KDF_ITERATIONS).contains(&file.kdf_iterations) {
+            return Err(KeyringError::Format(format!(
+                "{} KDF iterations is outside {}..={}",
+                file.kdf_iterations, KDF_ITERATIONS, MAX_KDF_ITERATIONS
+            )));
+        }
+        let cipher = cipher_for(passphrase, &salt, file.kdf_iterations);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), data.as_ref())
+            .map_err(|_| KeyringError::BadPassphrase)?;
+        let contents: KeyringContents = serde_json::from_slice(&plaintext)?;
+        Ok(Keyring {
+            path: path.to_path_buf(),
+            passphrase: passphrase.to_string(),
+            passphrase_hint: file.passphrase_hint,
+            contents,
+        })
+    }
+
+    /// Reads the passphrase hint without decrypting the keyring.
+    pub fn passphrase_hint(path: &Path) -> Result<Option<String>, KeyringError> {
+        Ok(read_keyring_file(path)?.passphrase_hint)
+    }
+
+    pub fn keys(&self) -> &[KeyringEntry] {
+        &self.contents.keys
+    }
+
+    pub fn get(&self, fp: u32) -> Result<&KeyringEntry, KeyringError> {
+        self.contents
+            .keys
+            .iter()
+            .find(|entry| entry.fingerprint == fp)
+            .ok_or(KeyringError::KeyNotFound(fp))
+    }
+
+    fn get_mut(&mut self, fp: u32) -> Result<&mut KeyringEntry, KeyringError> {
+        self.contents
+            .keys
+            .iter_mut()
+            .find(|entry| entry.fingerprint == fp)
+            .ok_or(KeyringError::KeyNotFound(fp))
+    }
+
+    pub fn add_mnemonic(
+        &mut self,
+        mnemonic: &str,
+        label: Option<String>,
+    ) -> Result<u32, KeyringError> {
+        let sk = secret_key_from_mnemonic(mnemonic, "")
+            .map_err(|e| KeyringError::InvalidKey(e.to_string()))?;
+        self.add_entry(&sk, Some(mnemonic.to_string()), label)
+    }
+
+    pub fn add_secret_key(
+        &mut self,
+        sk: &SecretKey,
+        label: Option<String>,
+    ) -> Result<u32, KeyringError> {
+        self.add_entry(sk, None, label)
+    }
+
+    fn add_entry(
+        &mut self,
+        sk: &SecretKey,
+        mnemonic: Option<String>,
+        label: Option<String>,
+    ) -> Result<u32, KeyringError> {
+        let fp = fingerprint(&sk.public_key());
+        if self.get(fp).is_ok() {
+            return Err(KeyringError::DuplicateKey(fp));
+        }
+        self.contents.keys.push(KeyringEntry {
+            fingerprint: fp,
+            label,
+            mnemonic,
+            secret_key: hex::encode(sk.to_bytes()),
+        });
+        self.save()?;
+        Ok(fp)
+    }
+
+    pub fn set_label(&mut self, fp: u32, label: Option<String>) -> Result<(), KeyringError> {
+        self.get_mut(fp)?.label = label;
+        self.save()
+    }
+
+    pub fn remove(&mut self, fp: u32) -> Result<(), KeyringError> {
+        self.get(fp)?;
+        self.contents.keys.retain(|entry| entry.fingerprint != fp);
+        self.save()
+    }
+
+    /// Returns the mnemonic a key was imported with, for backing it up.
+    pub fn export_mnemonic(&self, fp: u32) -> Result<String, KeyringError> {
+        self.get(fp)?
+            .mnemonic
+            .clone()
+            .ok_or(KeyringError::NoMnemonic(fp))
+    }
+
+    /// Re-encrypts the keyring under `new_passphrase` with a fresh salt and nonce.
+    pub fn change_passphrase(
+        &mut self,
+        current_passphrase: &str,
+        new_passphrase: &str,
+        passphrase_hint: Option<String>,
+    ) -> Result<(), KeyringError> {
+        if current_passphrase != self.passphrase {
+            return Err(KeyringError::BadPassphrase);
+        }
+        self.passphrase = new_passphrase.to_string();
+        self.passphrase_hint = passphrase_hint;
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), KeyringError> {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+        let plaintext = serde_json::to_vec(&self.contents)?;
+        let cipher = cipher_for(&self.passphrase, &salt, KDF_ITERATIONS);
+        let data = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_ref())
+            .map_err(|_| KeyringError::Format("encryption failed".to_string()))?;
+        let file = KeyringFile {
+            version: KEYRING_VERSION,
+            kdf_iterations: KDF_ITERATIONS,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            data: hex::encode(data),
+            passphrase_hint: self.passphrase_hint.clone(),
+        };
+        // Write next to the keyring and rename so a crash never leaves a truncated file.
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec_pretty(&file)?)?;
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+fn read_keyring_file(path: &Path) -> Result<KeyringFile, KeyringError> {
+    let file: KeyringFile = serde_json::from_slice(&fs::read(path)?)?;
+    if file.version != KEYRING_VERSION {
+        return Err(KeyringError::UnsupportedVersion(file.version));
+    }
+    Ok(file)
+}
+
+fn hex_field(value: &str, name: &str) -> Result<Vec<u8>, KeyringError> {
+    hex::decode(value).map_err(|e| KeyringError::Format(format!("{}: {}", name, e)))
+}
+
+fn cipher_for(passphrase: &str, salt: &[u8], iterations: u32) -> ChaCha20Poly1305 {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase.as_bytes(), salt, iterations, &mut key);
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+fn secret_key_from_hex(sk_hex: &str) -> Result<SecretKey, KeyringError> {
+    let bytes = hex_field(sk_hex, "secret key")?;
+    let sk_array: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| KeyringError::InvalidKey("secret key must be 32 bytes".to_string()))?;
+    SecretKey::from_bytes(&sk_array).map_err(|e| KeyringError::InvalidKey(format!("{:?}", e)))
+}
+
+#[test]
+fn test_keyring_round_trip() {
+    let path = std::env::temp_dir().join(format!("keyring-test-{}.json", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let sk = SecretKey::from_seed(&[7u8; 64]);
+    let mut keyring = Keyring::create(&path, "correct horse", Some("horse".to_string())).unwrap();
+    let fp = keyring
+        .add_secret_key(&sk, Some("savings".to_string()))
+        .unwrap();
+    assert!(matches!(
+        keyring.add_secret_key(&sk, None),
+        Err(KeyringError::DuplicateKey(_))
+    ));
+
+    let reopened = Keyring::open(&path, "correct horse").unwrap();
+    let entry = reopened.get(fp).unwrap();
+    assert_eq!(entry.label.as_deref(), Some("savings"));
+    assert_eq!(entry.secret_key().unwrap().to_bytes(), sk.to_bytes());
+    assert!(matches!(
+        Keyring::open(&path, "wrong horse"),
+        Err(KeyringError::BadPassphrase)
+    ));
+
+    keyring
+        .change_passphrase("correct horse", "battery staple", None)
+        .unwrap();
+    assert!(Keyring::open(&path, "battery staple").is_ok());
+    assert_eq!(Keyring::passphrase_hint(&path).unwrap(), None);
+
+    // A tampered iteration count is rejected before any key is derived.
+    let original: KeyringFile = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+    for kdf_iterations in [0, KDF_ITERATIONS - 1, u32::MAX] {
+        let tampered = KeyringFile {
+            kdf_iterations,
+            salt: original.salt.clone(),
+            nonce: original.nonce.clone(),
+            data: original.data.clone(),
+            passphrase_hint: None,
+            ..original
+        };
+        fs::write(&path, serde_json::to_vec(&tampered).unwrap()).unwrap();
+        assert!(matches!(
+            Keyring::open(&path, "battery staple"),
+            Err(KeyringError::Format(_))
+        ));
+    }
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,151 @@
+use std::error::Error;
+use std::fmt;
+
+use bip39::{ErrorKind, Language, Mnemonic, MnemonicType, Seed};
+use chia_bls::secret_key::SecretKey;
+
+pub const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MnemonicError {
+    InvalidWordCount(usize),
+    InvalidWord { word: String, index: usize },
+    InvalidChecksum,
+    InvalidEntropyLength(usize),
+}
+
+impl fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MnemonicError::InvalidWordCount(count) => write!(
+                f,
+                "mnemonic must have 12, 15, 18, 21 or 24 words, got {}",
+                count
+            ),
+            MnemonicError::InvalidWord { word, index } => {
+                write!(f, "{:?} (word {}) is not a BIP39 word", word, index + 1)
+            }
+            MnemonicError::InvalidChecksum => write!(f, "mnemonic checksum does not match"),
+            MnemonicError::InvalidEntropyLength(len) => {
+                write!(f, "entropy must be 16, 20, 24, 28 or 32 bytes, got {}", len)
+            }
+        }
+    }
+}
+
+impl Error for MnemonicError {}
+
+fn normalize_phrase(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Generates a new English mnemonic of `word_count` words from OS randomness.
+pub fn generate_mnemonic(word_count: usize) -> Result<String, MnemonicError> {
+    let mnemonic_type = MnemonicType::for_word_count(word_count)
+        .map_err(|_| MnemonicError::InvalidWordCount(word_count))?;
+    Ok(Mnemonic::new(mnemonic_type, Language::English).into_phrase())
+}
+
+/// Parses a phrase, reporting the first unknown word (by zero-based index)
+/// before the word count or checksum.
+pub fn parse_mnemonic(phrase: &str) -> Result<Mnemonic, MnemonicError> {
+    let phrase = normalize_phrase(phrase);
+    if phrase.is_empty() {
+        return Err(MnemonicError::InvalidWordCount(0));
+    }
+    let wordmap = Language::English.wordmap();
+    for (index, word) in phrase.split(' ').enumerate() {
+        if wordmap.get_bits(word).is_err() {
+            return Err(MnemonicError::InvalidWord {
+                word: word.to_string(),
+                index,
+            });
+        }
+    }
+    let word_count = phrase.split(' ').count();
+    if !VALID_WORD_COUNTS.contains(&word_count) {
+        return Err(MnemonicError::InvalidWordCount(word_count));
+    }
+    Mnemonic::from_phrase(&phrase, Language::English).map_err(|e| {
+        match e.downcast_ref::<ErrorKind>() {
+            Some(ErrorKind::InvalidWordLength(count)) => MnemonicError::InvalidWordCount(*count),
+            _ => MnemonicError::InvalidChecksum,
+        }
+    })
+}
+
+pub fn entropy_to_mnemonic(entropy: &[u8]) -> Result<String, MnemonicError> {
+    let mnemonic = Mnemonic::from_entropy(entropy, Language::English)
+        .map_err(|_| MnemonicError::InvalidEntropyLength(entropy.len()))?;
+    Ok(mnemonic.into_phrase())
+}
+
+pub fn mnemonic_to_entropy(phrase: &str) -> Result<Vec<u8>, MnemonicError> {
+    Ok(parse_mnemonic(phrase)?.entropy().to_vec())
+}
+
+/// BIP39 seed for `phrase`, salted with the optional `passphrase`.
+pub fn mnemonic_to_seed(phrase: &str, passphrase: &str) -> Result<Vec<u8>, MnemonicError> {
+    let mnemonic = parse_mnemonic(phrase)?;
+    Ok(Seed::new(&mnemonic, passphrase).as_bytes().to_vec())
+}
+
+pub fn entropy_to_seed(entropy: &[u8], passphrase: &str) -> Result<Vec<u8>, MnemonicError> {
+    let mnemonic = Mnemonic::from_entropy(entropy, Language::English)
+        .map_err(|_| MnemonicError::InvalidEntropyLength(entropy.len()))?;
+    Ok(Seed::new(&mnemonic, passphrase).as_bytes().to_vec())
+}
+
+pub fn secret_key_from_mnemonic(
+    phrase: &str,
+    passphrase: &str,
+) -> Result<SecretKey, MnemonicError> {
+    let seed = mnemonic_to_seed(phrase, passphrase)?;
+    Ok(SecretKey::from_seed(&seed))
+}
+
+#[test]
+fn test_bip39_vectors() {
+    // Trezor BIP39 vector, passphrase "TREZOR".
+    let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    assert_eq!(
+        hex::encode(mnemonic_to_entropy(phrase).unwrap()),
+        "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f"
+    );
+    assert_eq!(
+        hex::encode(mnemonic_to_seed(phrase, "TREZOR").unwrap()),
+        "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607"
+    );
+    assert_eq!(
+        entropy_to_mnemonic(&[0x7f; 16]).unwrap(),
+        phrase.to_string()
+    );
+}
+
+#[test]
+fn test_invalid_mnemonics() {
+    let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    assert_eq!(
+        parse_mnemonic(&phrase.replace("sausage", "sausages")).unwrap_err(),
+        MnemonicError::InvalidWord {
+            word: "sausages".to_string(),
+            index: 5
+        }
+    );
+    assert_eq!(
+        parse_mnemonic(&phrase.replace("yellow", "winner")).unwrap_err(),
+        MnemonicError::InvalidChecksum
+    );
+    assert_eq!(
+        parse_mnemonic("legal winner thank").unwrap_err(),
+        MnemonicError::InvalidWordCount(3)
+    );
+    for word_count in VALID_WORD_COUNTS {
+        let generated = generate_mnemonic(word_count).unwrap();
+        assert!(parse_mnemonic(&generated).is_ok());
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod fingerprint;
+pub(crate) mod key_info;
+pub(crate) mod keyring;
+pub(crate) mod mnemonic;
\ No newline at end of file
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::chia_wallet::puzzle_utils::int_program;
+use crate::program_utils::program::Program;
+
+/// Proves a coin's parent was the same kind of CAT or singleton. Fields are left
+/// out when the parent did not have them, as for eve spends.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub struct LineageProof {
+    pub parent_name: Option<Bytes32>,
+    pub inner_puzzle_hash: Option<Bytes32>,
+    pub amount: Option<u64>,
+}
+
+impl LineageProof {
+    pub fn to_program(&self) -> Program {
+        let mut items = Vec::new();
+        if let Some(parent_name) = &self.parent_name {
+            items.push(Program::from(parent_name.bytes.clone()));
+        }
+        if let Some(inner_puzzle_hash) = &self.inner_puzzle_hash {
+            items.push(Program::from(inner_puzzle_hash.bytes.clone()));
+        }
+        if let Some(amount) = self.amount {
+            items.push(int_program(amount));
+        }
+        Program::from(items)
+    }
+}
@@ -0,0 +1,62 @@
+use chia_bls::public_key::PublicKey;
+use chia_bls::secret_key::SecretKey;
+use chia_bls::signature::{sign, verify, Signature};
+
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    calculate_synthetic_secret_key, default_hidden_puzzle, get_puzzle_from_synthetic_pk,
+};
+use crate::program_utils::program::Program;
+
+/// CHIP-0002 signs the tree hash of `(CHIP_0002_SIGN_MESSAGE_PREFIX . message)`, so a
+/// signed message can never pass for a spend's AGG_SIG message.
+pub const CHIP_0002_SIGN_MESSAGE_PREFIX: &str = "Chia Signed Message";
+
+pub fn chip_0002_message_hash(message: &[u8]) -> Bytes32 {
+    Program::from(CHIP_0002_SIGN_MESSAGE_PREFIX.as_bytes().to_vec())
+        .cons(&Program::from(message.to_vec()))
+        .tree_hash()
+}
+
+/// Signs `message` with the synthetic key of `secret_key`, the key of the wallet's
+/// standard puzzle, as wallets do when a dApp asks them to sign for an address. Returns
+/// the synthetic public key, which verifiers need, and the signature.
+pub fn sign_message(secret_key: &SecretKey, message: &[u8]) -> (PublicKey, Signature) {
+    let synthetic_sk =
+        calculate_synthetic_secret_key(secret_key, &default_hidden_puzzle().tree_hash());
+    let signature = sign(&synthetic_sk, &chip_0002_message_hash(message).bytes);
+    (synthetic_sk.public_key(), signature)
+}
+
+pub fn verify_message(public_key: &PublicKey, message: &[u8], signature: &Signature) -> bool {
+    verify(
+        signature,
+        public_key,
+        &chip_0002_message_hash(message).bytes,
+    )
+}
+
+/// Whether `synthetic_pk` is the key of the standard puzzle behind `puzzle_hash`.
+pub fn synthetic_pk_matches_puzzle_hash(synthetic_pk: &PublicKey, puzzle_hash: &Bytes32) -> bool {
+    get_puzzle_from_synthetic_pk(synthetic_pk).tree_hash() == *puzzle_hash
+}
+
+#[test]
+fn test_sign_and_verify_message() {
+    use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::get_puzzle_from_pk;
+
+    let sk = SecretKey::from_seed(&[7u8; 64]);
+    let puzzle_hash = get_puzzle_from_pk(sk.public_key()).tree_hash();
+    let message = b"Hello, Chia!";
+    let (synthetic_pk, signature) = sign_message(&sk, message);
+    assert!(verify_message(&synthetic_pk, message, &signature));
+    assert!(!verify_message(&synthetic_pk, b"Hello, Chia?", &signature));
+    assert!(synthetic_pk_matches_puzzle_hash(
+        &synthetic_pk,
+        &puzzle_hash
+    ));
+    assert!(!synthetic_pk_matches_puzzle_hash(
+        &sk.public_key(),
+        &puzzle_hash
+    ));
+}
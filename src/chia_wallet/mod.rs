@@ -1,2 +1,20 @@
 pub(crate) mod standart;
-pub(crate) mod cat;
\ No newline at end of file
+pub(crate) mod cat;
+pub(crate) mod clawback;
+pub(crate) mod coin_selection;
+pub(crate) mod did;
+pub(crate) mod keys;
+pub(crate) mod lineage_proof;
+pub(crate) mod message_signing;
+pub(crate) mod multisig;
+pub(crate) mod nft;
+pub(crate) mod notification;
+pub(crate) mod offer;
+pub(crate) mod pool;
+pub(crate) mod puzzle_compression;
+pub(crate) mod puzzle_drivers;
+pub(crate) mod puzzle_utils;
+pub(crate) mod sign_coin_spends;
+pub(crate) mod singleton;
+#[cfg(test)]
+pub(crate) mod test_utils;
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub(crate) mod multisig_utils;
+pub(crate) mod puzzles;
@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use chia_bls::secret_key::SecretKey;
+use chia_bls::signature::{aggregate, sign, Signature};
+use serde::{Deserialize, Serialize};
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::constants::Constants;
+use crate::blockchain::sized_bytes::{Bytes32, Bytes48, Bytes96};
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::puzzle_utils::int_program;
+use crate::chia_wallet::standart::puzzles::p2_conditions::puzzleForConditions;
+use crate::chia_wallet::standart::transaction::{
+    empty_signature, spend_conditions_for_coins, Payment,
+};
+use crate::program_utils::bls_bindings::aggregate_verify_signature;
+use crate::program_utils::condition_utils::pkm_pairs;
+use crate::program_utils::program::Program;
+use crate::program_utils::serialized_program::SerializedProgram;
+
+use super::puzzles::p2_m_of_n_delegate_direct::P2_M_OF_N_DELEGATE_DIRECT_MOD;
+
+/// An m-of-n multisig: any `threshold` of `public_keys` spend its coins together. The
+/// order of the keys is part of the puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigInfo {
+    pub threshold: u32,
+    pub public_keys: Vec<Bytes48>,
+}
+
+impl MultisigInfo {
+    pub fn new(threshold: u32, public_keys: Vec<Bytes48>) -> Result<MultisigInfo, Box<dyn Error>> {
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(format!(
+                "threshold {} is not between 1 and the {} public keys",
+                threshold,
+                public_keys.len()
+            )
+            .into());
+        }
+        let mut seen = HashSet::new();
+        for public_key in &public_keys {
+            if !seen.insert(public_key.clone()) {
+                return Err(format!("public key {} is listed twice", public_key).into());
+            }
+        }
+        Ok(MultisigInfo {
+            threshold,
+            public_keys,
+        })
+    }
+
+    pub fn puzzle(&self) -> Program {
+        let public_keys: Vec<Program> = self
+            .public_keys
+            .iter()
+            .map(|public_key| Program::from(public_key.bytes.clone()))
+            .collect();
+        P2_M_OF_N_DELEGATE_DIRECT_MOD.curry(vec![
+            int_program(self.threshold as u64),
+            Program::from(public_keys),
+        ])
+    }
+
+    pub fn puzzle_hash(&self) -> Bytes32 {
+        self.puzzle().tree_hash()
+    }
+
+    /// One flag per public key, set for the `signers`. Exactly `threshold` of the keys
+    /// have to be chosen.
+    pub fn selectors(&self, signers: &[Bytes48]) -> Result<Vec<bool>, Box<dyn Error>> {
+        for signer in signers {
+            if !self.public_keys.contains(signer) {
+                return Err(format!("public key {} is not part of the multisig", signer).into());
+            }
+        }
+        let selectors: Vec<bool> = self
+            .public_keys
+            .iter()
+            .map(|public_key| signers.contains(public_key))
+            .collect();
+        let chosen = selectors.iter().filter(|selected| **selected).count();
+        if chosen != self.threshold as usize {
+            return Err(format!(
+                "{} distinct signers chosen, the multisig needs {}",
+                chosen, self.threshold
+            )
+            .into());
+        }
+        Ok(selectors)
+    }
+
+    /// Runs `delegated_puzzle` with `delegated_solution` once the `signers` sign its hash.
+    pub fn solution(
+        &self,
+        signers: &[Bytes48],
+        delegated_puzzle: &Program,
+        delegated_solution: &Program,
+    ) -> Result<Program, Box<dyn Error>> {
+        let selectors: Vec<Program> = self
+            .selectors(signers)?
+            .into_iter()
+            .map(|selected| int_program(selected as u64))
+            .collect();
+        Ok(Program::from(vec![
+            Program::from(selectors),
+            delegated_puzzle.clone(),
+            delegated_solution.clone(),
+        ]))
+    }
+}
+
+/// Builds the unsigned bundle paying `payments` and `fee` from the multisig `coins` and
+/// returning the rest to `change_puzzle_hash`. Every spend needs a signature from each
+/// of the `signers`; see `sign_multisig_spends`.
+pub fn create_multisig_spend_bundle(
+    info: &MultisigInfo,
+    coins: &[Coin],
+    signers: &[Bytes48],
+    payments: &[Payment],
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let puzzle = info.puzzle();
+    let puzzle_hash = puzzle.tree_hash();
+    for coin in coins {
+        if coin.puzzle_hash != puzzle_hash {
+            return Err(format!("coin {} is not locked by this multisig", coin.name()).into());
+        }
+    }
+    let conditions =
+        spend_conditions_for_coins(coins, payments, fee, change_puzzle_hash, Vec::new())?;
+    let mut coin_spends = Vec::new();
+    for (coin, conditions) in coins.iter().zip(conditions) {
+        let solution =
+            info.solution(signers, &puzzleForConditions(conditions), &Program::null())?;
+        coin_spends.push(CoinSpend {
+            coin: coin.clone(),
+            puzzle_reveal: SerializedProgram::from_bytes(&puzzle.serialized),
+            solution: SerializedProgram::from_bytes(&solution.serialized),
+        });
+    }
+    Ok(SpendBundle {
+        coin_spends,
+        aggregated_signature: empty_signature(),
+    })
+}
+
+/// One signer's share of the signature for multisig `coin_spends`: the aggregate of the
+/// messages `secret_key` has to sign. Signers sign on their own devices and the shares
+/// are added to the bundle with `add_partial_signatures`.
+pub fn sign_multisig_spends(
+    coin_spends: &[CoinSpend],
+    secret_key: &SecretKey,
+    constants: &Constants,
+    max_cost: u64,
+) -> Result<Bytes96, Box<dyn Error>> {
+    let public_key = Bytes48::from(secret_key.public_key().to_bytes().to_vec());
+    let signatures: Vec<Signature> =
+        pkm_pairs(coin_spends, &constants.genesis_challenge.bytes, max_cost)?
+            .into_iter()
+            .filter(|(pk, _)| *pk == public_key)
+            .map(|(_, msg)| sign(secret_key, &msg))
+            .collect();
+    if signatures.is_empty() {
+        return Err(format!(
+            "public key {} is not asked to sign these spends",
+            public_key
+        )
+        .into());
+    }
+    Ok(Bytes96::from(aggregate(&signatures).to_bytes().to_vec()))
+}
+
+/// Adds the signers' `partial_signatures` to `spend_bundle`. Fails unless, with the
+/// signature the bundle already has, they cover every AGG_SIG condition of its spends.
+pub fn add_partial_signatures(
+    spend_bundle: &SpendBundle,
+    partial_signatures: &[Bytes96],
+    constants: &Constants,
+    max_cost: u64,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let mut signatures = Vec::new();
+    for signature in std::iter::once(&spend_bundle.aggregated_signature).chain(partial_signatures) {
+        let mut bytes = [0u8; 96];
+        bytes.copy_from_slice(&signature.bytes);
+        signatures.push(
+            Signature::from_bytes(&bytes).map_err(|e| format!("invalid signature: {:?}", e))?,
+        );
+    }
+    let aggregated_signature = Bytes96::from(aggregate(&signatures).to_bytes().to_vec());
+    let pairs = pkm_pairs(
+        &spend_bundle.coin_spends,
+        &constants.genesis_challenge.bytes,
+        max_cost,
+    )?;
+    let public_keys: Vec<&Bytes48> = pairs.iter().map(|(pk, _)| pk).collect();
+    let messages: Vec<&[u8]> = pairs.iter().map(|(_, msg)| msg.as_slice()).collect();
+    if !aggregate_verify_signature(&public_keys, &messages, &aggregated_signature) {
+        return Err("the signatures do not cover every signer of the spends".into());
+    }
+    Ok(SpendBundle {
+        coin_spends: spend_bundle.coin_spends.clone(),
+        aggregated_signature,
+    })
+}
+
+#[test]
+fn test_multisig_spend() {
+    use crate::blockchain::constants::MAINNET;
+    use crate::program_utils::utils::INFINITE_COST;
+
+    let secret_keys: Vec<SecretKey> = (1u8..=3)
+        .map(|seed| SecretKey::from_seed(&[seed; 64]))
+        .collect();
+    let public_keys: Vec<Bytes48> = secret_keys
+        .iter()
+        .map(|sk| Bytes48::from(sk.public_key().to_bytes().to_vec()))
+        .collect();
+    assert!(MultisigInfo::new(4, public_keys.clone()).is_err());
+    let info = MultisigInfo::new(2, public_keys.clone()).unwrap();
+    assert!(info.selectors(&public_keys[..1]).is_err());
+    assert_eq!(
+        info.selectors(&[public_keys[2].clone(), public_keys[0].clone()])
+            .unwrap(),
+        vec![true, false, true]
+    );
+
+    let coin = Coin {
+        parent_coin_info: Bytes32::from(vec![0x12; 32]),
+        puzzle_hash: info.puzzle_hash(),
+        amount: 1000,
+    };
+    let destination = Bytes32::from(vec![0xaa; 32]);
+    let payments = vec![Payment {
+        puzzle_hash: destination.clone(),
+        amount: 600,
+        memos: Vec::new(),
+    }];
+    let signers = [public_keys[0].clone(), public_keys[2].clone()];
+    let unsigned = create_multisig_spend_bundle(
+        &info,
+        &[coin.clone()],
+        &signers,
+        &payments,
+        0,
+        &coin.puzzle_hash,
+    )
+    .unwrap();
+    let additions = unsigned.coin_spends[0].additions();
+    assert_eq!(additions.len(), 2);
+    assert_eq!(additions[0].puzzle_hash, destination);
+    assert_eq!(additions[1].amount, 400);
+
+    assert!(sign_multisig_spends(
+        &unsigned.coin_spends,
+        &secret_keys[1],
+        &MAINNET,
+        INFINITE_COST
+    )
+    .is_err());
+    let partials: Vec<Bytes96> = [&secret_keys[0], &secret_keys[2]]
+        .iter()
+        .map(|sk| sign_multisig_spends(&unsigned.coin_spends, sk, &MAINNET, INFINITE_COST).unwrap())
+        .collect();
+    assert!(add_partial_signatures(&unsigned, &partials[..1], &MAINNET, INFINITE_COST).is_err());
+    let signed = add_partial_signatures(&unsigned, &partials, &MAINNET, INFINITE_COST).unwrap();
+    assert_ne!(signed.aggregated_signature, empty_signature());
+}
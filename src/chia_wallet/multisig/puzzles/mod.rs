@@ -0,0 +1 @@
+pub(crate) mod p2_m_of_n_delegate_direct;
@@ -0,0 +1,17 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Curried with `M` and a list of public keys. Its solution is `(selectors
+/// delegated_puzzle delegated_solution)`: `selectors` flags, key by key, the `M` keys
+/// that sign the delegated puzzle hash with AGG_SIG_ME.
+pub static P2_M_OF_N_DELEGATE_DIRECT_STR: &str = "ff02ffff01ff02ff0affff04ff02ffff04ffff02ff0cffff04ff02ffff04ff05ffff04ff0bffff04ff17ffff04ffff02ff0effff04ff02ffff04ff2fff80808080ff80808080808080ffff04ffff02ff2fff5f80ff8080808080ffff04ffff01ffff32ff02ffff03ff17ffff01ff02ffff03ff27ffff01ff04ffff04ff08ffff04ff13ffff04ff2fff80808080ffff02ff0cffff04ff02ffff04ffff11ff05ffff010180ffff04ff1bffff04ff37ffff04ff2fff8080808080808080ffff01ff02ff0cffff04ff02ffff04ff05ffff04ff1bffff04ff37ffff04ff2fff8080808080808080ff0180ffff01ff02ffff03ff05ffff01ff0880ff8080ff018080ff0180ffff02ffff03ff05ffff01ff04ff09ffff02ff0affff04ff02ffff04ff0dffff04ff0bff808080808080ffff010b80ff0180ff02ffff03ffff07ff0580ffff01ff0bffff0102ffff02ff0effff04ff02ffff04ff09ff80808080ffff02ff0effff04ff02ffff04ff0dff8080808080ffff01ff0bffff0101ff058080ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref P2_M_OF_N_DELEGATE_DIRECT_MOD: Program = {
+        SerializedProgram::from_hex(P2_M_OF_N_DELEGATE_DIRECT_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref P2_M_OF_N_DELEGATE_DIRECT_MOD_HASH: Bytes32 =
+        P2_M_OF_N_DELEGATE_DIRECT_MOD.tree_hash();
+}
@@ -0,0 +1,2 @@
+pub(crate) mod nft_utils;
+pub(crate) mod puzzles;
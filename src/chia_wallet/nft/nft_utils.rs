@@ -0,0 +1,416 @@
+use std::error::Error;
+
+use chia_bls::public_key::PublicKey;
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::puzzle_utils::{
+    bytes32_atom, int_program, make_create_coin_condition, signed_int_program,
+};
+use crate::chia_wallet::singleton::singleton_utils::{
+    create_eve_spend, create_singleton_launch_bundle, generate_launcher_coin,
+    match_singleton_puzzle, puzzle_for_singleton, singleton_struct, spend_singleton,
+};
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    get_puzzle_from_pk, solutionForConditions,
+};
+use crate::chia_wallet::standart::transaction::SpendableCoin;
+use crate::program_utils::program::Program;
+
+use super::puzzles::nft_metadata_updater_default::NFT_METADATA_UPDATER_MOD_HASH;
+use super::puzzles::nft_ownership_layer::{NFT_OWNERSHIP_LAYER_MOD, NFT_OWNERSHIP_LAYER_MOD_HASH};
+use super::puzzles::nft_ownership_transfer_program_one_way_claim_with_royalties::{
+    NFT_TRANSFER_PROGRAM_MOD, NFT_TRANSFER_PROGRAM_MOD_HASH,
+};
+use super::puzzles::nft_state_layer::{NFT_STATE_LAYER_MOD, NFT_STATE_LAYER_MOD_HASH};
+
+/// Magic condition handled by the ownership layer: `(-10 did_id trade_prices did_inner_puzhash)`.
+pub const NEW_OWNER_CONDITION: i64 = -10;
+
+/// NFT1 metadata as stored on chain in the state layer. Hashes are optional only for
+/// the metadata and license files.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct NftMetadata {
+    pub data_uris: Vec<String>,
+    pub data_hash: Bytes32,
+    #[serde(default)]
+    pub metadata_uris: Vec<String>,
+    #[serde(default)]
+    pub metadata_hash: Option<Bytes32>,
+    #[serde(default)]
+    pub license_uris: Vec<String>,
+    #[serde(default)]
+    pub license_hash: Option<Bytes32>,
+    pub edition_number: u64,
+    pub edition_total: u64,
+}
+
+fn uri_list(uris: &[String]) -> Program {
+    Program::from(
+        uris.iter()
+            .map(|uri| Program::from(uri.as_bytes().to_vec()))
+            .collect::<Vec<Program>>(),
+    )
+}
+
+fn uris_from_program(program: &Program) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut uris = Vec::new();
+    for uri in program.iter() {
+        let bytes = uri.as_vec().ok_or("NFT URI is not an atom")?;
+        uris.push(String::from_utf8(bytes)?);
+    }
+    Ok(uris)
+}
+
+fn hash_from_program(program: &Program) -> Result<Bytes32, Box<dyn Error>> {
+    match program.as_vec() {
+        Some(bytes) if bytes.len() == 32 => Ok(Bytes32::from(bytes)),
+        _ => Err("NFT metadata hash is not 32 bytes".into()),
+    }
+}
+
+impl NftMetadata {
+    pub fn to_program(&self) -> Program {
+        let pair = |key: &str, value: Program| Program::from(key.as_bytes().to_vec()).cons(&value);
+        let mut items = vec![
+            pair("u", uri_list(&self.data_uris)),
+            pair("h", Program::from(self.data_hash.bytes.clone())),
+            pair("mu", uri_list(&self.metadata_uris)),
+            pair("lu", uri_list(&self.license_uris)),
+            pair("sn", int_program(self.edition_number)),
+            pair("st", int_program(self.edition_total)),
+        ];
+        if let Some(metadata_hash) = &self.metadata_hash {
+            items.push(pair("mh", Program::from(metadata_hash.bytes.clone())));
+        }
+        if let Some(license_hash) = &self.license_hash {
+            items.push(pair("lh", Program::from(license_hash.bytes.clone())));
+        }
+        Program::from(items)
+    }
+
+    /// Unknown keys are ignored; a missing edition defaults to 1 of 1.
+    pub fn from_program(program: &Program) -> Result<Self, Box<dyn Error>> {
+        let mut data_hash = None;
+        let mut metadata = NftMetadata {
+            data_uris: Vec::new(),
+            data_hash: Bytes32::from(vec![0; 32]),
+            metadata_uris: Vec::new(),
+            metadata_hash: None,
+            license_uris: Vec::new(),
+            license_hash: None,
+            edition_number: 1,
+            edition_total: 1,
+        };
+        for item in program.iter() {
+            let (key, value) = item.as_pair().ok_or("NFT metadata entry is not a pair")?;
+            match key.as_vec().unwrap_or_default().as_slice() {
+                b"u" => metadata.data_uris = uris_from_program(&value)?,
+                b"h" => data_hash = Some(hash_from_program(&value)?),
+                b"mu" => metadata.metadata_uris = uris_from_program(&value)?,
+                b"mh" => metadata.metadata_hash = Some(hash_from_program(&value)?),
+                b"lu" => metadata.license_uris = uris_from_program(&value)?,
+                b"lh" => metadata.license_hash = Some(hash_from_program(&value)?),
+                b"sn" => metadata.edition_number = u64::try_from(value.as_int()?)?,
+                b"st" => metadata.edition_total = u64::try_from(value.as_int()?)?,
+                _ => {}
+            }
+        }
+        metadata.data_hash = data_hash.ok_or("NFT metadata has no data hash")?;
+        Ok(metadata)
+    }
+}
+
+/// Everything curried into an NFT1 puzzle, except the p2 puzzle that owns it.
+#[derive(Clone, Debug)]
+pub struct NftInfo {
+    pub launcher_id: Bytes32,
+    pub metadata: Program,
+    pub metadata_updater_puzzle_hash: Bytes32,
+    /// Launcher id of the DID the NFT is assigned to.
+    pub current_owner: Option<Bytes32>,
+    pub royalty_puzzle_hash: Bytes32,
+    /// In basis points: 300 is 3% of the trade price.
+    pub royalty_percentage: u16,
+}
+
+impl NftInfo {
+    pub fn transfer_program(&self) -> Program {
+        NFT_TRANSFER_PROGRAM_MOD.curry(vec![
+            singleton_struct(&self.launcher_id),
+            Program::from(self.royalty_puzzle_hash.bytes.clone()),
+            int_program(self.royalty_percentage as u64),
+        ])
+    }
+
+    fn ownership_layer(&self, p2_puzzle: &Program) -> Program {
+        let current_owner = match &self.current_owner {
+            Some(did_id) => Program::from(did_id.bytes.clone()),
+            None => Program::from(Vec::<u8>::new()),
+        };
+        NFT_OWNERSHIP_LAYER_MOD.curry(vec![
+            Program::from(NFT_OWNERSHIP_LAYER_MOD_HASH.bytes.clone()),
+            current_owner,
+            self.transfer_program(),
+            p2_puzzle.clone(),
+        ])
+    }
+
+    /// The state and ownership layers around `p2_puzzle`, i.e. the singleton's inner puzzle.
+    pub fn inner_puzzle(&self, p2_puzzle: &Program) -> Program {
+        NFT_STATE_LAYER_MOD.curry(vec![
+            Program::from(NFT_STATE_LAYER_MOD_HASH.bytes.clone()),
+            self.metadata.clone(),
+            Program::from(self.metadata_updater_puzzle_hash.bytes.clone()),
+            self.ownership_layer(p2_puzzle),
+        ])
+    }
+
+    pub fn puzzle(&self, p2_puzzle: &Program) -> Program {
+        puzzle_for_singleton(&self.launcher_id, &self.inner_puzzle(p2_puzzle))
+    }
+}
+
+/// The DID an NFT is assigned to, by its launcher id and current inner puzzle hash.
+/// The DID must be spent in the same bundle, announcing the NFT's launcher id.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct DidOwner {
+    pub did_id: Bytes32,
+    pub did_inner_puzzle_hash: Bytes32,
+}
+
+pub enum OwnerChange {
+    Keep,
+    Assign(DidOwner),
+    Unassign,
+}
+
+fn new_owner_condition(owner_change: &OwnerChange) -> Option<Program> {
+    let nil = || Program::from(Vec::<u8>::new());
+    let (did_id, did_inner_puzzle_hash) = match owner_change {
+        OwnerChange::Keep => return None,
+        OwnerChange::Assign(owner) => (
+            Program::from(owner.did_id.bytes.clone()),
+            Program::from(owner.did_inner_puzzle_hash.bytes.clone()),
+        ),
+        OwnerChange::Unassign => (nil(), nil()),
+    };
+    Some(Program::from(vec![
+        signed_int_program(&BigInt::from(NEW_OWNER_CONDITION)),
+        did_id,
+        nil(),
+        did_inner_puzzle_hash,
+    ]))
+}
+
+/// Standard p2 solution sending the NFT to `new_puzzle_hash` (hinted with it).
+fn transfer_inner_solution(new_puzzle_hash: &Bytes32, owner_change: &OwnerChange) -> Program {
+    let mut conditions = vec![make_create_coin_condition(
+        new_puzzle_hash,
+        1,
+        &[new_puzzle_hash.bytes.clone()],
+    )];
+    conditions.extend(new_owner_condition(owner_change));
+    // The state and ownership layers each take the inner solution as their only argument.
+    Program::from(vec![Program::from(vec![solutionForConditions(
+        Program::from(conditions),
+    )])])
+}
+
+/// What to put on chain when minting an NFT1.
+pub struct NftMint {
+    pub metadata: NftMetadata,
+    pub royalty_puzzle_hash: Bytes32,
+    pub royalty_percentage: u16,
+    /// Signs the eve spend, which sends the NFT on to `target_puzzle_hash`.
+    pub minter_public_key: PublicKey,
+    pub target_puzzle_hash: Bytes32,
+    pub did_owner: Option<DidOwner>,
+}
+
+/// Mints an NFT1 from standard XCH `coins`: launches the singleton and spends its eve
+/// coin to `target_puzzle_hash`. Returns the launcher id and the unsigned bundle.
+pub fn create_nft_mint_bundle(
+    coins: &[SpendableCoin],
+    mint: &NftMint,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<(Bytes32, SpendBundle), Box<dyn Error>> {
+    if mint.royalty_percentage > 10000 {
+        return Err("royalty percentage is in basis points and cannot exceed 10000".into());
+    }
+    let origin = coins.first().ok_or("no coins to mint from")?;
+    let info = NftInfo {
+        launcher_id: generate_launcher_coin(&origin.coin, 1).name(),
+        metadata: mint.metadata.to_program(),
+        metadata_updater_puzzle_hash: NFT_METADATA_UPDATER_MOD_HASH.clone(),
+        current_owner: None,
+        royalty_puzzle_hash: mint.royalty_puzzle_hash.clone(),
+        royalty_percentage: mint.royalty_percentage,
+    };
+    let eve_inner_puzzle = info.inner_puzzle(&get_puzzle_from_pk(mint.minter_public_key.clone()));
+    let (launcher_id, mut spend_bundle) = create_singleton_launch_bundle(
+        coins,
+        &eve_inner_puzzle,
+        &Program::from(Vec::<u8>::new()),
+        1,
+        fee,
+        change_puzzle_hash,
+    )?;
+    let launcher_spend = spend_bundle
+        .coin_spends
+        .last()
+        .ok_or("launch bundle has no launcher spend")?
+        .clone();
+    let owner_change = match &mint.did_owner {
+        Some(owner) => OwnerChange::Assign(owner.clone()),
+        None => OwnerChange::Keep,
+    };
+    let eve_spend = create_eve_spend(
+        &launcher_spend,
+        &eve_inner_puzzle,
+        &transfer_inner_solution(&mint.target_puzzle_hash, &owner_change),
+    )?;
+    spend_bundle.coin_spends.push(eve_spend);
+    Ok((launcher_id, spend_bundle))
+}
+
+/// Spends the NFT `coin`, owned by the standard puzzle of `public_key`, to
+/// `new_puzzle_hash`, optionally changing the DID it is assigned to.
+pub fn create_nft_transfer_spend(
+    coin: &Coin,
+    info: &NftInfo,
+    public_key: &PublicKey,
+    lineage_proof: &LineageProof,
+    new_puzzle_hash: &Bytes32,
+    owner_change: &OwnerChange,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    spend_singleton(
+        coin,
+        &info.launcher_id,
+        &info.inner_puzzle(&get_puzzle_from_pk(public_key.clone())),
+        &transfer_inner_solution(new_puzzle_hash, owner_change),
+        lineage_proof,
+    )
+}
+
+/// Recognises an NFT1 puzzle reveal, returning its info and p2 puzzle.
+pub fn parse_nft_puzzle(puzzle: &Program) -> Option<(NftInfo, Program)> {
+    let (launcher_id, state_layer) = match_singleton_puzzle(puzzle)?;
+    let state_layer = state_layer.try_uncurry()?;
+    if state_layer.program.tree_hash() != *NFT_STATE_LAYER_MOD_HASH || state_layer.args.len() != 4 {
+        return None;
+    }
+    let ownership_layer = state_layer.args[3].try_uncurry()?;
+    if ownership_layer.program.tree_hash() != *NFT_OWNERSHIP_LAYER_MOD_HASH
+        || ownership_layer.args.len() != 4
+    {
+        return None;
+    }
+    let transfer_program = ownership_layer.args[2].try_uncurry()?;
+    if transfer_program.program.tree_hash() != *NFT_TRANSFER_PROGRAM_MOD_HASH
+        || transfer_program.args.len() != 3
+    {
+        return None;
+    }
+    // The owner is nil or a DID's launcher id.
+    let current_owner = match ownership_layer.args[1].as_vec()? {
+        did_id if did_id.is_empty() => None,
+        _ => Some(bytes32_atom(&ownership_layer.args[1])?),
+    };
+    let info = NftInfo {
+        launcher_id,
+        metadata: state_layer.args[1].clone(),
+        metadata_updater_puzzle_hash: bytes32_atom(&state_layer.args[2])?,
+        current_owner,
+        royalty_puzzle_hash: bytes32_atom(&transfer_program.args[1])?,
+        royalty_percentage: u16::try_from(transfer_program.args[2].as_int().ok()?).ok()?,
+    };
+    Some((info, ownership_layer.args[3].clone()))
+}
+
+#[test]
+fn test_nft_metadata_round_trip() {
+    let metadata = NftMetadata {
+        data_uris: vec!["https://example.com/nft.png".to_string()],
+        data_hash: Bytes32::from(vec![0x11; 32]),
+        metadata_uris: vec!["https://example.com/nft.json".to_string()],
+        metadata_hash: Some(Bytes32::from(vec![0x22; 32])),
+        license_uris: Vec::new(),
+        license_hash: None,
+        edition_number: 2,
+        edition_total: 10,
+    };
+    assert_eq!(
+        NftMetadata::from_program(&metadata.to_program()).unwrap(),
+        metadata
+    );
+}
+
+#[test]
+fn test_parse_nft_puzzle() {
+    let state_layer: Bytes32 =
+        "a04d9f57764f54a43e4030befb4d80026e870519aaa66334aef8304f5d0393c2".into();
+    let ownership_layer: Bytes32 =
+        "c5abea79afaa001b5427dfa0c8cf42ca6f38f5841b78f9b3c252733eb2de2726".into();
+    let transfer_program: Bytes32 =
+        "025dee0fb1e9fa110302a7e9bfb6e381ca09618e2778b0184fa5c6b275cfce1f".into();
+    let metadata_updater: Bytes32 =
+        "fe8a4b4e27a2e29a4d3fc7ce9d527adbcaccbab6ada3903ccf3ba9a769d2d78b".into();
+    assert_eq!(*NFT_STATE_LAYER_MOD_HASH, state_layer);
+    assert_eq!(*NFT_OWNERSHIP_LAYER_MOD_HASH, ownership_layer);
+    assert_eq!(*NFT_TRANSFER_PROGRAM_MOD_HASH, transfer_program);
+    assert_eq!(*NFT_METADATA_UPDATER_MOD_HASH, metadata_updater);
+
+    let info = NftInfo {
+        launcher_id: Bytes32::from(vec![0x5a; 32]),
+        metadata: Program::from(vec![
+            Program::from(vec![0x68]).cons(&Program::from(vec![0x33; 32]))
+        ]),
+        metadata_updater_puzzle_hash: NFT_METADATA_UPDATER_MOD_HASH.clone(),
+        current_owner: Some(Bytes32::from(vec![0x44; 32])),
+        royalty_puzzle_hash: Bytes32::from(vec![0x55; 32]),
+        royalty_percentage: 300,
+    };
+    let p2_puzzle = Program::from(vec![Program::from(1), Program::from(vec![0x33; 8])]);
+    let (parsed, parsed_p2) = parse_nft_puzzle(&info.puzzle(&p2_puzzle)).unwrap();
+    assert_eq!(parsed.launcher_id, info.launcher_id);
+    assert_eq!(parsed.metadata, info.metadata);
+    assert_eq!(parsed.current_owner, info.current_owner);
+    assert_eq!(parsed.royalty_puzzle_hash, info.royalty_puzzle_hash);
+    assert_eq!(parsed.royalty_percentage, 300);
+    assert_eq!(parsed_p2, p2_puzzle);
+
+    // Curried hashes must be 32 bytes, and the owner nil or 32 bytes.
+    let nft_puzzle = |updater: Vec<u8>, owner: Vec<u8>, royalty: Vec<u8>| {
+        let transfer_program = NFT_TRANSFER_PROGRAM_MOD.curry(vec![
+            singleton_struct(&info.launcher_id),
+            Program::from(royalty),
+            int_program(300),
+        ]);
+        let ownership_layer = NFT_OWNERSHIP_LAYER_MOD.curry(vec![
+            Program::from(NFT_OWNERSHIP_LAYER_MOD_HASH.bytes.clone()),
+            Program::from(owner),
+            transfer_program,
+            p2_puzzle.clone(),
+        ]);
+        let state_layer = NFT_STATE_LAYER_MOD.curry(vec![
+            Program::from(NFT_STATE_LAYER_MOD_HASH.bytes.clone()),
+            info.metadata.clone(),
+            Program::from(updater),
+            ownership_layer,
+        ]);
+        puzzle_for_singleton(&info.launcher_id, &state_layer)
+    };
+    let hash = vec![0x55; 32];
+    let short = vec![0x55; 31];
+    assert!(parse_nft_puzzle(&nft_puzzle(hash.clone(), Vec::new(), hash.clone())).is_some());
+    assert!(parse_nft_puzzle(&nft_puzzle(short.clone(), Vec::new(), hash.clone())).is_none());
+    assert!(parse_nft_puzzle(&nft_puzzle(hash.clone(), short.clone(), hash.clone())).is_none());
+    assert!(parse_nft_puzzle(&nft_puzzle(hash, Vec::new(), short)).is_none());
+}
@@ -0,0 +1,4 @@
+pub(crate) mod nft_metadata_updater_default;
+pub(crate) mod nft_ownership_layer;
+pub(crate) mod nft_ownership_transfer_program_one_way_claim_with_royalties;
+pub(crate) mod nft_state_layer;
@@ -0,0 +1,14 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Default metadata updater: prepends a data ("u"), metadata ("mu") or license ("lu") URI.
+pub static NFT_METADATA_UPDATER_STR: &str = "ff02ffff01ff04ffff04ffff02ffff03ffff22ff27ff3780ffff01ff02ffff03ffff21ffff09ff27ffff01826d7580ffff09ff27ffff01826c7580ffff09ff27ffff01758080ffff01ff02ff02ffff04ff02ffff04ff05ffff04ff27ffff04ff37ff808080808080ffff010580ff0180ffff010580ff0180ffff04ff0bff808080ffff01ff808080ffff04ffff01ff02ffff03ff05ffff01ff02ffff03ffff09ff11ff0b80ffff01ff04ffff04ff0bffff04ff17ff198080ff0d80ffff01ff04ff09ffff02ff02ffff04ff02ffff04ff0dffff04ff0bffff04ff17ff8080808080808080ff0180ff8080ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref NFT_METADATA_UPDATER_MOD: Program = {
+        SerializedProgram::from_hex(NFT_METADATA_UPDATER_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref NFT_METADATA_UPDATER_MOD_HASH: Bytes32 = NFT_METADATA_UPDATER_MOD.tree_hash();
+}
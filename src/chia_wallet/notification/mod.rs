@@ -0,0 +1,2 @@
+pub(crate) mod notification_utils;
+pub(crate) mod puzzles;
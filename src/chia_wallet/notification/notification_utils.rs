@@ -0,0 +1,220 @@
+use std::error::Error;
+
+use crate::blockchain::announcement::Announcement;
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::condition_opcode::ConditionOpcode;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::blockchain::utils::atom_to_int;
+use crate::chia_wallet::puzzle_utils::{int_program, make_assert_coin_announcement};
+use crate::chia_wallet::standart::transaction::{
+    create_standard_spend_bundle_with_conditions, Payment, SpendableCoin,
+};
+use crate::program_utils::condition_utils::{condition_programs_for_solution, create_coin_memos};
+use crate::program_utils::program::Program;
+use crate::program_utils::serialized_program::SerializedProgram;
+use crate::program_utils::utils::INFINITE_COST;
+
+use super::puzzles::notification::NOTIFICATION_MOD;
+
+/// Wallets ignore notification messages longer than this.
+pub const MAX_NOTIFICATION_MESSAGE_LENGTH: usize = 10000;
+
+/// A message sent with `amount` mojos to `target_puzzle_hash`. The notification coin
+/// pays the amount on to the target as soon as it is spent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Notification {
+    pub coin: Coin,
+    pub target_puzzle_hash: Bytes32,
+    pub amount: u64,
+    pub message: Vec<u8>,
+}
+
+pub fn notification_puzzle(target_puzzle_hash: &Bytes32, amount: u64) -> Program {
+    NOTIFICATION_MOD.curry(vec![
+        Program::from(target_puzzle_hash.bytes.clone()),
+        int_program(amount),
+    ])
+}
+
+/// Sends `message` with `amount` mojos to `target_puzzle_hash` from standard XCH
+/// `coins`. The first coin creates the notification coin, hinted to the target with the
+/// message as its second memo, and the bundle spends it at once. Only the standard
+/// spends need signing.
+pub fn create_notification_bundle(
+    coins: &[SpendableCoin],
+    target_puzzle_hash: &Bytes32,
+    message: &[u8],
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    if message.len() > MAX_NOTIFICATION_MESSAGE_LENGTH {
+        return Err(format!(
+            "the message is {} bytes, notifications carry at most {}",
+            message.len(),
+            MAX_NOTIFICATION_MESSAGE_LENGTH
+        )
+        .into());
+    }
+    let origin = coins
+        .first()
+        .ok_or("no coins to send the notification from")?;
+    let puzzle = notification_puzzle(target_puzzle_hash, amount);
+    let notification_coin = Coin {
+        parent_coin_info: origin.coin.name(),
+        puzzle_hash: puzzle.tree_hash(),
+        amount,
+    };
+    let announcement = Announcement {
+        origin_info: notification_coin.name(),
+        message: Vec::new(),
+    };
+    let payment = Payment {
+        puzzle_hash: notification_coin.puzzle_hash.clone(),
+        amount,
+        memos: vec![target_puzzle_hash.bytes.clone(), message.to_vec()],
+    };
+    let mut spend_bundle = create_standard_spend_bundle_with_conditions(
+        coins,
+        &[payment],
+        fee,
+        change_puzzle_hash,
+        vec![make_assert_coin_announcement(&announcement.name())],
+    )?;
+    spend_bundle.coin_spends.push(CoinSpend {
+        coin: notification_coin,
+        puzzle_reveal: SerializedProgram::from_bytes(&puzzle.serialized),
+        solution: SerializedProgram::from_bytes(&Program::null().serialized),
+    });
+    Ok(spend_bundle)
+}
+
+/// The notifications a coin spend created. Their message is only in the memos of the
+/// CREATE_COIN, so this takes the spend of the notification coin's parent, as the
+/// wallet fetches it for a coin hinted to one of its puzzle hashes.
+pub fn parse_notifications(parent_spend: &CoinSpend) -> Result<Vec<Notification>, Box<dyn Error>> {
+    let (conditions, _cost) = condition_programs_for_solution(
+        &parent_spend.puzzle_reveal,
+        &parent_spend.solution,
+        INFINITE_COST,
+    )?;
+    let mut notifications = Vec::new();
+    for (condition, program) in &conditions {
+        if condition.opcode != ConditionOpcode::CreateCoin || condition.vars.len() != 2 {
+            continue;
+        }
+        // The memos are the target and the message.
+        let memos = create_coin_memos(program);
+        if memos.len() != 2 || memos[1].len() > MAX_NOTIFICATION_MESSAGE_LENGTH {
+            continue;
+        }
+        // Malformed CREATE_COINs are not notifications; skip them rather than pad or
+        // truncate their hashes.
+        let target_puzzle_hash = match Bytes32::try_from(memos[0].as_slice()) {
+            Ok(target_puzzle_hash) => target_puzzle_hash,
+            Err(_) => continue,
+        };
+        let amount: u64 = match atom_to_int(&condition.vars[1]).try_into() {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+        let puzzle_hash = match Bytes32::try_from(condition.vars[0].as_slice()) {
+            Ok(puzzle_hash) => puzzle_hash,
+            Err(_) => continue,
+        };
+        if notification_puzzle(&target_puzzle_hash, amount).tree_hash() != puzzle_hash {
+            continue;
+        }
+        notifications.push(Notification {
+            coin: Coin {
+                parent_coin_info: parent_spend.coin.name(),
+                puzzle_hash,
+                amount,
+            },
+            target_puzzle_hash,
+            amount,
+            message: memos[1].clone(),
+        });
+    }
+    Ok(notifications)
+}
+
+#[test]
+fn test_notification_round_trip() {
+    use crate::chia_wallet::notification::puzzles::notification::NOTIFICATION_MOD_HASH;
+    use crate::chia_wallet::test_utils::{standard_coin, test_secret_key};
+
+    let notification: Bytes32 =
+        "b8b9d8ffca6d5cba5422ead7f477ecfc8f6aaaa1c024b8c3aeb1956b24a0ab1e".into();
+    assert_eq!(*NOTIFICATION_MOD_HASH, notification);
+
+    let coins = vec![standard_coin(&test_secret_key(9).public_key(), 0x21, 1000)];
+    let puzzle_hash = coins[0].coin.puzzle_hash.clone();
+    let target = Bytes32::from(vec![0xbb; 32]);
+    let message = b"offer1qqr83wcuu2rykcmqvpsxvgqqemhmlaek".to_vec();
+    let spend_bundle =
+        create_notification_bundle(&coins, &target, &message, 1, 10, &puzzle_hash).unwrap();
+    assert_eq!(spend_bundle.coin_spends.len(), 2);
+
+    let notifications = parse_notifications(&spend_bundle.coin_spends[0]).unwrap();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].target_puzzle_hash, target);
+    assert_eq!(notifications[0].amount, 1);
+    assert_eq!(notifications[0].message, message);
+    assert_eq!(notifications[0].coin, spend_bundle.coin_spends[1].coin);
+    let paid = spend_bundle.coin_spends[1].additions();
+    assert_eq!(paid.len(), 1);
+    assert_eq!(paid[0].puzzle_hash, target);
+}
+
+#[test]
+fn test_parse_notifications_skips_malformed_create_coins() {
+    use crate::chia_wallet::puzzle_utils::make_condition;
+    use crate::chia_wallet::test_utils::{standard_coin, standard_coin_spend, test_secret_key};
+
+    let target = vec![0xbb; 32];
+    let message = b"hello".to_vec();
+    let memos = Program::from(vec![
+        Program::from(target.clone()),
+        Program::from(message.clone()),
+    ]);
+    let puzzle_hash = notification_puzzle(&Bytes32::from(target.clone()), 1).tree_hash();
+    let conditions = Program::from(vec![
+        // A 31 byte puzzle hash.
+        make_condition(
+            ConditionOpcode::CreateCoin,
+            vec![
+                Program::from(puzzle_hash.bytes[1..].to_vec()),
+                int_program(1),
+                memos.clone(),
+            ],
+        ),
+        // A 31 byte target.
+        make_condition(
+            ConditionOpcode::CreateCoin,
+            vec![
+                Program::from(puzzle_hash.bytes.clone()),
+                int_program(1),
+                Program::from(vec![
+                    Program::from(target[1..].to_vec()),
+                    Program::from(message.clone()),
+                ]),
+            ],
+        ),
+        make_condition(
+            ConditionOpcode::CreateCoin,
+            vec![
+                Program::from(puzzle_hash.bytes.clone()),
+                int_program(1),
+                memos,
+            ],
+        ),
+    ]);
+    let coin = standard_coin(&test_secret_key(9).public_key(), 0x21, 1);
+    let notifications = parse_notifications(&standard_coin_spend(&coin, conditions)).unwrap();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0].coin.puzzle_hash, puzzle_hash);
+    assert_eq!(notifications[0].message, message);
+}
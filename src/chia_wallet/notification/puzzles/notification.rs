@@ -0,0 +1,15 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Curried with a target puzzle hash and an amount, and sends the amount on to the
+/// target with an empty coin announcement. Its solution is nil.
+pub static NOTIFICATION_STR: &str = "ff02ffff01ff04ffff04ff04ffff04ff05ffff04ff0bff80808080ffff04ffff04ff06ffff01ff808080ff808080ffff04ffff01ff333cff018080";
+
+lazy_static::lazy_static! {
+    pub static ref NOTIFICATION_MOD: Program = {
+        SerializedProgram::from_hex(NOTIFICATION_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref NOTIFICATION_MOD_HASH: Bytes32 = NOTIFICATION_MOD.tree_hash();
+}
@@ -0,0 +1,2 @@
+pub(crate) mod offer_utils;
+pub(crate) mod puzzles;
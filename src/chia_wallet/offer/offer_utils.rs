@@ -0,0 +1,959 @@
+use std::error::Error;
+
+use chia_bls::signature::{aggregate, Signature};
+
+use crate::blockchain::bech32m::{
+    bech32m_decode_with_max_length, bech32m_encode, convert_bits, Bech32Error,
+};
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::sized_bytes::{Bytes32, Bytes96};
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::cat::cat_utils::{
+    coin_as_program, match_cat_puzzle, unsigned_spend_bundle_for_spendable_cats, CatVersion,
+    SpendableCat,
+};
+use crate::chia_wallet::cat::issuance::{cat_puzzle_hash, StandardCatCoin};
+use crate::chia_wallet::cat::puzzles::create_cat_outer_puzzle::construct_cat_puzzle;
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::puzzle_compression::{
+    compress_spend_bundle, decompress_spend_bundle, lowest_best_version, spend_bundle_mods,
+    LATEST_VERSION,
+};
+use crate::chia_wallet::puzzle_utils::{
+    int_program, make_assert_puzzle_announcement, make_create_coin_condition,
+};
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    get_puzzle_from_pk, solutionForConditions,
+};
+use crate::chia_wallet::standart::transaction::{
+    create_standard_spend_bundle_with_conditions, empty_signature, Payment, SpendableCoin,
+};
+use crate::program_utils::program::Program;
+use crate::program_utils::utils::hash_256;
+
+use super::puzzles::settlement_payments::{SETTLEMENT_PAYMENTS_MOD, SETTLEMENT_PAYMENTS_MOD_HASH};
+
+pub const OFFER_PREFIX: &str = "offer";
+
+/// Offers are compressed with at least this version, as the reference wallet does.
+pub const MINIMUM_OFFER_VERSION: u16 = 4;
+
+/// The TAIL hash of a CAT, or `None` for XCH.
+pub type AssetId = Option<Bytes32>;
+
+pub fn asset_name(asset_id: &AssetId) -> String {
+    match asset_id {
+        Some(tail_hash) => format!("CAT {}", tail_hash),
+        None => "XCH".to_string(),
+    }
+}
+
+/// A payment made by the settlement puzzle. The nonce ties it to the coins of the
+/// offer that requested it, so it cannot be reused to satisfy another offer.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct NotarizedPayment {
+    pub nonce: Bytes32,
+    pub puzzle_hash: Bytes32,
+    pub amount: u64,
+    pub memos: Vec<Vec<u8>>,
+}
+
+impl NotarizedPayment {
+    /// `(puzzle_hash amount memos)`, the arguments of the CREATE_COIN it becomes.
+    fn condition_args(&self) -> Program {
+        let memos: Vec<Program> = self
+            .memos
+            .iter()
+            .map(|memo| Program::from(memo.clone()))
+            .collect();
+        Program::from(vec![
+            Program::from(self.puzzle_hash.bytes.clone()),
+            int_program(self.amount),
+            Program::from(memos),
+        ])
+    }
+}
+
+fn push_to_asset<T>(groups: &mut Vec<(AssetId, Vec<T>)>, asset_id: &AssetId, items: Vec<T>) {
+    match groups.iter_mut().find(|(id, _)| id == asset_id) {
+        Some((_, group)) => group.extend(items),
+        None => groups.push((asset_id.clone(), items)),
+    }
+}
+
+/// Tree hash of `coins` sorted by coin id.
+pub fn offer_nonce(coins: &[Coin]) -> Bytes32 {
+    let mut coins = coins.to_vec();
+    coins.sort_by(|a, b| a.name().bytes.cmp(&b.name().bytes));
+    Program::from(coins.iter().map(coin_as_program).collect::<Vec<Program>>()).tree_hash()
+}
+
+pub fn notarize_payments(
+    requested: &[(AssetId, Vec<Payment>)],
+    nonce: &Bytes32,
+) -> Vec<(AssetId, Vec<NotarizedPayment>)> {
+    let mut notarized = Vec::new();
+    for (asset_id, payments) in requested {
+        let payments = payments
+            .iter()
+            .map(|payment| NotarizedPayment {
+                nonce: nonce.clone(),
+                puzzle_hash: payment.puzzle_hash.clone(),
+                amount: payment.amount,
+                memos: payment.memos.clone(),
+            })
+            .collect();
+        push_to_asset(&mut notarized, asset_id, payments);
+    }
+    notarized
+}
+
+/// The settlement puzzle, wrapped in the CAT layer for CATs.
+pub fn settlement_puzzle(asset_id: &AssetId) -> Program {
+    match asset_id {
+        Some(tail_hash) => construct_cat_puzzle(tail_hash, &SETTLEMENT_PAYMENTS_MOD),
+        None => SETTLEMENT_PAYMENTS_MOD.clone(),
+    }
+}
+
+pub fn settlement_puzzle_hash(asset_id: &AssetId) -> Bytes32 {
+    match asset_id {
+        Some(tail_hash) => cat_puzzle_hash(tail_hash, &SETTLEMENT_PAYMENTS_MOD_HASH),
+        None => SETTLEMENT_PAYMENTS_MOD_HASH.clone(),
+    }
+}
+
+/// `(nonce . payments)`, which the settlement puzzle announces once it pays them.
+fn notarized_payments_program(nonce: &Bytes32, payments: &[&NotarizedPayment]) -> Program {
+    let payments: Vec<Program> = payments
+        .iter()
+        .map(|payment| payment.condition_args())
+        .collect();
+    Program::from(nonce.bytes.clone()).cons(&Program::from(payments))
+}
+
+/// The settlement puzzle's solution: the payments grouped by nonce.
+fn settlement_solution(payments: &[NotarizedPayment]) -> Program {
+    let mut nonces: Vec<&Bytes32> = Vec::new();
+    for payment in payments {
+        if !nonces.contains(&&payment.nonce) {
+            nonces.push(&payment.nonce);
+        }
+    }
+    let groups: Vec<Program> = nonces
+        .into_iter()
+        .map(|nonce| {
+            let group: Vec<&NotarizedPayment> = payments
+                .iter()
+                .filter(|payment| payment.nonce == *nonce)
+                .collect();
+            notarized_payments_program(nonce, &group)
+        })
+        .collect();
+    Program::from(groups)
+}
+
+/// One ASSERT_PUZZLE_ANNOUNCEMENT per asset, so the maker's spends only go through
+/// if the settlement puzzle pays what they requested.
+pub fn payment_announcements(requested: &[(AssetId, Vec<NotarizedPayment>)]) -> Vec<Program> {
+    let mut announcements = Vec::new();
+    for (asset_id, payments) in requested {
+        let nonce = match payments.first() {
+            Some(payment) => &payment.nonce,
+            None => continue,
+        };
+        let payments: Vec<&NotarizedPayment> = payments.iter().collect();
+        let mut announcement = settlement_puzzle_hash(asset_id).bytes;
+        announcement.extend(
+            notarized_payments_program(nonce, &payments)
+                .tree_hash()
+                .bytes,
+        );
+        announcements.push(make_assert_puzzle_announcement(&Bytes32::from(hash_256(
+            announcement,
+        ))));
+    }
+    announcements
+}
+
+fn asset_of_puzzle(puzzle: &Program) -> AssetId {
+    match match_cat_puzzle(puzzle) {
+        Some((CatVersion::Cat2, tail_hash, _inner_puzzle)) => Some(tail_hash),
+        _ => None,
+    }
+}
+
+pub struct Offer {
+    /// What the maker asks for in return, per asset.
+    pub requested_payments: Vec<(AssetId, Vec<NotarizedPayment>)>,
+    /// The maker's spends, which pay the offered assets to the settlement puzzle.
+    pub bundle: SpendBundle,
+}
+
+impl Offer {
+    /// The bundle plus, per requested asset, a spend of a zero coin holding the
+    /// settlement puzzle whose solution lists the requested payments. This is the
+    /// spend bundle an offer file carries.
+    pub fn to_spend_bundle(&self) -> SpendBundle {
+        let mut coin_spends = self.bundle.coin_spends.clone();
+        for (asset_id, payments) in &self.requested_payments {
+            let puzzle = settlement_puzzle(asset_id);
+            coin_spends.push(CoinSpend {
+                coin: Coin {
+                    parent_coin_info: Bytes32::from(vec![0u8; 32]),
+                    puzzle_hash: puzzle.tree_hash(),
+                    amount: 0,
+                },
+                puzzle_reveal: puzzle.into(),
+                solution: settlement_solution(payments).into(),
+            });
+        }
+        SpendBundle {
+            coin_spends,
+            aggregated_signature: self.bundle.aggregated_signature.clone(),
+        }
+    }
+
+    pub fn from_spend_bundle(spend_bundle: &SpendBundle) -> Result<Offer, Box<dyn Error>> {
+        let zero = Bytes32::from(vec![0u8; 32]);
+        let mut requested_payments = Vec::new();
+        let mut coin_spends = Vec::new();
+        for coin_spend in &spend_bundle.coin_spends {
+            if coin_spend.coin.parent_coin_info != zero {
+                coin_spends.push(coin_spend.clone());
+                continue;
+            }
+            let puzzle = Program::new(coin_spend.puzzle_reveal.to_bytes());
+            let asset_id = asset_of_puzzle(&puzzle);
+            if puzzle.tree_hash() != settlement_puzzle_hash(&asset_id) {
+                return Err("requested payments are not made by the settlement puzzle".into());
+            }
+            let payments =
+                parse_settlement_solution(&Program::new(coin_spend.solution.to_bytes()))?;
+            push_to_asset(&mut requested_payments, &asset_id, payments);
+        }
+        Ok(Offer {
+            requested_payments,
+            bundle: SpendBundle {
+                coin_spends,
+                aggregated_signature: spend_bundle.aggregated_signature.clone(),
+            },
+        })
+    }
+
+    /// Encodes the offer as an `offer1…` string, compressed with the lowest dictionary
+    /// version that covers its puzzles, but never below `MINIMUM_OFFER_VERSION`.
+    pub fn to_bech32(&self) -> Result<String, Box<dyn Error>> {
+        let spend_bundle = self.to_spend_bundle();
+        let version =
+            lowest_best_version(&spend_bundle_mods(&spend_bundle)).max(MINIMUM_OFFER_VERSION);
+        let compressed = compress_spend_bundle(&spend_bundle, Some(version))?;
+        let data = convert_bits(&compressed, 8, 5, true).expect("8-bit values always convert");
+        Ok(bech32m_encode(OFFER_PREFIX, &data))
+    }
+
+    pub fn from_bech32(offer: &str) -> Result<Offer, Box<dyn Error>> {
+        let (prefix, data) = bech32m_decode_with_max_length(offer, offer.len())?;
+        if prefix != OFFER_PREFIX {
+            return Err(Bech32Error::WrongPrefix {
+                expected: OFFER_PREFIX.to_string(),
+                got: prefix,
+            }
+            .into());
+        }
+        let bytes = convert_bits(&data, 5, 8, false)?;
+        let version = bytes
+            .get(..2)
+            .map(|prefix| u16::from_be_bytes([prefix[0], prefix[1]]));
+        let spend_bundle = match version {
+            Some(version) if version <= LATEST_VERSION => decompress_spend_bundle(&bytes)?,
+            // Offers made without compression carry the spend bundle as is.
+            _ => SpendBundle::from_bytes(&bytes)?,
+        };
+        Offer::from_spend_bundle(&spend_bundle)
+    }
+
+    /// Coins the bundle pays to the settlement puzzle, per asset.
+    pub fn offered_coins(&self) -> Vec<(AssetId, Vec<Coin>)> {
+        let mut offered = Vec::new();
+        for coin_spend in &self.bundle.coin_spends {
+            let asset_id = asset_of_puzzle(&Program::new(coin_spend.puzzle_reveal.to_bytes()));
+            let settlement_puzzle_hash = settlement_puzzle_hash(&asset_id);
+            let coins: Vec<Coin> = coin_spend
+                .additions()
+                .into_iter()
+                .filter(|coin| coin.puzzle_hash == settlement_puzzle_hash)
+                .collect();
+            if !coins.is_empty() {
+                push_to_asset(&mut offered, &asset_id, coins);
+            }
+        }
+        offered
+    }
+
+    pub fn offered_amounts(&self) -> Vec<(AssetId, u64)> {
+        self.offered_coins()
+            .into_iter()
+            .map(|(asset_id, coins)| {
+                let amount = coins
+                    .iter()
+                    .fold(0u64, |total, coin| total.saturating_add(coin.amount));
+                (asset_id, amount)
+            })
+            .collect()
+    }
+
+    pub fn requested_amounts(&self) -> Vec<(AssetId, u64)> {
+        self.requested_payments
+            .iter()
+            .map(|(asset_id, payments)| {
+                let amount = payments
+                    .iter()
+                    .fold(0u64, |total, payment| total.saturating_add(payment.amount));
+                (asset_id.clone(), amount)
+            })
+            .collect()
+    }
+
+    /// XCH the maker's spends leave unclaimed.
+    pub fn fee(&self) -> u64 {
+        let mut input: u64 = 0;
+        let mut output: u64 = 0;
+        for coin_spend in &self.bundle.coin_spends {
+            let puzzle = Program::new(coin_spend.puzzle_reveal.to_bytes());
+            if match_cat_puzzle(&puzzle).is_some() {
+                continue;
+            }
+            input = input.saturating_add(coin_spend.coin.amount);
+            for coin in coin_spend.additions() {
+                output = output.saturating_add(coin.amount);
+            }
+        }
+        input.saturating_sub(output)
+    }
+}
+
+fn parse_settlement_solution(solution: &Program) -> Result<Vec<NotarizedPayment>, Box<dyn Error>> {
+    let mut payments = Vec::new();
+    for notarized in solution.iter() {
+        let (nonce, group) = notarized
+            .as_pair()
+            .ok_or("notarized payments are not a (nonce . payments) pair")?;
+        let nonce = nonce.as_vec().ok_or("nonce is not an atom")?;
+        if nonce.len() != 32 {
+            return Err("nonce is not 32 bytes".into());
+        }
+        for payment in group.iter() {
+            let args: Vec<Program> = payment.iter().collect();
+            let puzzle_hash = args
+                .first()
+                .and_then(|arg| arg.as_vec())
+                .filter(|puzzle_hash| puzzle_hash.len() == 32)
+                .ok_or("payment puzzle hash is not 32 bytes")?;
+            let amount = args.get(1).ok_or("payment has no amount")?.as_int()?;
+            let amount = u64::try_from(amount).map_err(|_| "payment amount is out of range")?;
+            let mut memos = Vec::new();
+            if let Some(memo_list) = args.get(2) {
+                for memo in memo_list.iter() {
+                    memos.push(memo.as_vec().ok_or("memo is not an atom")?);
+                }
+            }
+            payments.push(NotarizedPayment {
+                nonce: Bytes32::from(nonce.clone()),
+                puzzle_hash: Bytes32::from(puzzle_hash),
+                amount,
+                memos,
+            });
+        }
+    }
+    Ok(payments)
+}
+
+/// Standard CAT coins to offer `amount` mojos from.
+#[derive(Clone)]
+pub struct OfferedCat {
+    pub tail_hash: Bytes32,
+    pub coins: Vec<StandardCatCoin>,
+    pub amount: u64,
+}
+
+fn offered_cat_spends(
+    cat: &OfferedCat,
+    change_puzzle_hash: &Bytes32,
+    announcements: &[Program],
+) -> Result<Vec<CoinSpend>, Box<dyn Error>> {
+    let mut total: u64 = 0;
+    for standard in &cat.coins {
+        total = total
+            .checked_add(standard.coin.amount)
+            .ok_or("coin amounts overflow")?;
+    }
+    if cat.amount == 0 || cat.amount > total {
+        return Err(format!(
+            "cannot offer {} mojos of CAT {} from coins holding {}",
+            cat.amount, cat.tail_hash, total
+        )
+        .into());
+    }
+    let mut conditions = vec![make_create_coin_condition(
+        &SETTLEMENT_PAYMENTS_MOD_HASH,
+        cat.amount,
+        &[],
+    )];
+    if total > cat.amount {
+        conditions.push(make_create_coin_condition(
+            change_puzzle_hash,
+            total - cat.amount,
+            &[change_puzzle_hash.bytes.clone()],
+        ));
+    }
+    conditions.extend(announcements.iter().cloned());
+    let mut spendable_cats = Vec::with_capacity(cat.coins.len());
+    for (index, standard) in cat.coins.iter().enumerate() {
+        let inner_conditions = if index == 0 {
+            conditions.clone()
+        } else {
+            Vec::new()
+        };
+        spendable_cats.push(SpendableCat {
+            coin: standard.coin.clone(),
+            limitations_program_hash: cat.tail_hash.clone(),
+            inner_puzzle: get_puzzle_from_pk(standard.public_key.clone()),
+            inner_solution: solutionForConditions(Program::from(inner_conditions)),
+            lineage_proof: standard.lineage_proof.clone(),
+            extra_delta: 0,
+        });
+    }
+    Ok(unsigned_spend_bundle_for_spendable_cats(&spendable_cats)?.coin_spends)
+}
+
+/// Builds an unsigned offer of `offered_xch` mojos from `xch_coins` and the
+/// `offered_cats`, in exchange for the `requested` payments. `fee` is paid from
+/// `xch_coins` and change goes to `change_puzzle_hash`.
+pub fn create_offer(
+    xch_coins: &[SpendableCoin],
+    offered_xch: u64,
+    offered_cats: &[OfferedCat],
+    requested: &[(AssetId, Vec<Payment>)],
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<Offer, Box<dyn Error>> {
+    let mut coins: Vec<Coin> = xch_coins
+        .iter()
+        .map(|spendable| spendable.coin.clone())
+        .collect();
+    for cat in offered_cats {
+        coins.extend(cat.coins.iter().map(|standard| standard.coin.clone()));
+    }
+    if coins.is_empty() {
+        return Err("an offer must spend at least one coin".into());
+    }
+    let requested_payments = notarize_payments(requested, &offer_nonce(&coins));
+    let announcements = payment_announcements(&requested_payments);
+
+    let mut coin_spends = Vec::new();
+    if !xch_coins.is_empty() {
+        let mut payments = Vec::new();
+        if offered_xch > 0 {
+            payments.push(Payment {
+                puzzle_hash: SETTLEMENT_PAYMENTS_MOD_HASH.clone(),
+                amount: offered_xch,
+                memos: Vec::new(),
+            });
+        }
+        let xch_bundle = create_standard_spend_bundle_with_conditions(
+            xch_coins,
+            &payments,
+            fee,
+            change_puzzle_hash,
+            announcements.clone(),
+        )?;
+        coin_spends.extend(xch_bundle.coin_spends);
+    } else if offered_xch > 0 || fee > 0 {
+        return Err("offering XCH or paying a fee needs XCH coins".into());
+    }
+    for cat in offered_cats {
+        coin_spends.extend(offered_cat_spends(cat, change_puzzle_hash, &announcements)?);
+    }
+    Ok(Offer {
+        requested_payments,
+        bundle: SpendBundle {
+            coin_spends,
+            aggregated_signature: empty_signature(),
+        },
+    })
+}
+
+/// Builds the unsigned taker side of `offer`: it pays what the maker requested from
+/// the taker's coins and asks for everything offered at `receive_puzzle_hash`.
+/// `cat_coins` are the taker's standard CAT coins, by TAIL hash.
+pub fn create_taker_offer(
+    offer: &Offer,
+    xch_coins: &[SpendableCoin],
+    cat_coins: &[(Bytes32, Vec<StandardCatCoin>)],
+    receive_puzzle_hash: &Bytes32,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<Offer, Box<dyn Error>> {
+    let mut offered_xch = 0;
+    let mut offered_cats = Vec::new();
+    for (asset_id, amount) in offer.requested_amounts() {
+        match asset_id {
+            None => offered_xch = amount,
+            Some(tail_hash) => {
+                let coins = cat_coins
+                    .iter()
+                    .find(|(coins_tail_hash, _)| *coins_tail_hash == tail_hash)
+                    .map(|(_, coins)| coins.clone())
+                    .ok_or_else(|| format!("no coins of requested CAT {}", tail_hash))?;
+                offered_cats.push(OfferedCat {
+                    tail_hash,
+                    coins,
+                    amount,
+                });
+            }
+        }
+    }
+    let requested: Vec<(AssetId, Vec<Payment>)> = offer
+        .offered_amounts()
+        .into_iter()
+        .map(|(asset_id, amount)| {
+            let payment = Payment {
+                puzzle_hash: receive_puzzle_hash.clone(),
+                amount,
+                memos: vec![receive_puzzle_hash.bytes.clone()],
+            };
+            (asset_id, vec![payment])
+        })
+        .collect();
+    if requested.is_empty() {
+        return Err("the offer does not offer anything".into());
+    }
+    create_offer(
+        xch_coins,
+        offered_xch,
+        &offered_cats,
+        &requested,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+fn settlement_lineage_proof(
+    coin: &Coin,
+    coin_spends: &[CoinSpend],
+) -> Result<LineageProof, Box<dyn Error>> {
+    let parent = coin_spends
+        .iter()
+        .find(|coin_spend| coin_spend.coin.name() == coin.parent_coin_info)
+        .ok_or("settlement coin's parent is not in the bundle")?;
+    let (_version, _tail_hash, inner_puzzle) =
+        match_cat_puzzle(&Program::new(parent.puzzle_reveal.to_bytes()))
+            .ok_or("settlement coin's parent is not a CAT")?;
+    Ok(LineageProof {
+        parent_name: Some(parent.coin.parent_coin_info.clone()),
+        inner_puzzle_hash: Some(inner_puzzle.tree_hash()),
+        amount: Some(parent.coin.amount),
+    })
+}
+
+fn settlement_spends(
+    asset_id: &AssetId,
+    coins: &[Coin],
+    payments: &[NotarizedPayment],
+    coin_spends: &[CoinSpend],
+) -> Result<Vec<CoinSpend>, Box<dyn Error>> {
+    // The first coin makes every payment; the rest are spent with an empty solution.
+    let solution_for = |index: usize| {
+        if index == 0 {
+            settlement_solution(payments)
+        } else {
+            Program::from(Vec::<Program>::new())
+        }
+    };
+    match asset_id {
+        None => Ok(coins
+            .iter()
+            .enumerate()
+            .map(|(index, coin)| CoinSpend {
+                coin: coin.clone(),
+                puzzle_reveal: SETTLEMENT_PAYMENTS_MOD.clone().into(),
+                solution: solution_for(index).into(),
+            })
+            .collect()),
+        Some(tail_hash) => {
+            let mut spendable_cats = Vec::with_capacity(coins.len());
+            for (index, coin) in coins.iter().enumerate() {
+                spendable_cats.push(SpendableCat {
+                    coin: coin.clone(),
+                    limitations_program_hash: tail_hash.clone(),
+                    inner_puzzle: SETTLEMENT_PAYMENTS_MOD.clone(),
+                    inner_solution: solution_for(index),
+                    lineage_proof: settlement_lineage_proof(coin, coin_spends)?,
+                    extra_delta: 0,
+                });
+            }
+            Ok(unsigned_spend_bundle_for_spendable_cats(&spendable_cats)?.coin_spends)
+        }
+    }
+}
+
+fn total_amount(amounts: impl Iterator<Item = u64>) -> Result<u64, Box<dyn Error>> {
+    let mut total: u64 = 0;
+    for amount in amounts {
+        total = total.checked_add(amount).ok_or("amounts overflow")?;
+    }
+    Ok(total)
+}
+
+/// Joins the signed maker and taker offers into the final spend bundle, spending the
+/// settlement coins of each asset to make the payments both sides requested. Whatever
+/// an asset's settlement coins hold beyond those payments is paid to
+/// `arbitrage_puzzle_hash` under a zero nonce, as the reference wallet's
+/// `Offer.to_valid_spend` does.
+pub fn complete_offer(
+    maker: &Offer,
+    taker: &Offer,
+    arbitrage_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let mut coin_spends = maker.bundle.coin_spends.clone();
+    coin_spends.extend(taker.bundle.coin_spends.iter().cloned());
+    let mut requested = maker.requested_payments.clone();
+    for (asset_id, payments) in &taker.requested_payments {
+        push_to_asset(&mut requested, asset_id, payments.clone());
+    }
+    let mut offered = maker.offered_coins();
+    for (asset_id, coins) in taker.offered_coins() {
+        push_to_asset(&mut offered, &asset_id, coins);
+    }
+
+    let mut settlements = Vec::new();
+    for (asset_id, coins) in &offered {
+        let mut payments = requested
+            .iter()
+            .find(|(id, _)| id == asset_id)
+            .map(|(_, payments)| payments.clone())
+            .unwrap_or_default();
+        let offered_amount = total_amount(coins.iter().map(|coin| coin.amount))?;
+        let requested_amount = total_amount(payments.iter().map(|payment| payment.amount))?;
+        let arbitrage = offered_amount
+            .checked_sub(requested_amount)
+            .ok_or_else(|| {
+                format!(
+                    "{} mojos of {} are requested but only {} are offered",
+                    requested_amount,
+                    asset_name(asset_id),
+                    offered_amount
+                )
+            })?;
+        if arbitrage > 0 {
+            payments.push(NotarizedPayment {
+                nonce: Bytes32::from(vec![0u8; 32]),
+                puzzle_hash: arbitrage_puzzle_hash.clone(),
+                amount: arbitrage,
+                memos: Vec::new(),
+            });
+        }
+        settlements.extend(settlement_spends(asset_id, coins, &payments, &coin_spends)?);
+    }
+    for (asset_id, payments) in &requested {
+        if !payments.is_empty() && !offered.iter().any(|(id, _)| id == asset_id) {
+            return Err(format!("requested {} is not offered", asset_name(asset_id)).into());
+        }
+    }
+    coin_spends.extend(settlements);
+
+    let mut signatures = Vec::new();
+    for signature in [
+        &maker.bundle.aggregated_signature,
+        &taker.bundle.aggregated_signature,
+    ] {
+        let mut bytes = [0u8; 96];
+        bytes.copy_from_slice(&signature.bytes);
+        signatures.push(
+            Signature::from_bytes(&bytes).map_err(|e| format!("invalid signature: {:?}", e))?,
+        );
+    }
+    Ok(SpendBundle {
+        coin_spends,
+        aggregated_signature: Bytes96::from(aggregate(&signatures).to_bytes().to_vec()),
+    })
+}
+
+/// An offer of 600 mojos for 250 of a CAT whose TAIL hash is sha256("offer fixture
+/// tail"), from a standard coin of the G1 generator key. The spend bundle was built
+/// by hand in Python following chia-blockchain's `Offer.to_spend_bundle`, compressed
+/// with CPython's zlib as `compress_object_with_puzzles(bytes, 4)` does, and bech32m
+/// encoded per BIP-350. It is not an offer exported by a chia wallet.
+#[cfg(test)]
+static OFFER_FIXTURE: &str = "offer1qqz83wcuu2rykcmqvps240ljl28wkshp4vnpynv9evazgn27cj7d8h8vz8879uuqllkzrjm0mxvnt27knm3wyn2t88ayxnrs3xqck3ufmamqa3kltup9x8q7mc8l92rpqqp7v9lswm48p8lrqcdmcc06cl9ukrd8t706yd34h8rhleg6lmp3j0076d9hdv3w7jemy9uhtcfezyl6ctlx09w5l004v8acdc43mkcd6mdqqjt6luteehmlaakcenaz0wmdchc4w600dhj7vfjn477jkwz369l88jll73l37fekqxs8zh45p9vysg0esdexqp2a4l6l2pc09vym067768k4ezstsd5fcjr7l9n3h379mw2evmc79f8wu4q7cn8q2p3zqq9k09z3kl458qj392tga7he4dm92lt8wtg7ec2777y96cjdvexc8lng922r38kardk7dpdkld9dxlddvxmdawxmdavseg8l4d04czzlkqhn0k038h44xcdxlxcmry6jra6g9n3l5q8nh94s8cmkqemwmt536k4gy25tnz6vej45seha834z00e0ellcv4hlmtnvx6y7ll06hph0lwf4tdfkvsdt3tglvgc3d5s5dzxvqg94d0ng4k9rkx64xtztlnv0mwl898sa37eeasw06fucec3l5mkf7ghjn7vgvrxr9u3ffqfv83q2s92ryyqqq6v0xj5s8spltu";
+
+#[test]
+fn test_offer_fixture_round_trip() {
+    let offer = Offer::from_bech32(OFFER_FIXTURE).unwrap();
+    let tail_hash: Bytes32 =
+        "5892a63a357c0eb2b172de1f0ffe0f1ddafb7983345f8df5e37ebfceacb5649a".into();
+    assert_eq!(offer.offered_amounts(), vec![(None, 600)]);
+    assert_eq!(offer.requested_amounts(), vec![(Some(tail_hash), 250)]);
+    assert_eq!(offer.to_bech32().unwrap(), OFFER_FIXTURE);
+
+    // A known version whose data does not decompress is not read as a raw bundle.
+    let mut corrupt = MINIMUM_OFFER_VERSION.to_be_bytes().to_vec();
+    corrupt.extend(offer.to_spend_bundle().to_bytes());
+    let data = convert_bits(&corrupt, 8, 5, true).unwrap();
+    assert!(Offer::from_bech32(&bech32m_encode(OFFER_PREFIX, &data)).is_err());
+}
+
+#[test]
+fn test_offer_round_trip() {
+    let settlement: Bytes32 =
+        "cfbfdeed5c4ca2de3d0bf520b9cb4bb7743a359bd2e6a188d19ce7dffc21d3e7".into();
+    assert_eq!(*SETTLEMENT_PAYMENTS_MOD_HASH, settlement);
+
+    // `1` returns its solution, so this coin pays 600 mojos to the settlement puzzle.
+    let coin = Coin {
+        parent_coin_info: Bytes32::from(vec![0x11; 32]),
+        puzzle_hash: Program::from(1).tree_hash(),
+        amount: 1000,
+    };
+    let coin_spend = CoinSpend {
+        coin: coin.clone(),
+        puzzle_reveal: Program::from(1).into(),
+        solution: Program::from(vec![make_create_coin_condition(&settlement, 600, &[])]).into(),
+    };
+    let tail_hash = Bytes32::from(vec![0x22; 32]);
+    let receive_puzzle_hash = Bytes32::from(vec![0x33; 32]);
+    let requested = vec![(
+        Some(tail_hash.clone()),
+        vec![Payment {
+            puzzle_hash: receive_puzzle_hash.clone(),
+            amount: 250,
+            memos: vec![receive_puzzle_hash.bytes.clone()],
+        }],
+    )];
+    let offer = Offer {
+        requested_payments: notarize_payments(&requested, &offer_nonce(&[coin])),
+        bundle: SpendBundle {
+            coin_spends: vec![coin_spend],
+            aggregated_signature: empty_signature(),
+        },
+    };
+
+    let encoded = offer.to_bech32().unwrap();
+    assert!(encoded.starts_with("offer1"));
+    let decoded = Offer::from_bech32(&encoded).unwrap();
+    assert_eq!(decoded.requested_payments, offer.requested_payments);
+    assert_eq!(decoded.offered_amounts(), vec![(None, 600)]);
+    assert_eq!(decoded.requested_amounts(), vec![(Some(tail_hash), 250)]);
+    assert_eq!(decoded.fee(), 400);
+    assert_eq!(
+        decoded.to_spend_bundle().to_bytes(),
+        offer.to_spend_bundle().to_bytes()
+    );
+}
+
+/// A maker offering 600 mojos for 250 of a CAT, and a taker holding a CAT coin of 400.
+#[cfg(test)]
+struct OfferFlowFixture {
+    tail_hash: Bytes32,
+    maker: Offer,
+    maker_puzzle_hash: Bytes32,
+    taker_cat: StandardCatCoin,
+    taker_puzzle_hash: Bytes32,
+}
+
+#[cfg(test)]
+fn offer_flow_fixture() -> OfferFlowFixture {
+    use crate::chia_wallet::test_utils::{standard_coin, test_secret_key};
+
+    let tail_hash = Bytes32::from(vec![0x77; 32]);
+    let maker_coin = standard_coin(&test_secret_key(21).public_key(), 0x41, 1000);
+    let maker_puzzle_hash = maker_coin.coin.puzzle_hash.clone();
+    let requested = vec![(
+        Some(tail_hash.clone()),
+        vec![Payment {
+            puzzle_hash: maker_puzzle_hash.clone(),
+            amount: 250,
+            memos: vec![maker_puzzle_hash.bytes.clone()],
+        }],
+    )];
+    let maker = create_offer(&[maker_coin], 600, &[], &requested, 0, &maker_puzzle_hash).unwrap();
+    let maker = Offer::from_bech32(&maker.to_bech32().unwrap()).unwrap();
+
+    let taker_pk = test_secret_key(22).public_key();
+    let taker_puzzle_hash = get_puzzle_from_pk(taker_pk.clone()).tree_hash();
+    let taker_cat_puzzle_hash = cat_puzzle_hash(&tail_hash, &taker_puzzle_hash);
+    let parent = Coin {
+        parent_coin_info: Bytes32::from(vec![0x42; 32]),
+        puzzle_hash: taker_cat_puzzle_hash.clone(),
+        amount: 400,
+    };
+    let taker_cat = StandardCatCoin {
+        coin: Coin {
+            parent_coin_info: parent.name(),
+            puzzle_hash: taker_cat_puzzle_hash,
+            amount: 400,
+        },
+        public_key: taker_pk,
+        lineage_proof: LineageProof {
+            parent_name: Some(parent.parent_coin_info.clone()),
+            inner_puzzle_hash: Some(taker_puzzle_hash.clone()),
+            amount: Some(parent.amount),
+        },
+    };
+    OfferFlowFixture {
+        tail_hash,
+        maker,
+        maker_puzzle_hash,
+        taker_cat,
+        taker_puzzle_hash,
+    }
+}
+
+/// A taker offer paying `offered_cat` of the fixture's CAT for `requested_xch` mojos.
+#[cfg(test)]
+fn fixture_taker_offer(fixture: &OfferFlowFixture, offered_cat: u64, requested_xch: u64) -> Offer {
+    let taker_puzzle_hash = &fixture.taker_puzzle_hash;
+    let offered_cats = [OfferedCat {
+        tail_hash: fixture.tail_hash.clone(),
+        coins: vec![fixture.taker_cat.clone()],
+        amount: offered_cat,
+    }];
+    let requested = vec![(
+        None,
+        vec![Payment {
+            puzzle_hash: taker_puzzle_hash.clone(),
+            amount: requested_xch,
+            memos: vec![taker_puzzle_hash.bytes.clone()],
+        }],
+    )];
+    create_offer(&[], 0, &offered_cats, &requested, 0, taker_puzzle_hash).unwrap()
+}
+
+#[cfg(test)]
+fn offer_additions(spend_bundle: &SpendBundle) -> Vec<Coin> {
+    spend_bundle
+        .coin_spends
+        .iter()
+        .flat_map(|coin_spend| coin_spend.additions())
+        .collect()
+}
+
+#[test]
+fn test_offer_flow() {
+    use crate::blockchain::announcement::Announcement;
+    use crate::blockchain::condition_opcode::ConditionOpcode;
+    use crate::program_utils::condition_utils::conditions_for_solution;
+    use crate::program_utils::utils::INFINITE_COST;
+
+    let OfferFlowFixture {
+        tail_hash,
+        maker,
+        maker_puzzle_hash,
+        taker_cat,
+        taker_puzzle_hash,
+    } = offer_flow_fixture();
+
+    // The taker pays from a CAT coin of 400 and keeps the change.
+    let taker = create_taker_offer(
+        &maker,
+        &[],
+        &[(tail_hash.clone(), vec![taker_cat])],
+        &taker_puzzle_hash,
+        0,
+        &taker_puzzle_hash,
+    )
+    .unwrap();
+    assert_eq!(
+        taker.offered_amounts(),
+        vec![(Some(tail_hash.clone()), 250)]
+    );
+    assert_eq!(taker.requested_amounts(), vec![(None, 600)]);
+
+    // Both sides' spends, then one settlement spend per asset.
+    let spend_bundle = complete_offer(&maker, &taker, &taker_puzzle_hash).unwrap();
+    assert_eq!(spend_bundle.coin_spends.len(), 4);
+    let mut created = Vec::new();
+    let mut asserted = Vec::new();
+    for coin_spend in &spend_bundle.coin_spends {
+        let (conditions, _cost) = conditions_for_solution(
+            &coin_spend.puzzle_reveal,
+            &coin_spend.solution,
+            INFINITE_COST,
+        )
+        .unwrap();
+        for condition in conditions {
+            match condition.opcode {
+                ConditionOpcode::CreatePuzzleAnnouncement => created.push(
+                    Announcement {
+                        origin_info: coin_spend.coin.puzzle_hash.clone(),
+                        message: condition.vars[0].clone(),
+                    }
+                    .name(),
+                ),
+                ConditionOpcode::AssertPuzzleAnnouncement => {
+                    asserted.push(Bytes32::from(condition.vars[0].clone()))
+                }
+                _ => {}
+            }
+        }
+    }
+    assert_eq!(asserted.len(), 2);
+    for announcement in &asserted {
+        assert!(created.contains(announcement));
+    }
+
+    let additions = offer_additions(&spend_bundle);
+    let maker_cat_puzzle_hash = cat_puzzle_hash(&tail_hash, &maker_puzzle_hash);
+    assert!(additions
+        .iter()
+        .any(|coin| coin.puzzle_hash == taker_puzzle_hash && coin.amount == 600));
+    assert!(additions
+        .iter()
+        .any(|coin| coin.puzzle_hash == maker_cat_puzzle_hash && coin.amount == 250));
+}
+
+#[test]
+fn test_offer_cat_surplus_goes_to_taker() {
+    let fixture = offer_flow_fixture();
+    // The taker offers 300 of the CAT where the maker asked for 250.
+    let taker = fixture_taker_offer(&fixture, 300, 600);
+    let spend_bundle = complete_offer(&fixture.maker, &taker, &fixture.taker_puzzle_hash).unwrap();
+    let additions = offer_additions(&spend_bundle);
+    let maker_cat_puzzle_hash = cat_puzzle_hash(&fixture.tail_hash, &fixture.maker_puzzle_hash);
+    let taker_cat_puzzle_hash = cat_puzzle_hash(&fixture.tail_hash, &fixture.taker_puzzle_hash);
+    assert!(additions
+        .iter()
+        .any(|coin| coin.puzzle_hash == maker_cat_puzzle_hash && coin.amount == 250));
+    assert!(additions
+        .iter()
+        .any(|coin| coin.puzzle_hash == taker_cat_puzzle_hash && coin.amount == 50));
+    assert!(additions
+        .iter()
+        .any(|coin| coin.puzzle_hash == fixture.taker_puzzle_hash && coin.amount == 600));
+
+    // Offering less than the maker asked for is not a valid spend.
+    let short = fixture_taker_offer(&fixture, 200, 600);
+    assert!(complete_offer(&fixture.maker, &short, &fixture.taker_puzzle_hash).is_err());
+}
+
+#[test]
+fn test_offer_xch_surplus_goes_to_taker() {
+    let fixture = offer_flow_fixture();
+    // The taker only asks for 500 of the 600 mojos offered.
+    let taker = fixture_taker_offer(&fixture, 250, 500);
+    let spend_bundle = complete_offer(&fixture.maker, &taker, &fixture.taker_puzzle_hash).unwrap();
+    let taker_xch: Vec<u64> = offer_additions(&spend_bundle)
+        .iter()
+        .filter(|coin| coin.puzzle_hash == fixture.taker_puzzle_hash)
+        .map(|coin| coin.amount)
+        .collect();
+    assert_eq!(taker_xch.len(), 2);
+    assert!(taker_xch.contains(&500));
+    assert!(taker_xch.contains(&100));
+
+    // Asking for more than the maker offered is not a valid spend.
+    let greedy = fixture_taker_offer(&fixture, 250, 700);
+    assert!(complete_offer(&fixture.maker, &greedy, &fixture.taker_puzzle_hash).is_err());
+}
@@ -0,0 +1,2 @@
+pub(crate) mod pool_utils;
+pub(crate) mod puzzles;
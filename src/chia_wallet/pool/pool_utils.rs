@@ -0,0 +1,818 @@
+use std::error::Error;
+
+use serde::{Deserialize, Serialize};
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::sized_bytes::{Bytes32, Bytes48};
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::cat::puzzles::curry_and_treehash::{
+    atom_hash, curried_puzzle_hash, pair_hash,
+};
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::puzzle_utils::{bytes32_atom, int_program};
+use crate::chia_wallet::singleton::puzzles::singleton_launcher::SINGLETON_LAUNCHER_HASH;
+use crate::chia_wallet::singleton::puzzles::singleton_top_layer_v1_0::{
+    SINGLETON_TOP_LAYER_V1_0_MOD, SINGLETON_TOP_LAYER_V1_0_MOD_HASH,
+};
+use crate::chia_wallet::singleton::singleton_utils::{
+    create_launch_bundle_for_puzzle_hash, generate_launcher_coin, solution_for_singleton,
+};
+use crate::chia_wallet::standart::transaction::SpendableCoin;
+use crate::program_utils::program::Program;
+
+use super::puzzles::p2_singleton_or_delayed::P2_SINGLETON_OR_DELAYED_MOD;
+use super::puzzles::pool_member_innerpuz::{
+    POOL_MEMBER_INNERPUZ_MOD, POOL_MEMBER_INNERPUZ_MOD_HASH,
+};
+use super::puzzles::pool_waitingroom_innerpuz::{
+    POOL_WAITINGROOM_INNERPUZ_MOD, POOL_WAITINGROOM_INNERPUZ_MOD_HASH,
+};
+
+pub const POOL_PROTOCOL_VERSION: u8 = 1;
+
+/// Plot NFTs are launched with a single mojo.
+pub const PLOT_NFT_AMOUNT: u64 = 1;
+
+/// How long pool rewards wait for the plot NFT before they can be claimed to its delay
+/// puzzle hash: a week, in seconds.
+pub const DEFAULT_DELAY_TIME: u64 = 604800;
+
+/// The wallet refuses pools that would lock the plot NFT for longer than this.
+pub const MAX_RELATIVE_LOCK_HEIGHT: u32 = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolSingletonState {
+    SelfPooling = 1,
+    LeavingPool = 2,
+    FarmingToPool = 3,
+}
+
+impl PoolSingletonState {
+    pub fn from_u8(value: u8) -> Result<PoolSingletonState, Box<dyn Error>> {
+        match value {
+            1 => Ok(PoolSingletonState::SelfPooling),
+            2 => Ok(PoolSingletonState::LeavingPool),
+            3 => Ok(PoolSingletonState::FarmingToPool),
+            _ => Err(format!("unknown pool singleton state {}", value).into()),
+        }
+    }
+}
+
+/// The state a plot NFT is in. It is carried, streamable-encoded, under the `p` key of
+/// the launcher's and of every travel spend's key-value list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolState {
+    pub version: u8,
+    pub state: PoolSingletonState,
+    /// The pool's puzzle hash, or the owner's own when self-pooling.
+    pub target_puzzle_hash: Bytes32,
+    pub owner_pubkey: Bytes48,
+    pub pool_url: Option<String>,
+    pub relative_lock_height: u32,
+}
+
+impl PoolState {
+    pub fn self_pooling(owner_pubkey: &Bytes48, target_puzzle_hash: &Bytes32) -> PoolState {
+        PoolState {
+            version: POOL_PROTOCOL_VERSION,
+            state: PoolSingletonState::SelfPooling,
+            target_puzzle_hash: target_puzzle_hash.clone(),
+            owner_pubkey: owner_pubkey.clone(),
+            pool_url: None,
+            relative_lock_height: 0,
+        }
+    }
+
+    pub fn farming_to_pool(
+        owner_pubkey: &Bytes48,
+        pool_puzzle_hash: &Bytes32,
+        pool_url: &str,
+        relative_lock_height: u32,
+    ) -> Result<PoolState, Box<dyn Error>> {
+        if relative_lock_height > MAX_RELATIVE_LOCK_HEIGHT {
+            return Err(format!(
+                "relative lock height {} is over the maximum of {}",
+                relative_lock_height, MAX_RELATIVE_LOCK_HEIGHT
+            )
+            .into());
+        }
+        Ok(PoolState {
+            version: POOL_PROTOCOL_VERSION,
+            state: PoolSingletonState::FarmingToPool,
+            target_puzzle_hash: pool_puzzle_hash.clone(),
+            owner_pubkey: owner_pubkey.clone(),
+            pool_url: Some(pool_url.to_string()),
+            relative_lock_height,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.version, self.state as u8];
+        bytes.extend(&self.target_puzzle_hash.bytes);
+        bytes.extend(&self.owner_pubkey.bytes);
+        match &self.pool_url {
+            Some(pool_url) => {
+                bytes.push(1);
+                bytes.extend((pool_url.len() as u32).to_be_bytes());
+                bytes.extend(pool_url.as_bytes());
+            }
+            None => bytes.push(0),
+        }
+        bytes.extend(self.relative_lock_height.to_be_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<PoolState, Box<dyn Error>> {
+        let truncated = || format!("pool state of {} bytes is truncated", bytes.len());
+        if bytes.len() < 83 {
+            return Err(truncated().into());
+        }
+        let mut offset = 82;
+        let pool_url = match bytes[offset] {
+            0 => {
+                offset += 1;
+                None
+            }
+            1 => {
+                let length = bytes.get(offset + 1..offset + 5).ok_or_else(truncated)?;
+                let length = u32::from_be_bytes([length[0], length[1], length[2], length[3]]);
+                offset += 5;
+                let url = bytes
+                    .get(offset..offset + length as usize)
+                    .ok_or_else(truncated)?;
+                offset += length as usize;
+                Some(String::from_utf8(url.to_vec())?)
+            }
+            flag => return Err(format!("invalid pool url flag {}", flag).into()),
+        };
+        if bytes.len() != offset + 4 {
+            return Err(format!(
+                "pool state is {} bytes, expected {}",
+                bytes.len(),
+                offset + 4
+            )
+            .into());
+        }
+        let relative_lock_height = &bytes[offset..];
+        Ok(PoolState {
+            version: bytes[0],
+            state: PoolSingletonState::from_u8(bytes[1])?,
+            target_puzzle_hash: Bytes32::try_from(&bytes[2..34])?,
+            owner_pubkey: Bytes48::try_from(&bytes[34..82])?,
+            pool_url,
+            relative_lock_height: u32::from_be_bytes([
+                relative_lock_height[0],
+                relative_lock_height[1],
+                relative_lock_height[2],
+                relative_lock_height[3],
+            ]),
+        })
+    }
+
+    /// The key-value list of a travel spend into this state.
+    pub fn key_value_list(&self) -> Program {
+        Program::from(vec![
+            Program::from(b"p".to_vec()).cons(&Program::from(self.to_bytes()))
+        ])
+    }
+}
+
+/// Pool rewards are coinbase coins whose parent id is the first half of the genesis
+/// challenge followed by the block height.
+pub fn pool_reward_prefix(genesis_challenge: &Bytes32) -> Bytes32 {
+    let mut prefix = genesis_challenge.bytes[..16].to_vec();
+    prefix.extend([0u8; 16]);
+    Bytes32::from(prefix)
+}
+
+pub fn pool_reward_parent_id(genesis_challenge: &Bytes32, height: u32) -> Bytes32 {
+    let mut parent_id = genesis_challenge.bytes[..16].to_vec();
+    parent_id.extend((height as u128).to_be_bytes());
+    Bytes32::from(parent_id)
+}
+
+/// The block height of a pool reward coin, or `None` if it is not one on this network.
+pub fn pool_reward_height(genesis_challenge: &Bytes32, reward_coin: &Coin) -> Option<u32> {
+    let parent_id = &reward_coin.parent_coin_info.bytes;
+    if parent_id[..16] != genesis_challenge.bytes[..16] || parent_id[16..28] != [0u8; 12] {
+        return None;
+    }
+    Some(u32::from_be_bytes([
+        parent_id[28],
+        parent_id[29],
+        parent_id[30],
+        parent_id[31],
+    ]))
+}
+
+fn singleton_struct_hash(launcher_id: &Bytes32) -> Bytes32 {
+    pair_hash(
+        &atom_hash(&SINGLETON_TOP_LAYER_V1_0_MOD_HASH.bytes),
+        &pair_hash(
+            &atom_hash(&launcher_id.bytes),
+            &atom_hash(&SINGLETON_LAUNCHER_HASH.bytes),
+        ),
+    )
+}
+
+pub fn pool_singleton_puzzle(launcher_id: &Bytes32, inner_puzzle: &Program) -> Program {
+    let singleton_struct = Program::from(SINGLETON_TOP_LAYER_V1_0_MOD_HASH.bytes.clone()).cons(
+        &Program::from(launcher_id.bytes.clone())
+            .cons(&Program::from(SINGLETON_LAUNCHER_HASH.bytes.clone())),
+    );
+    SINGLETON_TOP_LAYER_V1_0_MOD.curry(vec![singleton_struct, inner_puzzle.clone()])
+}
+
+pub fn pool_singleton_puzzle_hash(launcher_id: &Bytes32, inner_puzzle_hash: &Bytes32) -> Bytes32 {
+    curried_puzzle_hash(
+        &SINGLETON_TOP_LAYER_V1_0_MOD_HASH,
+        &[
+            singleton_struct_hash(launcher_id),
+            inner_puzzle_hash.clone(),
+        ],
+    )
+}
+
+/// Splits a plot NFT puzzle into its launcher id and inner puzzle.
+pub fn match_pool_singleton_puzzle(puzzle: &Program) -> Option<(Bytes32, Program)> {
+    let uncurried = puzzle.try_uncurry()?;
+    if uncurried.program.tree_hash() != *SINGLETON_TOP_LAYER_V1_0_MOD_HASH
+        || uncurried.args.len() != 2
+    {
+        return None;
+    }
+    let launcher_id = bytes32_atom(&uncurried.args[0].rest().ok()?.first().ok()?)?;
+    Some((launcher_id, uncurried.args[1].clone()))
+}
+
+/// Pool rewards for the plot NFT `launcher_id` are paid to this puzzle. Rewards it
+/// does not absorb within `delay_time` seconds can be claimed to `delay_puzzle_hash`.
+pub fn p2_singleton_puzzle(
+    launcher_id: &Bytes32,
+    delay_time: u64,
+    delay_puzzle_hash: &Bytes32,
+) -> Program {
+    P2_SINGLETON_OR_DELAYED_MOD.curry(vec![
+        Program::from(SINGLETON_TOP_LAYER_V1_0_MOD_HASH.bytes.clone()),
+        Program::from(launcher_id.bytes.clone()),
+        Program::from(SINGLETON_LAUNCHER_HASH.bytes.clone()),
+        int_program(delay_time),
+        Program::from(delay_puzzle_hash.bytes.clone()),
+    ])
+}
+
+/// The inner puzzle of a plot NFT in `state`: the waiting room when self-pooling or
+/// leaving a pool, the pool member puzzle when farming to one.
+pub fn pool_state_to_inner_puzzle(
+    state: &PoolState,
+    launcher_id: &Bytes32,
+    genesis_challenge: &Bytes32,
+    delay_time: u64,
+    delay_puzzle_hash: &Bytes32,
+) -> Program {
+    let shared_args = vec![
+        Program::from(state.target_puzzle_hash.bytes.clone()),
+        Program::from(
+            p2_singleton_puzzle(launcher_id, delay_time, delay_puzzle_hash)
+                .tree_hash()
+                .bytes,
+        ),
+        Program::from(state.owner_pubkey.bytes.clone()),
+        Program::from(pool_reward_prefix(genesis_challenge).bytes),
+    ];
+    let mut waiting_room_args = shared_args.clone();
+    waiting_room_args.push(int_program(state.relative_lock_height as u64));
+    let waiting_room = POOL_WAITINGROOM_INNERPUZ_MOD.curry(waiting_room_args);
+    if state.state != PoolSingletonState::FarmingToPool {
+        return waiting_room;
+    }
+    let mut member_args = shared_args;
+    member_args.push(Program::from(waiting_room.tree_hash().bytes));
+    POOL_MEMBER_INNERPUZ_MOD.curry(member_args)
+}
+
+fn is_pool_member_inner_puzzle(inner_puzzle: &Program) -> bool {
+    match inner_puzzle.try_uncurry() {
+        Some(uncurried) => uncurried.program.tree_hash() == *POOL_MEMBER_INNERPUZ_MOD_HASH,
+        None => false,
+    }
+}
+
+/// Finds the pool state in a launcher or travel key-value list.
+pub fn pool_state_from_extra_data(extra_data: &Program) -> Option<PoolState> {
+    let state_bytes = key_value_list_get(extra_data, b"p")?.as_vec()?;
+    PoolState::from_bytes(&state_bytes).ok()
+}
+
+fn key_value_list_get(key_value_list: &Program, key: &[u8]) -> Option<Program> {
+    key_value_list.iter().find_map(|item| {
+        let (item_key, value) = item.as_pair()?;
+        if item_key.as_vec()? == key {
+            Some(value)
+        } else {
+            None
+        }
+    })
+}
+
+/// The pool state a spend moved the plot NFT into: the initial state for the launcher
+/// spend, the target for a travel spend, and `None` for an absorb spend.
+pub fn solution_to_pool_state(coin_spend: &CoinSpend) -> Result<Option<PoolState>, Box<dyn Error>> {
+    let solution = Program::new(coin_spend.solution.to_bytes());
+    if coin_spend.coin.puzzle_hash == *SINGLETON_LAUNCHER_HASH {
+        let extra_data = solution.rest()?.rest()?.first()?;
+        return Ok(Some(
+            pool_state_from_extra_data(&extra_data).ok_or("launcher spend has no pool state")?,
+        ));
+    }
+    let inner_solution = solution.rest()?.rest()?.first()?;
+    let inner_arguments: Vec<Program> = inner_solution.iter().collect();
+    let extra_data = match inner_arguments.len() {
+        // Pool member: `(key_value_list 0)` to travel, `(amount height)` to absorb.
+        2 => {
+            if inner_arguments[1].as_int()? != 0.into() || inner_arguments[0].is_atom() {
+                return Ok(None);
+            }
+            inner_arguments[0].clone()
+        }
+        // Waiting room: `(1 key_value_list destination)` or `(0 amount height)`.
+        3 => {
+            if inner_arguments[0].as_int()? == 0.into() {
+                return Ok(None);
+            }
+            inner_arguments[1].clone()
+        }
+        _ => return Err("not a plot NFT spend".into()),
+    };
+    Ok(Some(
+        pool_state_from_extra_data(&extra_data).ok_or("travel spend has no pool state")?,
+    ))
+}
+
+/// A plot NFT as its owner's wallet tracks it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlotNft {
+    pub launcher_coin: Coin,
+    /// Unabsorbed pool rewards can be claimed to `delay_puzzle_hash` after `delay_time`
+    /// seconds. Both are fixed at launch.
+    pub delay_time: u64,
+    pub delay_puzzle_hash: Bytes32,
+    pub state: PoolState,
+    /// The current singleton coin and the proof of its parent.
+    pub coin: Coin,
+    pub lineage_proof: LineageProof,
+}
+
+impl PlotNft {
+    pub fn launcher_id(&self) -> Bytes32 {
+        self.launcher_coin.name()
+    }
+
+    pub fn p2_singleton_puzzle(&self) -> Program {
+        p2_singleton_puzzle(
+            &self.launcher_id(),
+            self.delay_time,
+            &self.delay_puzzle_hash,
+        )
+    }
+
+    pub fn inner_puzzle(&self, genesis_challenge: &Bytes32) -> Program {
+        pool_state_to_inner_puzzle(
+            &self.state,
+            &self.launcher_id(),
+            genesis_challenge,
+            self.delay_time,
+            &self.delay_puzzle_hash,
+        )
+    }
+
+    /// Spends the current coin, recreating it with `next_inner_puzzle_hash`, and moves
+    /// `self` on to the child.
+    fn spend(
+        &mut self,
+        inner_puzzle: &Program,
+        inner_solution: &Program,
+        next_state: PoolState,
+        genesis_challenge: &Bytes32,
+    ) -> Result<CoinSpend, Box<dyn Error>> {
+        let puzzle = pool_singleton_puzzle(&self.launcher_id(), inner_puzzle);
+        if puzzle.tree_hash() != self.coin.puzzle_hash {
+            return Err(format!(
+                "pool state does not match the puzzle hash of plot NFT coin {}",
+                self.coin.name()
+            )
+            .into());
+        }
+        let coin_spend = CoinSpend {
+            coin: self.coin.clone(),
+            puzzle_reveal: puzzle.into(),
+            solution: solution_for_singleton(&self.lineage_proof, self.coin.amount, inner_solution)
+                .into(),
+        };
+        self.state = next_state;
+        self.lineage_proof = LineageProof {
+            parent_name: Some(self.coin.parent_coin_info.clone()),
+            inner_puzzle_hash: Some(inner_puzzle.tree_hash()),
+            amount: Some(self.coin.amount),
+        };
+        self.coin = Coin {
+            parent_coin_info: self.coin.name(),
+            puzzle_hash: pool_singleton_puzzle_hash(
+                &self.launcher_id(),
+                &self.inner_puzzle(genesis_challenge).tree_hash(),
+            ),
+            amount: self.coin.amount,
+        };
+        Ok(coin_spend)
+    }
+}
+
+/// Rebuilds a plot NFT from its spends, oldest first, starting with the launcher spend.
+pub fn parse_plot_nft(coin_spends: &[CoinSpend]) -> Result<PlotNft, Box<dyn Error>> {
+    let launcher_spend = coin_spends.first().ok_or("no plot NFT spends")?;
+    if launcher_spend.coin.puzzle_hash != *SINGLETON_LAUNCHER_HASH {
+        return Err("the first plot NFT spend must be the launcher spend".into());
+    }
+    let launcher_solution = Program::new(launcher_spend.solution.to_bytes());
+    let extra_data = launcher_solution.rest()?.rest()?.first()?;
+    let delay_time = key_value_list_get(&extra_data, b"t")
+        .ok_or("launcher spend has no delay time")?
+        .as_int()?;
+    let delay_puzzle_hash = key_value_list_get(&extra_data, b"h")
+        .and_then(|delay_puzzle_hash| bytes32_atom(&delay_puzzle_hash))
+        .ok_or("launcher spend has no 32 byte delay puzzle hash")?;
+    let launcher_coin = launcher_spend.coin.clone();
+    let mut state = solution_to_pool_state(launcher_spend)?.ok_or("launcher has no pool state")?;
+    let mut coin = Coin {
+        parent_coin_info: launcher_coin.name(),
+        puzzle_hash: bytes32_atom(&launcher_solution.first()?)
+            .ok_or("launcher solution does not start with a 32 byte puzzle hash")?,
+        amount: launcher_coin.amount,
+    };
+    let mut lineage_proof = LineageProof {
+        parent_name: Some(launcher_coin.parent_coin_info.clone()),
+        inner_puzzle_hash: None,
+        amount: Some(launcher_coin.amount),
+    };
+    for coin_spend in &coin_spends[1..] {
+        if coin_spend.coin.name() != coin.name() {
+            return Err(format!(
+                "spend of {} does not continue the plot NFT",
+                coin_spend.coin.name()
+            )
+            .into());
+        }
+        if let Some(next_state) = solution_to_pool_state(coin_spend)? {
+            state = next_state;
+        }
+        let puzzle = Program::new(coin_spend.puzzle_reveal.to_bytes());
+        let (_launcher_id, inner_puzzle) =
+            match_pool_singleton_puzzle(&puzzle).ok_or("not a plot NFT puzzle")?;
+        lineage_proof = LineageProof {
+            parent_name: Some(coin_spend.coin.parent_coin_info.clone()),
+            inner_puzzle_hash: Some(inner_puzzle.tree_hash()),
+            amount: Some(coin_spend.coin.amount),
+        };
+        coin = coin_spend
+            .additions()
+            .into_iter()
+            .find(|child| child.amount % 2 == 1)
+            .ok_or("plot NFT spend does not recreate the singleton")?;
+    }
+    Ok(PlotNft {
+        launcher_coin,
+        delay_time: u64::try_from(delay_time).map_err(|_| "invalid delay time")?,
+        delay_puzzle_hash,
+        state,
+        coin,
+        lineage_proof,
+    })
+}
+
+/// Launches a plot NFT in `initial_state` from standard XCH `coins`. Returns the launcher
+/// id and the unsigned bundle.
+#[allow(clippy::too_many_arguments)]
+pub fn create_plot_nft_launch_bundle(
+    coins: &[SpendableCoin],
+    initial_state: &PoolState,
+    delay_time: u64,
+    delay_puzzle_hash: &Bytes32,
+    genesis_challenge: &Bytes32,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<(Bytes32, SpendBundle), Box<dyn Error>> {
+    if initial_state.state == PoolSingletonState::LeavingPool {
+        return Err("a plot NFT cannot be launched leaving a pool".into());
+    }
+    let origin = coins
+        .first()
+        .ok_or("no coins to launch the plot NFT from")?;
+    let launcher_id = generate_launcher_coin(&origin.coin, PLOT_NFT_AMOUNT).name();
+    let inner_puzzle = pool_state_to_inner_puzzle(
+        initial_state,
+        &launcher_id,
+        genesis_challenge,
+        delay_time,
+        delay_puzzle_hash,
+    );
+    let extra_data = Program::from(vec![
+        Program::from(b"p".to_vec()).cons(&Program::from(initial_state.to_bytes())),
+        Program::from(b"t".to_vec()).cons(&int_program(delay_time)),
+        Program::from(b"h".to_vec()).cons(&Program::from(delay_puzzle_hash.bytes.clone())),
+    ]);
+    create_launch_bundle_for_puzzle_hash(
+        coins,
+        &pool_singleton_puzzle_hash(&launcher_id, &inner_puzzle.tree_hash()),
+        &extra_data,
+        PLOT_NFT_AMOUNT,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+/// Moves the plot NFT towards `target`. A plot NFT farming to a pool first has to leave
+/// it: that spend only enters the waiting room, and `target` is reached by a second
+/// spend once `relative_lock_height` blocks have passed. Returns the spend, to be signed
+/// by the owner key, and the state it moves the plot NFT into.
+pub fn create_plot_nft_travel_spend(
+    plot_nft: &PlotNft,
+    target: &PoolState,
+    genesis_challenge: &Bytes32,
+) -> Result<(CoinSpend, PoolState), Box<dyn Error>> {
+    if target.state == PoolSingletonState::LeavingPool {
+        return Err("travel to a pool or to self-pooling, not to leaving".into());
+    }
+    if target.owner_pubkey != plot_nft.state.owner_pubkey {
+        return Err("a plot NFT cannot change its owner".into());
+    }
+    let mut plot_nft = plot_nft.clone();
+    let inner_puzzle = plot_nft.inner_puzzle(genesis_challenge);
+    let (next_state, inner_solution) = if is_pool_member_inner_puzzle(&inner_puzzle) {
+        let leaving = PoolState {
+            state: PoolSingletonState::LeavingPool,
+            ..plot_nft.state.clone()
+        };
+        let inner_solution = Program::from(vec![leaving.key_value_list(), int_program(0)]);
+        (leaving, inner_solution)
+    } else {
+        let destination = pool_state_to_inner_puzzle(
+            target,
+            &plot_nft.launcher_id(),
+            genesis_challenge,
+            plot_nft.delay_time,
+            &plot_nft.delay_puzzle_hash,
+        );
+        let inner_solution = Program::from(vec![
+            int_program(1),
+            target.key_value_list(),
+            Program::from(destination.tree_hash().bytes),
+        ]);
+        (target.clone(), inner_solution)
+    };
+    let coin_spend = plot_nft.spend(
+        &inner_puzzle,
+        &inner_solution,
+        next_state.clone(),
+        genesis_challenge,
+    )?;
+    Ok((coin_spend, next_state))
+}
+
+/// Absorbs pool `reward_coins` into the plot NFT, one singleton spend per reward, each
+/// paired with the spend of its reward coin. The bundle needs no signature.
+pub fn create_plot_nft_absorb_spends(
+    plot_nft: &PlotNft,
+    reward_coins: &[Coin],
+    genesis_challenge: &Bytes32,
+) -> Result<Vec<CoinSpend>, Box<dyn Error>> {
+    let mut plot_nft = plot_nft.clone();
+    let p2_singleton_puzzle = plot_nft.p2_singleton_puzzle();
+    let p2_singleton_puzzle_hash = p2_singleton_puzzle.tree_hash();
+    let mut coin_spends = Vec::new();
+    for reward_coin in reward_coins {
+        if reward_coin.puzzle_hash != p2_singleton_puzzle_hash {
+            return Err(format!("coin {} is not paid to this plot NFT", reward_coin.name()).into());
+        }
+        let height = pool_reward_height(genesis_challenge, reward_coin)
+            .ok_or_else(|| format!("coin {} is not a pool reward", reward_coin.name()))?;
+        let inner_puzzle = plot_nft.inner_puzzle(genesis_challenge);
+        let mut inner_solution = vec![int_program(reward_coin.amount), int_program(height as u64)];
+        if !is_pool_member_inner_puzzle(&inner_puzzle) {
+            inner_solution.insert(0, int_program(0));
+        }
+        let state = plot_nft.state.clone();
+        coin_spends.push(plot_nft.spend(
+            &inner_puzzle,
+            &Program::from(inner_solution),
+            state,
+            genesis_challenge,
+        )?);
+        coin_spends.push(CoinSpend {
+            coin: reward_coin.clone(),
+            puzzle_reveal: p2_singleton_puzzle.clone().into(),
+            solution: Program::from(vec![
+                Program::from(inner_puzzle.tree_hash().bytes),
+                Program::from(reward_coin.name().bytes),
+            ])
+            .into(),
+        });
+    }
+    Ok(coin_spends)
+}
+
+#[test]
+fn test_plot_nft_puzzles() {
+    let singleton_v1_0: Bytes32 =
+        "24e044101e57b3d8c908b8a38ad57848afd29d3eecc439dba45f4412df4954fd".into();
+    let pool_member: Bytes32 =
+        "a8490702e333ddd831a3ac9c22d0fa26d2bfeaf2d33608deb22f0e0123eb0494".into();
+    let pool_waitingroom: Bytes32 =
+        "a317541a765bf8375e1c6e7c13503d0d2cbf56cacad5182befe947e78e2c0307".into();
+    assert_eq!(*SINGLETON_TOP_LAYER_V1_0_MOD_HASH, singleton_v1_0);
+    assert_eq!(*POOL_MEMBER_INNERPUZ_MOD_HASH, pool_member);
+    assert_eq!(*POOL_WAITINGROOM_INNERPUZ_MOD_HASH, pool_waitingroom);
+
+    let launcher_id = Bytes32::from(vec![0x5a; 32]);
+    let inner_puzzle = Program::from(vec![Program::from(1), Program::from(vec![0x33; 8])]);
+    let puzzle = pool_singleton_puzzle(&launcher_id, &inner_puzzle);
+    assert_eq!(
+        pool_singleton_puzzle_hash(&launcher_id, &inner_puzzle.tree_hash()),
+        puzzle.tree_hash()
+    );
+    let (matched_id, matched_inner) = match_pool_singleton_puzzle(&puzzle).unwrap();
+    assert_eq!(matched_id, launcher_id);
+    assert_eq!(matched_inner, inner_puzzle);
+}
+
+#[test]
+fn test_pool_state_round_trip() {
+    let owner = Bytes48::from(vec![0x44; 48]);
+    let pool_puzzle_hash = Bytes32::from(vec![0x22; 32]);
+    for state in [
+        PoolState::self_pooling(&owner, &pool_puzzle_hash),
+        PoolState::farming_to_pool(&owner, &pool_puzzle_hash, "https://pool.example", 100).unwrap(),
+    ] {
+        let bytes = state.to_bytes();
+        assert_eq!(PoolState::from_bytes(&bytes).unwrap(), state);
+        assert_eq!(
+            pool_state_from_extra_data(&state.key_value_list()),
+            Some(state)
+        );
+    }
+    assert!(
+        PoolState::farming_to_pool(&owner, &pool_puzzle_hash, "https://pool.example", 5000)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_plot_nft_travel_and_absorb() {
+    use crate::blockchain::constants::MAINNET;
+
+    let genesis_challenge = MAINNET.genesis_challenge.clone();
+    let owner = Bytes48::from(vec![0x44; 48]);
+    let self_pooling = PoolState::self_pooling(&owner, &Bytes32::from(vec![0x11; 32]));
+    let launcher_coin = Coin {
+        parent_coin_info: Bytes32::from(vec![0x01; 32]),
+        puzzle_hash: SINGLETON_LAUNCHER_HASH.clone(),
+        amount: PLOT_NFT_AMOUNT,
+    };
+    let launcher_id = launcher_coin.name();
+    let delay_puzzle_hash = Bytes32::from(vec![0x33; 32]);
+    let inner_puzzle = pool_state_to_inner_puzzle(
+        &self_pooling,
+        &launcher_id,
+        &genesis_challenge,
+        604800,
+        &delay_puzzle_hash,
+    );
+    let plot_nft = PlotNft {
+        launcher_coin: launcher_coin.clone(),
+        delay_time: 604800,
+        delay_puzzle_hash,
+        state: self_pooling,
+        coin: Coin {
+            parent_coin_info: launcher_id.clone(),
+            puzzle_hash: pool_singleton_puzzle_hash(&launcher_id, &inner_puzzle.tree_hash()),
+            amount: PLOT_NFT_AMOUNT,
+        },
+        lineage_proof: LineageProof {
+            parent_name: Some(launcher_coin.parent_coin_info.clone()),
+            inner_puzzle_hash: None,
+            amount: Some(PLOT_NFT_AMOUNT),
+        },
+    };
+
+    let pool = PoolState::farming_to_pool(
+        &owner,
+        &Bytes32::from(vec![0x22; 32]),
+        "https://pool.example",
+        100,
+    )
+    .unwrap();
+    let (travel_spend, next_state) =
+        create_plot_nft_travel_spend(&plot_nft, &pool, &genesis_challenge).unwrap();
+    assert_eq!(next_state, pool);
+    assert_eq!(
+        solution_to_pool_state(&travel_spend).unwrap(),
+        Some(pool.clone())
+    );
+    let child = travel_spend
+        .additions()
+        .into_iter()
+        .find(|coin| coin.amount == 1)
+        .unwrap();
+    let pool_inner_puzzle = pool_state_to_inner_puzzle(
+        &pool,
+        &launcher_id,
+        &genesis_challenge,
+        604800,
+        &plot_nft.delay_puzzle_hash,
+    );
+    assert_eq!(
+        child.puzzle_hash,
+        pool_singleton_puzzle_hash(&launcher_id, &pool_inner_puzzle.tree_hash())
+    );
+
+    let pooling = PlotNft {
+        state: pool.clone(),
+        coin: child,
+        lineage_proof: LineageProof {
+            parent_name: Some(travel_spend.coin.parent_coin_info.clone()),
+            inner_puzzle_hash: Some(inner_puzzle.tree_hash()),
+            amount: Some(PLOT_NFT_AMOUNT),
+        },
+        ..plot_nft.clone()
+    };
+    let reward_coin = Coin {
+        parent_coin_info: pool_reward_parent_id(&genesis_challenge, 1000),
+        puzzle_hash: pooling.p2_singleton_puzzle().tree_hash(),
+        amount: 1_750_000_000_000,
+    };
+    let absorb_spends =
+        create_plot_nft_absorb_spends(&pooling, &[reward_coin.clone()], &genesis_challenge)
+            .unwrap();
+    assert_eq!(absorb_spends.len(), 2);
+    assert_eq!(solution_to_pool_state(&absorb_spends[0]).unwrap(), None);
+    let additions = absorb_spends[0].additions();
+    assert!(additions.iter().any(
+        |coin| coin.puzzle_hash == pool.target_puzzle_hash && coin.amount == reward_coin.amount
+    ));
+    assert!(additions
+        .iter()
+        .any(|coin| coin.puzzle_hash == pooling.coin.puzzle_hash));
+
+    let (leave_spend, leaving) =
+        create_plot_nft_travel_spend(&pooling, &plot_nft.state, &genesis_challenge).unwrap();
+    assert_eq!(leaving.state, PoolSingletonState::LeavingPool);
+    assert_eq!(solution_to_pool_state(&leave_spend).unwrap(), Some(leaving));
+}
+
+#[test]
+fn test_parse_plot_nft_launcher() {
+    use crate::blockchain::constants::MAINNET;
+    use crate::chia_wallet::test_utils::{standard_coin, test_secret_key};
+
+    let coin = standard_coin(&test_secret_key(8).public_key(), 0x18, 10);
+    let owner = Bytes48::from(vec![0x44; 48]);
+    let state = PoolState::self_pooling(&owner, &coin.coin.puzzle_hash);
+    let delay_puzzle_hash = Bytes32::from(vec![0x33; 32]);
+    let (launcher_id, spend_bundle) = create_plot_nft_launch_bundle(
+        &[coin],
+        &state,
+        DEFAULT_DELAY_TIME,
+        &delay_puzzle_hash,
+        &MAINNET.genesis_challenge,
+        0,
+        &delay_puzzle_hash,
+    )
+    .unwrap();
+    let launcher_spend = spend_bundle.coin_spends.last().unwrap().clone();
+    let plot_nft = parse_plot_nft(&[launcher_spend.clone()]).unwrap();
+    assert_eq!(plot_nft.launcher_id(), launcher_id);
+    assert_eq!(plot_nft.delay_puzzle_hash, delay_puzzle_hash);
+    assert_eq!(plot_nft.state, state);
+
+    // Hashes of any other length are rejected rather than padded or truncated.
+    let with_solution = |eve_puzzle_hash: Vec<u8>, delay_puzzle_hash: Vec<u8>| CoinSpend {
+        solution: Program::from(vec![
+            Program::from(eve_puzzle_hash),
+            int_program(PLOT_NFT_AMOUNT),
+            Program::from(vec![
+                Program::from(b"p".to_vec()).cons(&Program::from(state.to_bytes())),
+                Program::from(b"t".to_vec()).cons(&int_program(DEFAULT_DELAY_TIME)),
+                Program::from(b"h".to_vec()).cons(&Program::from(delay_puzzle_hash)),
+            ]),
+        ])
+        .into(),
+        ..launcher_spend.clone()
+    };
+    let eve_puzzle_hash = plot_nft.coin.puzzle_hash.bytes.clone();
+    assert!(parse_plot_nft(&[with_solution(eve_puzzle_hash.clone(), vec![0x33; 32])]).is_ok());
+    assert!(parse_plot_nft(&[with_solution(eve_puzzle_hash.clone(), vec![0x33; 31])]).is_err());
+    assert!(
+        parse_plot_nft(&[with_solution(eve_puzzle_hash[1..].to_vec(), vec![0x33; 32])]).is_err()
+    );
+}
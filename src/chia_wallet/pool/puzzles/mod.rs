@@ -0,0 +1,3 @@
+pub(crate) mod p2_singleton_or_delayed;
+pub(crate) mod pool_member_innerpuz;
+pub(crate) mod pool_waitingroom_innerpuz;
@@ -0,0 +1,17 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Pool rewards are paid to this puzzle. Curried with the singleton mod hash, launcher id,
+/// launcher puzzle hash, a delay in seconds and a delayed puzzle hash. The singleton
+/// absorbs a reward with `(singleton_inner_puzzle_hash my_id)`; after the delay anyone
+/// can send it to the delayed puzzle hash with `(amount ())`.
+pub static P2_SINGLETON_OR_DELAYED_STR: &str = "ff02ffff01ff02ffff03ff82017fffff01ff04ffff04ff38ffff04ffff0bffff02ff2effff04ff02ffff04ff05ffff04ff81bfffff04ffff02ff3effff04ff02ffff04ffff04ff05ffff04ff0bff178080ff80808080ff808080808080ff82017f80ff808080ffff04ffff04ff3cffff01ff248080ffff04ffff04ff28ffff04ff82017fff808080ff80808080ffff01ff04ffff04ff24ffff04ff2fff808080ffff04ffff04ff2cffff04ff5fffff04ff81bfff80808080ffff04ffff04ff10ffff04ff81bfff808080ff8080808080ff0180ffff04ffff01ffffff49ff463fffff5002ff333cffff04ff0101ffff02ff02ffff03ff05ffff01ff02ff36ffff04ff02ffff04ff0dffff04ffff0bff26ffff0bff2aff1280ffff0bff26ffff0bff26ffff0bff2aff3a80ff0980ffff0bff26ff0bffff0bff2aff8080808080ff8080808080ffff010b80ff0180ffff0bff26ffff0bff2aff3480ffff0bff26ffff0bff26ffff0bff2aff3a80ff0580ffff0bff26ffff02ff36ffff04ff02ffff04ff07ffff04ffff0bff2aff2a80ff8080808080ffff0bff2aff8080808080ff02ffff03ffff07ff0580ffff01ff0bffff0102ffff02ff3effff04ff02ffff04ff09ff80808080ffff02ff3effff04ff02ffff04ff0dff8080808080ffff01ff0bffff0101ff058080ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref P2_SINGLETON_OR_DELAYED_MOD: Program = {
+        SerializedProgram::from_hex(P2_SINGLETON_OR_DELAYED_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref P2_SINGLETON_OR_DELAYED_MOD_HASH: Bytes32 = P2_SINGLETON_OR_DELAYED_MOD.tree_hash();
+}
@@ -0,0 +1,17 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Inner puzzle of a plot NFT farming to a pool. Curried with the pool puzzle hash, the
+/// p2 singleton puzzle hash, the owner public key, the pool reward prefix and the waiting
+/// room inner puzzle hash. Its solution either absorbs a reward, `(amount height)`, or
+/// travels to the waiting room, `(key_value_list 0)`.
+pub static POOL_MEMBER_INNERPUZ_STR: &str = "ff02ffff01ff02ffff03ff8202ffffff01ff02ff16ffff04ff02ffff04ff05ffff04ff8204bfffff04ff8206bfffff04ff82017fffff04ffff0bffff19ff2fffff18ffff019100ffffffffffffffffffffffffffffffffff8202ff8080ff0bff82017f80ff8080808080808080ffff01ff04ffff04ff08ffff04ff17ffff04ffff02ff1effff04ff02ffff04ff82017fff80808080ff80808080ffff04ffff04ff1cffff04ff5fffff04ff8206bfff80808080ff80808080ff0180ffff04ffff01ffff32ff3d33ff3effff04ffff04ff1cffff04ff0bffff04ff17ff80808080ffff04ffff04ff1cffff04ff05ffff04ff2fff80808080ffff04ffff04ff0affff04ff5fff808080ffff04ffff04ff14ffff04ffff0bff5fffff012480ff808080ff8080808080ff02ffff03ffff07ff0580ffff01ff0bffff0102ffff02ff1effff04ff02ffff04ff09ff80808080ffff02ff1effff04ff02ffff04ff0dff8080808080ffff01ff0bffff0101ff058080ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref POOL_MEMBER_INNERPUZ_MOD: Program = {
+        SerializedProgram::from_hex(POOL_MEMBER_INNERPUZ_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref POOL_MEMBER_INNERPUZ_MOD_HASH: Bytes32 = POOL_MEMBER_INNERPUZ_MOD.tree_hash();
+}
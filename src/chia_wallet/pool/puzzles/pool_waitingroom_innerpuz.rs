@@ -0,0 +1,17 @@
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::program_utils::{program::Program, serialized_program::SerializedProgram};
+
+/// Inner puzzle of a plot NFT that is self-pooling or leaving a pool. Curried like the
+/// pool member puzzle, with the relative lock height in place of the waiting room hash.
+/// Its solution is `(0 amount height)` to absorb a reward or
+/// `(1 key_value_list destination_inner_puzzle_hash)` to travel.
+pub static POOL_WAITINGROOM_INNERPUZ_STR: &str = "ff02ffff01ff02ffff03ff82017fffff01ff04ffff04ff1cffff04ff5fff808080ffff04ffff04ff12ffff04ff8205ffffff04ff8206bfff80808080ffff04ffff04ff08ffff04ff17ffff04ffff02ff1effff04ff02ffff04ffff04ff8205ffffff04ff8202ffff808080ff80808080ff80808080ff80808080ffff01ff02ff16ffff04ff02ffff04ff05ffff04ff8204bfffff04ff8206bfffff04ff8202ffffff04ffff0bffff19ff2fffff18ffff019100ffffffffffffffffffffffffffffffffff8205ff8080ff0bff8202ff80ff808080808080808080ff0180ffff04ffff01ffff32ff3d52ffff333effff04ffff04ff12ffff04ff0bffff04ff17ff80808080ffff04ffff04ff12ffff04ff05ffff04ff2fff80808080ffff04ffff04ff1affff04ff5fff808080ffff04ffff04ff14ffff04ffff0bff5fffff012480ff808080ff8080808080ff02ffff03ffff07ff0580ffff01ff0bffff0102ffff02ff1effff04ff02ffff04ff09ff80808080ffff02ff1effff04ff02ffff04ff0dff8080808080ffff01ff0bffff0101ff058080ff0180ff018080";
+
+lazy_static::lazy_static! {
+    pub static ref POOL_WAITINGROOM_INNERPUZ_MOD: Program = {
+        SerializedProgram::from_hex(POOL_WAITINGROOM_INNERPUZ_STR.to_string())
+            .to_program()
+            .unwrap()
+    };
+    pub static ref POOL_WAITINGROOM_INNERPUZ_MOD_HASH: Bytes32 = POOL_WAITINGROOM_INNERPUZ_MOD.tree_hash();
+}
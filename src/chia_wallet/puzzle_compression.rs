@@ -0,0 +1,226 @@
+use std::error::Error;
+
+use flate2::{Compress, Compression, Decompress, FlushCompress, FlushDecompress, Status};
+
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::cat::puzzles::cat_puzzle_program::{CAT1_MOD, CAT_MOD};
+use crate::chia_wallet::nft::puzzles::nft_metadata_updater_default::NFT_METADATA_UPDATER_MOD;
+use crate::chia_wallet::nft::puzzles::nft_ownership_layer::NFT_OWNERSHIP_LAYER_MOD;
+use crate::chia_wallet::nft::puzzles::nft_ownership_transfer_program_one_way_claim_with_royalties::NFT_TRANSFER_PROGRAM_MOD;
+use crate::chia_wallet::nft::puzzles::nft_state_layer::NFT_STATE_LAYER_MOD;
+use crate::chia_wallet::offer::puzzles::settlement_payments::{
+    SETTLEMENT_PAYMENTS_MOD, SETTLEMENT_PAYMENTS_V1_MOD,
+};
+use crate::chia_wallet::singleton::puzzles::singleton_top_layer_v1_1::SINGLETON_TOP_LAYER_MOD;
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::p2_delegated_puzzle_or_hidden_puzzle_program;
+use crate::program_utils::program::Program;
+
+lazy_static::lazy_static! {
+    /// The puzzles each dictionary version adds, in the reference wallet's order. The
+    /// dictionary for version `n` is the first `n` entries joined together.
+    static ref ZDICT: Vec<Vec<u8>> = {
+        let join = |puzzles: Vec<Program>| -> Vec<u8> {
+            puzzles.into_iter().flat_map(|puzzle| puzzle.serialized).collect()
+        };
+        vec![
+            join(vec![p2_delegated_puzzle_or_hidden_puzzle_program(), CAT1_MOD.clone()]),
+            join(vec![
+                SETTLEMENT_PAYMENTS_V1_MOD.clone(),
+                SINGLETON_TOP_LAYER_MOD.clone(),
+                NFT_STATE_LAYER_MOD.clone(),
+                NFT_OWNERSHIP_LAYER_MOD.clone(),
+                NFT_METADATA_UPDATER_MOD.clone(),
+                NFT_TRANSFER_PROGRAM_MOD.clone(),
+            ]),
+            join(vec![CAT_MOD.clone(), SETTLEMENT_PAYMENTS_MOD.clone()]),
+            // Empty on purpose: offers are compressed with at least this version (see
+            // `MINIMUM_OFFER_VERSION`), so older wallets reject them rather than
+            // misread them. `lowest_best_version` never picks it by itself.
+            Vec::new(),
+        ]
+    };
+}
+
+pub const LATEST_VERSION: u16 = 4;
+
+/// Version 0 compresses without a dictionary.
+pub fn zdict_for_version(version: u16) -> Result<Vec<u8>, Box<dyn Error>> {
+    if version > LATEST_VERSION {
+        return Err(format!("unsupported compression version {}", version).into());
+    }
+    Ok(ZDICT[..version as usize].concat())
+}
+
+/// zlib at the default level with `zdict` preset, byte for byte what Python's
+/// `zlib.compressobj(zdict=...)` produces.
+pub fn compress_with_zdict(blob: &[u8], zdict: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut compress = Compress::new(Compression::default(), true);
+    if !zdict.is_empty() {
+        compress.set_dictionary(zdict)?;
+    }
+    let mut compressed = Vec::with_capacity(blob.len() / 2 + 64);
+    loop {
+        let consumed = compress.total_in() as usize;
+        match compress.compress_vec(&blob[consumed..], &mut compressed, FlushCompress::Finish)? {
+            Status::StreamEnd => return Ok(compressed),
+            Status::Ok | Status::BufError => compressed.reserve(compressed.capacity().max(64)),
+        }
+    }
+}
+
+pub fn decompress_with_zdict(blob: &[u8], zdict: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut decompress = Decompress::new(true);
+    let mut decompressed = Vec::with_capacity(blob.len() * 3);
+    loop {
+        let consumed = decompress.total_in() as usize;
+        let status = decompress.decompress_vec(
+            &blob[consumed..],
+            &mut decompressed,
+            FlushDecompress::Finish,
+        );
+        match status {
+            Ok(Status::StreamEnd) => return Ok(decompressed),
+            Ok(Status::BufError) if consumed == blob.len() => {
+                return Err("compressed data ends early".into())
+            }
+            Ok(_) => decompressed.reserve(decompressed.capacity().max(64)),
+            // zlib asks for the dictionary once it has read the header.
+            Err(e) if e.needs_dictionary().is_some() => {
+                decompress.set_dictionary(zdict)?;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// A big-endian u16 version followed by the data compressed with that version's
+/// dictionary.
+pub fn compress_object_with_puzzles(
+    object_bytes: &[u8],
+    version: u16,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let zdict = zdict_for_version(version)?;
+    let mut compressed = version.to_be_bytes().to_vec();
+    compressed.extend(compress_with_zdict(object_bytes, &zdict)?);
+    Ok(compressed)
+}
+
+pub fn decompress_object_with_puzzles(compressed: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    if compressed.len() < 2 {
+        return Err("compressed data has no version".into());
+    }
+    let version = u16::from_be_bytes([compressed[0], compressed[1]]);
+    decompress_with_zdict(&compressed[2..], &zdict_for_version(version)?)
+}
+
+/// The lowest version whose dictionary holds every puzzle in `puzzles` that any
+/// dictionary holds. Never lower than 1.
+pub fn lowest_best_version(puzzles: &[Vec<u8>]) -> u16 {
+    let mut best = 1;
+    for puzzle in puzzles {
+        if puzzle.is_empty() {
+            continue;
+        }
+        for (index, zdict) in ZDICT.iter().enumerate() {
+            if zdict
+                .windows(puzzle.len())
+                .any(|window| window == puzzle.as_slice())
+            {
+                best = best.max(index as u16 + 1);
+                break;
+            }
+        }
+    }
+    best
+}
+
+/// The outer mods of every puzzle reveal in `spend_bundle`.
+pub fn spend_bundle_mods(spend_bundle: &SpendBundle) -> Vec<Vec<u8>> {
+    spend_bundle
+        .coin_spends
+        .iter()
+        .map(|coin_spend| {
+            let puzzle = Program::new(coin_spend.puzzle_reveal.to_bytes());
+            match puzzle.try_uncurry() {
+                Some(uncurried) => uncurried.program.serialized,
+                None => puzzle.serialized,
+            }
+        })
+        .collect()
+}
+
+/// Compresses the streamable bytes of `spend_bundle`, with the lowest version that
+/// covers its puzzles unless `version` is given.
+pub fn compress_spend_bundle(
+    spend_bundle: &SpendBundle,
+    version: Option<u16>,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let version = version.unwrap_or_else(|| lowest_best_version(&spend_bundle_mods(spend_bundle)));
+    compress_object_with_puzzles(&spend_bundle.to_bytes(), version)
+}
+
+pub fn decompress_spend_bundle(compressed: &[u8]) -> Result<SpendBundle, Box<dyn Error>> {
+    SpendBundle::from_bytes(&decompress_object_with_puzzles(compressed)?)
+}
+
+/// `fixture_spend_bundle()` compressed with version 1: the two byte version followed by
+/// the output of CPython's `zlib.compressobj(zdict=...)` over the serialized bundle,
+/// which are the calls `compress_object_with_puzzles` makes in chia-blockchain's
+/// `chia/wallet/util/puzzle_compression.py`. Generated with CPython 3 and its bundled
+/// zlib at the default level, not taken from a chia-blockchain test.
+#[cfg(test)]
+static COMPRESSED_FIXTURE: &str =
+    "000178bb2342e8ea636060601424009408000630607e012bd78771d5d5708081b6000049ed8ecd";
+
+#[cfg(test)]
+fn fixture_spend_bundle() -> SpendBundle {
+    use crate::blockchain::coin::Coin;
+    use crate::blockchain::coin_spend::CoinSpend;
+    use crate::blockchain::sized_bytes::Bytes32;
+    use crate::chia_wallet::standart::transaction::empty_signature;
+
+    SpendBundle {
+        coin_spends: vec![CoinSpend {
+            coin: Coin {
+                parent_coin_info: Bytes32::from(vec![0x11; 32]),
+                puzzle_hash: Bytes32::from(vec![0x22; 32]),
+                amount: 1000,
+            },
+            puzzle_reveal: p2_delegated_puzzle_or_hidden_puzzle_program().into(),
+            solution: Program::from(Vec::<u8>::new()).into(),
+        }],
+        aggregated_signature: empty_signature(),
+    }
+}
+
+#[test]
+fn test_compression_matches_reference_zlib() {
+    use crate::blockchain::sized_bytes::Bytes32;
+    use crate::chia_wallet::cat::puzzles::cat_puzzle_program::CAT1_MOD_HASH_STR;
+
+    assert_eq!(CAT1_MOD.tree_hash(), Bytes32::from(CAT1_MOD_HASH_STR));
+    let spend_bundle = fixture_spend_bundle();
+    assert_eq!(lowest_best_version(&spend_bundle_mods(&spend_bundle)), 1);
+    // Python's zlib output for the same input (see `COMPRESSED_FIXTURE`).
+    let fixture = hex::decode(COMPRESSED_FIXTURE).unwrap();
+    assert_eq!(compress_spend_bundle(&spend_bundle, None).unwrap(), fixture);
+    assert_eq!(
+        decompress_spend_bundle(&fixture).unwrap().to_bytes(),
+        spend_bundle.to_bytes()
+    );
+}
+
+#[test]
+fn test_compression_round_trip_every_version() {
+    let spend_bundle = fixture_spend_bundle();
+    for version in 0..=LATEST_VERSION {
+        let compressed = compress_spend_bundle(&spend_bundle, Some(version)).unwrap();
+        assert_eq!(&compressed[..2], &version.to_be_bytes());
+        assert_eq!(
+            decompress_spend_bundle(&compressed).unwrap().to_bytes(),
+            spend_bundle.to_bytes()
+        );
+    }
+    assert!(compress_spend_bundle(&spend_bundle, Some(LATEST_VERSION + 1)).is_err());
+    assert_eq!(lowest_best_version(&[CAT_MOD.serialized.clone()]), 3);
+}
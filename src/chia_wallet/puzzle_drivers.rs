@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::chia_wallet::cat::puzzles::cat_puzzle_program::{CAT1_MOD, CAT_MOD};
+use crate::chia_wallet::did::puzzles::did_innerpuz::DID_INNERPUZ_MOD_HASH;
+use crate::chia_wallet::nft::puzzles::nft_ownership_layer::NFT_OWNERSHIP_LAYER_MOD_HASH;
+use crate::chia_wallet::nft::puzzles::nft_ownership_transfer_program_one_way_claim_with_royalties::NFT_TRANSFER_PROGRAM_MOD_HASH;
+use crate::chia_wallet::nft::puzzles::nft_state_layer::NFT_STATE_LAYER_MOD_HASH;
+use crate::chia_wallet::offer::puzzles::settlement_payments::{
+    SETTLEMENT_PAYMENTS_MOD_HASH, SETTLEMENT_PAYMENTS_V1_MOD,
+};
+use crate::chia_wallet::pool::puzzles::pool_member_innerpuz::POOL_MEMBER_INNERPUZ_MOD_HASH;
+use crate::chia_wallet::pool::puzzles::pool_waitingroom_innerpuz::POOL_WAITINGROOM_INNERPUZ_MOD_HASH;
+use crate::chia_wallet::singleton::puzzles::singleton_launcher::SINGLETON_LAUNCHER_HASH;
+use crate::chia_wallet::singleton::puzzles::singleton_top_layer_v1_0::SINGLETON_TOP_LAYER_V1_0_MOD_HASH;
+use crate::chia_wallet::singleton::puzzles::singleton_top_layer_v1_1::SINGLETON_TOP_LAYER_MOD_HASH;
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::p2_delegated_puzzle_or_hidden_puzzle_program;
+use crate::program_utils::program::Program;
+
+/// One layer of a puzzle: the driver that recognised it, its curried parameters and
+/// the layer it wraps.
+#[derive(Clone, Debug)]
+pub struct PuzzleLayer {
+    pub name: String,
+    /// Hash of the uncurried mod, or of the whole puzzle when it is not curried.
+    pub mod_hash: Bytes32,
+    pub params: Vec<(String, Program)>,
+    pub inner: Option<Box<PuzzleLayer>>,
+}
+
+impl PuzzleLayer {
+    pub fn param(&self, name: &str) -> Option<&Program> {
+        self.params
+            .iter()
+            .find(|(param_name, _)| param_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// The layer names from the outermost in, e.g. `["singleton", "nft_state_layer", …]`.
+    pub fn names(&self) -> Vec<String> {
+        let mut names = vec![self.name.clone()];
+        if let Some(inner) = &self.inner {
+            names.extend(inner.names());
+        }
+        names
+    }
+}
+
+/// Recognises the puzzles built from one mod.
+pub trait PuzzleDriver: Send + Sync {
+    fn name(&self) -> String;
+
+    fn mod_hash(&self) -> Bytes32;
+
+    /// Mods that are not curried are matched on the whole puzzle hash.
+    fn is_curried(&self) -> bool {
+        true
+    }
+
+    /// Names the curried `args` and picks out the inner puzzle, if the layer has one.
+    /// Returning `None` rejects the match.
+    fn parse_args(&self, args: &[Program]) -> Option<(Vec<(String, Program)>, Option<Program>)>;
+}
+
+/// A driver described only by the names of its curried arguments.
+pub struct CurriedArgsDriver {
+    pub name: String,
+    pub mod_hash: Bytes32,
+    pub arg_names: Vec<String>,
+    /// Index of the inner puzzle in `arg_names`.
+    pub inner_puzzle_arg: Option<usize>,
+}
+
+impl CurriedArgsDriver {
+    pub fn new(
+        name: &str,
+        mod_hash: &Bytes32,
+        arg_names: &[&str],
+        inner_puzzle_arg: Option<usize>,
+    ) -> Self {
+        CurriedArgsDriver {
+            name: name.to_string(),
+            mod_hash: mod_hash.clone(),
+            arg_names: arg_names.iter().map(|arg| arg.to_string()).collect(),
+            inner_puzzle_arg,
+        }
+    }
+}
+
+impl PuzzleDriver for CurriedArgsDriver {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn mod_hash(&self) -> Bytes32 {
+        self.mod_hash.clone()
+    }
+
+    fn is_curried(&self) -> bool {
+        !self.arg_names.is_empty()
+    }
+
+    fn parse_args(&self, args: &[Program]) -> Option<(Vec<(String, Program)>, Option<Program>)> {
+        if args.len() != self.arg_names.len() {
+            return None;
+        }
+        let params = self
+            .arg_names
+            .iter()
+            .cloned()
+            .zip(args.iter().cloned())
+            .collect();
+        let inner_puzzle = self.inner_puzzle_arg.map(|index| args[index].clone());
+        Some((params, inner_puzzle))
+    }
+}
+
+/// Reports the launcher id rather than the whole singleton struct. v1.0 is still
+/// the top layer of plot NFTs; everything newer uses v1.1.
+struct SingletonDriver {
+    name: &'static str,
+    mod_hash: Bytes32,
+}
+
+impl PuzzleDriver for SingletonDriver {
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn mod_hash(&self) -> Bytes32 {
+        self.mod_hash.clone()
+    }
+
+    fn parse_args(&self, args: &[Program]) -> Option<(Vec<(String, Program)>, Option<Program>)> {
+        if args.len() != 2 {
+            return None;
+        }
+        let launcher_id = args[0].rest().ok()?.first().ok()?;
+        if launcher_id.as_vec()?.len() != 32 {
+            return None;
+        }
+        let params = vec![("launcher_id".to_string(), launcher_id)];
+        Some((params, Some(args[1].clone())))
+    }
+}
+
+/// Drivers by mod hash. Registering a driver for a known mod hash replaces the old one.
+pub struct DriverRegistry {
+    drivers: HashMap<Bytes32, Box<dyn PuzzleDriver>>,
+}
+
+impl DriverRegistry {
+    pub fn empty() -> Self {
+        DriverRegistry {
+            drivers: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, driver: Box<dyn PuzzleDriver>) {
+        self.drivers.insert(driver.mod_hash(), driver);
+    }
+
+    /// Peels `puzzle` layer by layer. The innermost layer no driver recognises is
+    /// reported as "unknown" with its puzzle hash.
+    pub fn identify(&self, puzzle: &Program) -> PuzzleLayer {
+        let puzzle_hash = puzzle.tree_hash();
+        if let Some(driver) = self.drivers.get(&puzzle_hash) {
+            if !driver.is_curried() {
+                return PuzzleLayer {
+                    name: driver.name(),
+                    mod_hash: puzzle_hash,
+                    params: Vec::new(),
+                    inner: None,
+                };
+            }
+        }
+        if let Some(uncurried) = puzzle.try_uncurry() {
+            let mod_hash = uncurried.program.tree_hash();
+            if let Some(driver) = self.drivers.get(&mod_hash) {
+                if driver.is_curried() {
+                    if let Some((params, inner_puzzle)) = driver.parse_args(&uncurried.args) {
+                        return PuzzleLayer {
+                            name: driver.name(),
+                            mod_hash,
+                            params,
+                            inner: inner_puzzle.map(|inner| Box::new(self.identify(&inner))),
+                        };
+                    }
+                }
+            }
+        }
+        PuzzleLayer {
+            name: "unknown".to_string(),
+            mod_hash: puzzle_hash,
+            params: Vec::new(),
+            inner: None,
+        }
+    }
+
+    pub fn identify_coin_spend(&self, coin_spend: &CoinSpend) -> PuzzleLayer {
+        self.identify(&Program::new(coin_spend.puzzle_reveal.to_bytes()))
+    }
+}
+
+impl Default for DriverRegistry {
+    /// The standard, CAT, singleton, NFT, DID, plot NFT and settlement puzzles.
+    fn default() -> Self {
+        let mut registry = DriverRegistry::empty();
+        let drivers: Vec<Box<dyn PuzzleDriver>> = vec![
+            Box::new(CurriedArgsDriver::new(
+                "standard",
+                &p2_delegated_puzzle_or_hidden_puzzle_program().tree_hash(),
+                &["synthetic_public_key"],
+                None,
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "cat",
+                &CAT_MOD.tree_hash(),
+                &["mod_hash", "tail_hash", "inner_puzzle"],
+                Some(2),
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "cat_v1",
+                &CAT1_MOD.tree_hash(),
+                &["mod_hash", "tail_hash", "inner_puzzle"],
+                Some(2),
+            )),
+            Box::new(SingletonDriver {
+                name: "singleton",
+                mod_hash: SINGLETON_TOP_LAYER_MOD_HASH.clone(),
+            }),
+            Box::new(SingletonDriver {
+                name: "singleton_v1_0",
+                mod_hash: SINGLETON_TOP_LAYER_V1_0_MOD_HASH.clone(),
+            }),
+            Box::new(CurriedArgsDriver::new(
+                "singleton_launcher",
+                &SINGLETON_LAUNCHER_HASH,
+                &[],
+                None,
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "nft_state_layer",
+                &NFT_STATE_LAYER_MOD_HASH,
+                &[
+                    "mod_hash",
+                    "metadata",
+                    "metadata_updater_puzzle_hash",
+                    "inner_puzzle",
+                ],
+                Some(3),
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "nft_ownership_layer",
+                &NFT_OWNERSHIP_LAYER_MOD_HASH,
+                &[
+                    "mod_hash",
+                    "current_owner",
+                    "transfer_program",
+                    "inner_puzzle",
+                ],
+                Some(3),
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "nft_transfer_program",
+                &NFT_TRANSFER_PROGRAM_MOD_HASH,
+                &[
+                    "singleton_struct",
+                    "royalty_puzzle_hash",
+                    "royalty_percentage",
+                ],
+                None,
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "did",
+                &DID_INNERPUZ_MOD_HASH,
+                &[
+                    "p2_puzzle",
+                    "recovery_list_hash",
+                    "num_verifications_required",
+                    "singleton_struct",
+                    "metadata",
+                ],
+                Some(0),
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "pool_waitingroom",
+                &POOL_WAITINGROOM_INNERPUZ_MOD_HASH,
+                &[
+                    "target_puzzle_hash",
+                    "p2_singleton_puzzle_hash",
+                    "owner_pubkey",
+                    "pool_reward_prefix",
+                    "relative_lock_height",
+                ],
+                None,
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "pool_member",
+                &POOL_MEMBER_INNERPUZ_MOD_HASH,
+                &[
+                    "target_puzzle_hash",
+                    "p2_singleton_puzzle_hash",
+                    "owner_pubkey",
+                    "pool_reward_prefix",
+                    "escape_puzzle_hash",
+                ],
+                None,
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "settlement_payments",
+                &SETTLEMENT_PAYMENTS_MOD_HASH,
+                &[],
+                None,
+            )),
+            Box::new(CurriedArgsDriver::new(
+                "settlement_payments_v1",
+                &SETTLEMENT_PAYMENTS_V1_MOD.tree_hash(),
+                &[],
+                None,
+            )),
+        ];
+        for driver in drivers {
+            registry.register(driver);
+        }
+        registry
+    }
+}
+
+#[test]
+fn test_identify_layers() {
+    use crate::chia_wallet::cat::puzzles::create_cat_outer_puzzle::construct_cat_puzzle;
+    use crate::chia_wallet::offer::puzzles::settlement_payments::SETTLEMENT_PAYMENTS_MOD;
+
+    let registry = DriverRegistry::default();
+    let synthetic_key = Program::from(vec![0x44; 48]);
+    let standard =
+        p2_delegated_puzzle_or_hidden_puzzle_program().curry(vec![synthetic_key.clone()]);
+    let tail_hash = Bytes32::from(vec![0x11; 32]);
+    let layer = registry.identify(&construct_cat_puzzle(&tail_hash, &standard));
+    assert_eq!(layer.names(), vec!["cat", "standard"]);
+    assert_eq!(
+        layer.param("tail_hash").and_then(|tail| tail.as_vec()),
+        Some(tail_hash.bytes.clone())
+    );
+    assert_eq!(
+        layer.inner.unwrap().param("synthetic_public_key"),
+        Some(&synthetic_key)
+    );
+
+    let settlement = construct_cat_puzzle(&tail_hash, &SETTLEMENT_PAYMENTS_MOD);
+    assert_eq!(
+        registry.identify(&settlement).names(),
+        vec!["cat", "settlement_payments"]
+    );
+
+    // A third-party mod is unknown until its driver is registered.
+    let custom_mod = Program::from(vec![Program::from(1), Program::from(vec![0x55; 4])]);
+    let custom = construct_cat_puzzle(&tail_hash, &custom_mod.curry(vec![standard.clone()]));
+    assert_eq!(registry.identify(&custom).names(), vec!["cat", "unknown"]);
+    let mut registry = registry;
+    registry.register(Box::new(CurriedArgsDriver::new(
+        "custom",
+        &custom_mod.tree_hash(),
+        &["inner_puzzle"],
+        Some(0),
+    )));
+    assert_eq!(
+        registry.identify(&custom).names(),
+        vec!["cat", "custom", "standard"]
+    );
+}
+
+#[test]
+fn test_identify_plot_nft() {
+    use crate::blockchain::constants::MAINNET;
+    use crate::blockchain::sized_bytes::Bytes48;
+    use crate::chia_wallet::pool::pool_utils::{
+        pool_singleton_puzzle, pool_state_to_inner_puzzle, PoolState, DEFAULT_DELAY_TIME,
+    };
+    use crate::chia_wallet::singleton::puzzles::singleton_top_layer_v1_1::SINGLETON_TOP_LAYER_MOD;
+
+    let registry = DriverRegistry::default();
+    let launcher_id = Bytes32::from(vec![0x5a; 32]);
+    let owner = Bytes48::from(vec![0x66; 48]);
+    let target = Bytes32::from(vec![0x77; 32]);
+    let inner_puzzle = pool_state_to_inner_puzzle(
+        &PoolState::self_pooling(&owner, &target),
+        &launcher_id,
+        &MAINNET.genesis_challenge,
+        DEFAULT_DELAY_TIME,
+        &target,
+    );
+    let layer = registry.identify(&pool_singleton_puzzle(&launcher_id, &inner_puzzle));
+    assert_eq!(layer.names(), vec!["singleton_v1_0", "pool_waitingroom"]);
+    assert_eq!(
+        layer.param("launcher_id").and_then(|id| id.as_vec()),
+        Some(launcher_id.bytes.clone())
+    );
+
+    // A singleton struct whose launcher id is not 32 bytes is not a singleton.
+    let short_id = Program::from(SINGLETON_TOP_LAYER_MOD_HASH.bytes.clone())
+        .cons(&Program::from(vec![0x5a; 31]).cons(&Program::from(vec![0x33; 32])));
+    let crafted = SINGLETON_TOP_LAYER_MOD.curry(vec![short_id, inner_puzzle]);
+    assert_eq!(registry.identify(&crafted).names(), vec!["unknown"]);
+}
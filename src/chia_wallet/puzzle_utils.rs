@@ -0,0 +1,83 @@
+use num_bigint::BigInt;
+
+use crate::blockchain::condition_opcode::ConditionOpcode;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::utils::int_to_bytes;
+use crate::program_utils::program::Program;
+
+pub fn int_program(value: u64) -> Program {
+    signed_int_program(&BigInt::from(value))
+}
+
+pub fn signed_int_program(value: &BigInt) -> Program {
+    Program::from(int_to_bytes(value))
+}
+
+/// The value of an atom that must hold a 32-byte hash, or `None` for any other program.
+pub fn bytes32_atom(program: &Program) -> Option<Bytes32> {
+    Bytes32::try_from(program.as_vec()?.as_slice()).ok()
+}
+
+pub fn make_condition(opcode: ConditionOpcode, args: Vec<Program>) -> Program {
+    let mut condition = vec![Program::from(vec![opcode as u8])];
+    condition.extend(args);
+    Program::from(condition)
+}
+
+pub fn make_create_coin_condition(
+    puzzle_hash: &Bytes32,
+    amount: u64,
+    memos: &[Vec<u8>],
+) -> Program {
+    let mut args = vec![
+        Program::from(puzzle_hash.bytes.clone()),
+        int_program(amount),
+    ];
+    if !memos.is_empty() {
+        let memos: Vec<Program> = memos
+            .iter()
+            .map(|memo| Program::from(memo.clone()))
+            .collect();
+        args.push(Program::from(memos));
+    }
+    make_condition(ConditionOpcode::CreateCoin, args)
+}
+
+pub fn make_reserve_fee_condition(fee: u64) -> Program {
+    make_condition(ConditionOpcode::ReserveFee, vec![int_program(fee)])
+}
+
+pub fn make_create_coin_announcement(message: &[u8]) -> Program {
+    make_condition(
+        ConditionOpcode::CreateCoinAnnouncement,
+        vec![Program::from(message.to_vec())],
+    )
+}
+
+pub fn make_assert_coin_announcement(announcement_id: &Bytes32) -> Program {
+    make_condition(
+        ConditionOpcode::AssertCoinAnnouncement,
+        vec![Program::from(announcement_id.bytes.clone())],
+    )
+}
+
+pub fn make_create_puzzle_announcement(message: &[u8]) -> Program {
+    make_condition(
+        ConditionOpcode::CreatePuzzleAnnouncement,
+        vec![Program::from(message.to_vec())],
+    )
+}
+
+pub fn make_assert_puzzle_announcement(announcement_id: &Bytes32) -> Program {
+    make_condition(
+        ConditionOpcode::AssertPuzzleAnnouncement,
+        vec![Program::from(announcement_id.bytes.clone())],
+    )
+}
+
+pub fn make_assert_my_coin_id_condition(coin_name: &Bytes32) -> Program {
+    make_condition(
+        ConditionOpcode::AssertMyCoinId,
+        vec![Program::from(coin_name.bytes.clone())],
+    )
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use chia_bls::secret_key::SecretKey;
+use chia_bls::signature::{aggregate, sign};
+
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::constants::Constants;
+use crate::blockchain::sized_bytes::{Bytes48, Bytes96};
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    calculate_synthetic_secret_key, default_hidden_puzzle,
+};
+use crate::program_utils::condition_utils::pkm_pairs;
+
+/// Maps every public key the given secret keys can sign for, including their
+/// synthetic keys, to the matching secret key.
+pub fn secret_keys_by_public_key(secret_keys: &[SecretKey]) -> HashMap<Bytes48, SecretKey> {
+    let hidden_puzzle_hash = default_hidden_puzzle().tree_hash();
+    let mut keys = HashMap::new();
+    for sk in secret_keys {
+        let synthetic_sk = calculate_synthetic_secret_key(sk, &hidden_puzzle_hash);
+        for key in [sk.clone(), synthetic_sk] {
+            keys.insert(Bytes48::from(key.public_key().to_bytes().to_vec()), key);
+        }
+    }
+    keys
+}
+
+/// Signs every AGG_SIG condition of `coin_spends` and returns the resulting bundle.
+/// Fails if a required public key has no matching secret key.
+pub fn sign_coin_spends(
+    coin_spends: Vec<CoinSpend>,
+    secret_keys: &[SecretKey],
+    constants: &Constants,
+    max_cost: u64,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let keys = secret_keys_by_public_key(secret_keys);
+    let pairs = pkm_pairs(&coin_spends, &constants.genesis_challenge.bytes, max_cost)?;
+    let mut signatures = Vec::new();
+    for (pk, msg) in pairs {
+        match keys.get(&pk) {
+            Some(sk) => signatures.push(sign(sk, &msg)),
+            None => return Err(format!("no secret key for public key {}", pk).into()),
+        }
+    }
+    let aggregated_signature = aggregate(&signatures);
+    Ok(SpendBundle {
+        coin_spends,
+        aggregated_signature: Bytes96::from(aggregated_signature.to_bytes().to_vec()),
+    })
+}
+
+#[test]
+fn test_sign_standard_spend() {
+    use crate::blockchain::constants::MAINNET;
+    use crate::blockchain::sized_bytes::Bytes32;
+    use crate::chia_wallet::puzzle_utils::make_create_coin_condition;
+    use crate::chia_wallet::test_utils::{standard_coin, standard_coin_spend, test_secret_key};
+    use crate::program_utils::bls_bindings::aggregate_verify_signature;
+    use crate::program_utils::program::Program;
+    use crate::program_utils::utils::INFINITE_COST;
+
+    let sk = test_secret_key(3);
+    let conditions = Program::from(vec![make_create_coin_condition(
+        &Bytes32::from(vec![0xaa; 32]),
+        900,
+        &[],
+    )]);
+    let coin_spend = standard_coin_spend(&standard_coin(&sk.public_key(), 0x13, 1000), conditions);
+
+    // The standard puzzle asks for its synthetic key, which only the lookup knows.
+    let pairs = pkm_pairs(
+        &[coin_spend.clone()],
+        &MAINNET.genesis_challenge.bytes,
+        INFINITE_COST,
+    )
+    .unwrap();
+    assert_eq!(pairs.len(), 1);
+    assert_ne!(
+        pairs[0].0,
+        Bytes48::from(sk.public_key().to_bytes().to_vec())
+    );
+    assert!(secret_keys_by_public_key(&[sk.clone()]).contains_key(&pairs[0].0));
+
+    let spend_bundle =
+        sign_coin_spends(vec![coin_spend.clone()], &[sk], &MAINNET, INFINITE_COST).unwrap();
+    let public_keys: Vec<&Bytes48> = pairs.iter().map(|(pk, _)| pk).collect();
+    let messages: Vec<&[u8]> = pairs.iter().map(|(_, msg)| msg.as_slice()).collect();
+    assert!(aggregate_verify_signature(
+        &public_keys,
+        &messages,
+        &spend_bundle.aggregated_signature
+    ));
+
+    let other_sk = test_secret_key(4);
+    assert!(sign_coin_spends(vec![coin_spend], &[other_sk], &MAINNET, INFINITE_COST).is_err());
+}
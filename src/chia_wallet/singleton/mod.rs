@@ -0,0 +1,2 @@
+pub(crate) mod puzzles;
+pub(crate) mod singleton_utils;
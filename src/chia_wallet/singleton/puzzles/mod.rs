@@ -0,0 +1,3 @@
+pub(crate) mod singleton_launcher;
+pub(crate) mod singleton_top_layer_v1_0;
+pub(crate) mod singleton_top_layer_v1_1;
@@ -0,0 +1,331 @@
+use std::error::Error;
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::condition_opcode::ConditionOpcode;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::cat::puzzles::curry_and_treehash::{
+    atom_hash, curried_puzzle_hash, pair_hash,
+};
+use crate::chia_wallet::lineage_proof::LineageProof;
+use crate::chia_wallet::puzzle_utils::{int_program, make_condition};
+use crate::chia_wallet::standart::transaction::{
+    create_standard_spend_bundle_with_conditions, Payment, SpendableCoin,
+};
+use crate::program_utils::program::Program;
+use crate::program_utils::utils::hash_256;
+
+use super::puzzles::singleton_launcher::{SINGLETON_LAUNCHER, SINGLETON_LAUNCHER_HASH};
+use super::puzzles::singleton_top_layer_v1_1::{
+    SINGLETON_TOP_LAYER_MOD, SINGLETON_TOP_LAYER_MOD_HASH,
+};
+
+/// `(MOD_HASH . (LAUNCHER_ID . LAUNCHER_PUZZLE_HASH))`, the first curried argument.
+pub fn singleton_struct(launcher_id: &Bytes32) -> Program {
+    Program::from(SINGLETON_TOP_LAYER_MOD_HASH.bytes.clone()).cons(
+        &Program::from(launcher_id.bytes.clone())
+            .cons(&Program::from(SINGLETON_LAUNCHER_HASH.bytes.clone())),
+    )
+}
+
+pub fn puzzle_for_singleton(launcher_id: &Bytes32, inner_puzzle: &Program) -> Program {
+    SINGLETON_TOP_LAYER_MOD.curry(vec![singleton_struct(launcher_id), inner_puzzle.clone()])
+}
+
+/// Puzzle hash of a singleton, computed from its inner puzzle hash alone.
+pub fn singleton_puzzle_hash(launcher_id: &Bytes32, inner_puzzle_hash: &Bytes32) -> Bytes32 {
+    let struct_hash = pair_hash(
+        &atom_hash(&SINGLETON_TOP_LAYER_MOD_HASH.bytes),
+        &pair_hash(
+            &atom_hash(&launcher_id.bytes),
+            &atom_hash(&SINGLETON_LAUNCHER_HASH.bytes),
+        ),
+    );
+    curried_puzzle_hash(
+        &SINGLETON_TOP_LAYER_MOD_HASH,
+        &[struct_hash, inner_puzzle_hash.clone()],
+    )
+}
+
+/// The launcher created by `parent`. Its coin id is the singleton's launcher id.
+pub fn generate_launcher_coin(parent: &Coin, amount: u64) -> Coin {
+    Coin {
+        parent_coin_info: parent.name(),
+        puzzle_hash: SINGLETON_LAUNCHER_HASH.clone(),
+        amount,
+    }
+}
+
+/// Conditions the launcher's parent must include, and the launcher spend that
+/// creates the eve singleton with `inner_puzzle`.
+pub fn launch_conditions_and_coin_spend(
+    parent: &Coin,
+    inner_puzzle: &Program,
+    key_value_list: &Program,
+    amount: u64,
+) -> Result<(Vec<Program>, CoinSpend), Box<dyn Error>> {
+    let launcher_id = generate_launcher_coin(parent, amount).name();
+    let eve_puzzle_hash = singleton_puzzle_hash(&launcher_id, &inner_puzzle.tree_hash());
+    launch_conditions_and_coin_spend_for_puzzle_hash(parent, &eve_puzzle_hash, key_value_list, amount)
+}
+
+/// As `launch_conditions_and_coin_spend`, for an eve singleton known only by its full
+/// puzzle hash, e.g. one using another singleton top layer.
+pub fn launch_conditions_and_coin_spend_for_puzzle_hash(
+    parent: &Coin,
+    eve_puzzle_hash: &Bytes32,
+    key_value_list: &Program,
+    amount: u64,
+) -> Result<(Vec<Program>, CoinSpend), Box<dyn Error>> {
+    if amount % 2 == 0 {
+        return Err("singletons must have an odd amount".into());
+    }
+    let launcher_coin = generate_launcher_coin(parent, amount);
+    let launcher_id = launcher_coin.name();
+    let launcher_solution = Program::from(vec![
+        Program::from(eve_puzzle_hash.bytes.clone()),
+        int_program(amount),
+        key_value_list.clone(),
+    ]);
+    let mut announcement = launcher_id.bytes.clone();
+    announcement.extend(launcher_solution.tree_hash().bytes);
+    let conditions = vec![
+        make_condition(
+            ConditionOpcode::CreateCoin,
+            vec![
+                Program::from(SINGLETON_LAUNCHER_HASH.bytes.clone()),
+                int_program(amount),
+            ],
+        ),
+        make_condition(
+            ConditionOpcode::AssertCoinAnnouncement,
+            vec![Program::from(hash_256(announcement))],
+        ),
+    ];
+    let launcher_spend = CoinSpend {
+        coin: launcher_coin,
+        puzzle_reveal: SINGLETON_LAUNCHER.clone().into(),
+        solution: launcher_solution.into(),
+    };
+    Ok((conditions, launcher_spend))
+}
+
+/// Launches a singleton from standard XCH `coins`; the first coin is the launcher's
+/// parent. Returns the launcher id and the unsigned bundle, which ends with the
+/// launcher spend.
+pub fn create_singleton_launch_bundle(
+    coins: &[SpendableCoin],
+    inner_puzzle: &Program,
+    key_value_list: &Program,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<(Bytes32, SpendBundle), Box<dyn Error>> {
+    let origin = coins.first().ok_or("no coins to launch from")?;
+    let launcher_id = generate_launcher_coin(&origin.coin, amount).name();
+    create_launch_bundle_for_puzzle_hash(
+        coins,
+        &singleton_puzzle_hash(&launcher_id, &inner_puzzle.tree_hash()),
+        key_value_list,
+        amount,
+        fee,
+        change_puzzle_hash,
+    )
+}
+
+/// As `create_singleton_launch_bundle`, for an eve singleton known only by its full
+/// puzzle hash.
+pub fn create_launch_bundle_for_puzzle_hash(
+    coins: &[SpendableCoin],
+    eve_puzzle_hash: &Bytes32,
+    key_value_list: &Program,
+    amount: u64,
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<(Bytes32, SpendBundle), Box<dyn Error>> {
+    let origin = coins.first().ok_or("no coins to launch from")?;
+    let (conditions, launcher_spend) = launch_conditions_and_coin_spend_for_puzzle_hash(
+        &origin.coin,
+        eve_puzzle_hash,
+        key_value_list,
+        amount,
+    )?;
+    // The launcher CREATE_COIN goes through the payment so change is computed with it.
+    let assert_announcement = conditions.into_iter().skip(1).collect();
+    let payment = Payment {
+        puzzle_hash: SINGLETON_LAUNCHER_HASH.clone(),
+        amount,
+        memos: Vec::new(),
+    };
+    let mut spend_bundle = create_standard_spend_bundle_with_conditions(
+        coins,
+        &[payment],
+        fee,
+        change_puzzle_hash,
+        assert_announcement,
+    )?;
+    let launcher_id = launcher_spend.coin.name();
+    spend_bundle.coin_spends.push(launcher_spend);
+    Ok((launcher_id, spend_bundle))
+}
+
+/// Lineage proof for spending a child of `parent_spend`. The eve singleton's parent
+/// is the launcher, which has no inner puzzle; any other parent must be a singleton.
+pub fn lineage_proof_for_coin_spend(
+    parent_spend: &CoinSpend,
+) -> Result<LineageProof, Box<dyn Error>> {
+    let mut inner_puzzle_hash = None;
+    if parent_spend.coin.puzzle_hash != *SINGLETON_LAUNCHER_HASH {
+        let puzzle = Program::new(parent_spend.puzzle_reveal.to_bytes());
+        let (_launcher_id, inner_puzzle) = match_singleton_puzzle(&puzzle).ok_or_else(|| {
+            format!(
+                "parent coin {} is neither a singleton launcher nor a singleton",
+                parent_spend.coin.name()
+            )
+        })?;
+        inner_puzzle_hash = Some(inner_puzzle.tree_hash());
+    }
+    Ok(LineageProof {
+        parent_name: Some(parent_spend.coin.parent_coin_info.clone()),
+        inner_puzzle_hash,
+        amount: Some(parent_spend.coin.amount),
+    })
+}
+
+pub fn solution_for_singleton(
+    lineage_proof: &LineageProof,
+    amount: u64,
+    inner_solution: &Program,
+) -> Program {
+    Program::from(vec![
+        lineage_proof.to_program(),
+        int_program(amount),
+        inner_solution.clone(),
+    ])
+}
+
+/// Spends the singleton `coin`, whose inner puzzle is `inner_puzzle`.
+pub fn spend_singleton(
+    coin: &Coin,
+    launcher_id: &Bytes32,
+    inner_puzzle: &Program,
+    inner_solution: &Program,
+    lineage_proof: &LineageProof,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    let puzzle = puzzle_for_singleton(launcher_id, inner_puzzle);
+    if puzzle.tree_hash() != coin.puzzle_hash {
+        return Err(format!(
+            "inner puzzle does not match the puzzle hash of singleton coin {}",
+            coin.name()
+        )
+        .into());
+    }
+    let solution = solution_for_singleton(lineage_proof, coin.amount, inner_solution);
+    Ok(CoinSpend {
+        coin: coin.clone(),
+        puzzle_reveal: puzzle.into(),
+        solution: solution.into(),
+    })
+}
+
+/// Spends the eve singleton created by `launcher_spend`.
+pub fn create_eve_spend(
+    launcher_spend: &CoinSpend,
+    inner_puzzle: &Program,
+    inner_solution: &Program,
+) -> Result<CoinSpend, Box<dyn Error>> {
+    let launcher_id = launcher_spend.coin.name();
+    let eve_coin = Coin {
+        parent_coin_info: launcher_id.clone(),
+        puzzle_hash: singleton_puzzle_hash(&launcher_id, &inner_puzzle.tree_hash()),
+        amount: launcher_spend.coin.amount,
+    };
+    spend_singleton(
+        &eve_coin,
+        &launcher_id,
+        inner_puzzle,
+        inner_solution,
+        &lineage_proof_for_coin_spend(launcher_spend)?,
+    )
+}
+
+/// Splits a singleton puzzle into its launcher id and inner puzzle.
+pub fn match_singleton_puzzle(puzzle: &Program) -> Option<(Bytes32, Program)> {
+    let uncurried = puzzle.try_uncurry()?;
+    if uncurried.program.tree_hash() != *SINGLETON_TOP_LAYER_MOD_HASH || uncurried.args.len() != 2 {
+        return None;
+    }
+    let launcher_id = uncurried.args[0].rest().ok()?.first().ok()?.as_vec()?;
+    if launcher_id.len() != 32 {
+        return None;
+    }
+    Some((Bytes32::from(launcher_id), uncurried.args[1].clone()))
+}
+
+#[test]
+fn test_singleton_puzzle_hash() {
+    let launcher_id = Bytes32::from(vec![0x5a; 32]);
+    let inner_puzzle = Program::from(vec![Program::from(1), Program::from(vec![0x33; 8])]);
+    let puzzle = puzzle_for_singleton(&launcher_id, &inner_puzzle);
+    assert_eq!(
+        singleton_puzzle_hash(&launcher_id, &inner_puzzle.tree_hash()),
+        puzzle.tree_hash()
+    );
+    let (matched_id, matched_inner) = match_singleton_puzzle(&puzzle).unwrap();
+    assert_eq!(matched_id, launcher_id);
+    assert_eq!(matched_inner.serialized, inner_puzzle.serialized);
+    assert!(match_singleton_puzzle(&inner_puzzle).is_none());
+}
+
+#[test]
+fn test_singleton_puzzle_hashes() {
+    let top_layer: Bytes32 =
+        "7faa3253bfddd1e0decb0906b2dc6247bbc4cf608f58345d173adb63e8b47c9f".into();
+    let launcher: Bytes32 =
+        "eff07522495060c066f66f32acc2a77e3a3e737aca8baea4d1a64ea4cdc13da9".into();
+    assert_eq!(*SINGLETON_TOP_LAYER_MOD_HASH, top_layer);
+    assert_eq!(*SINGLETON_LAUNCHER_HASH, launcher);
+}
+
+#[test]
+fn test_lineage_proof_for_coin_spend() {
+    let launcher_id = Bytes32::from(vec![0x5a; 32]);
+    let inner_puzzle = Program::from(vec![Program::from(1), Program::from(vec![0x33; 8])]);
+    let puzzle = puzzle_for_singleton(&launcher_id, &inner_puzzle);
+    let parent_spend = CoinSpend {
+        coin: Coin {
+            parent_coin_info: Bytes32::from(vec![0x12; 32]),
+            puzzle_hash: puzzle.tree_hash(),
+            amount: 1,
+        },
+        puzzle_reveal: puzzle.into(),
+        solution: Program::from(Vec::<u8>::new()).into(),
+    };
+    let lineage_proof = lineage_proof_for_coin_spend(&parent_spend).unwrap();
+    assert_eq!(
+        lineage_proof.inner_puzzle_hash,
+        Some(inner_puzzle.tree_hash())
+    );
+    assert_eq!(lineage_proof.amount, Some(1));
+
+    let launcher_spend = CoinSpend {
+        coin: Coin {
+            puzzle_hash: SINGLETON_LAUNCHER_HASH.clone(),
+            ..parent_spend.coin.clone()
+        },
+        ..parent_spend.clone()
+    };
+    assert_eq!(
+        lineage_proof_for_coin_spend(&launcher_spend)
+            .unwrap()
+            .inner_puzzle_hash,
+        None
+    );
+
+    let not_a_singleton = CoinSpend {
+        puzzle_reveal: inner_puzzle.into(),
+        ..parent_spend
+    };
+    assert!(lineage_proof_for_coin_spend(&not_a_singleton).is_err());
+}
@@ -0,0 +1,239 @@
+use std::error::Error;
+use std::fmt;
+
+use chia_bls::derivable_key::DerivableKey;
+use chia_bls::derive_keys::{
+    master_to_wallet_hardened_intermediate, master_to_wallet_unhardened_intermediate,
+};
+use chia_bls::public_key::PublicKey;
+use chia_bls::secret_key::SecretKey;
+
+use crate::blockchain::sized_bytes::Bytes32;
+
+use super::puzzles::p2_delegated_puzzle_or_hidden_puzzle::get_puzzle_from_pk;
+
+/// The most keys one call derives. Wallets derive in batches far smaller than this.
+pub const MAX_DERIVATION_COUNT: u32 = 10_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DerivationError {
+    TooManyKeys {
+        count: u32,
+        max: u32,
+    },
+    /// `start + count` does not fit in a u32 index.
+    IndexOverflow {
+        start: u32,
+        count: u32,
+    },
+}
+
+impl fmt::Display for DerivationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DerivationError::TooManyKeys { count, max } => write!(
+                f,
+                "cannot derive {} keys at once, at most {} per call",
+                count, max
+            ),
+            DerivationError::IndexOverflow { start, count } => write!(
+                f,
+                "{} keys starting at index {} exceed the u32 index range",
+                count, start
+            ),
+        }
+    }
+}
+
+impl Error for DerivationError {}
+
+pub struct DerivationRecord {
+    pub index: u32,
+    pub public_key: PublicKey,
+    pub puzzle_hash: Bytes32,
+    pub hardened: bool,
+}
+
+impl DerivationRecord {
+    fn new(index: u32, public_key: PublicKey, hardened: bool) -> Self {
+        let puzzle_hash = get_puzzle_from_pk(public_key.clone()).tree_hash();
+        DerivationRecord {
+            index,
+            public_key,
+            puzzle_hash,
+            hardened,
+        }
+    }
+}
+
+/// Derives the m/12381/8444/2 intermediate key from a master public key.
+pub fn master_pk_to_wallet_unhardened_intermediate(master: &PublicKey) -> PublicKey {
+    master
+        .derive_unhardened(12381)
+        .derive_unhardened(8444)
+        .derive_unhardened(2)
+}
+
+/// Standard puzzle hashes for the unhardened wallet keys `start..start + count`, in
+/// index order. Fails if `count` exceeds `MAX_DERIVATION_COUNT`.
+pub fn derive_unhardened_puzzle_hashes(
+    intermediate: &PublicKey,
+    start: u32,
+    count: u32,
+) -> Result<Vec<DerivationRecord>, DerivationError> {
+    derive_in_parallel(start, count, |index| {
+        DerivationRecord::new(index, intermediate.derive_unhardened(index), false)
+    })
+}
+
+/// Standard puzzle hashes for the hardened wallet keys `start..start + count`, in
+/// index order. Fails if `count` exceeds `MAX_DERIVATION_COUNT`.
+pub fn derive_hardened_puzzle_hashes(
+    intermediate: &SecretKey,
+    start: u32,
+    count: u32,
+) -> Result<Vec<DerivationRecord>, DerivationError> {
+    derive_in_parallel(start, count, |index| {
+        let sk = intermediate.derive_hardened(index);
+        DerivationRecord::new(index, sk.public_key(), true)
+    })
+}
+
+/// Unhardened records followed by hardened records for a master secret key.
+pub fn derive_puzzle_hashes_from_master_sk(
+    master: &SecretKey,
+    start: u32,
+    count: u32,
+) -> Result<Vec<DerivationRecord>, DerivationError> {
+    let unhardened = master_to_wallet_unhardened_intermediate(master);
+    let hardened = master_to_wallet_hardened_intermediate(master);
+    let mut records = derive_unhardened_puzzle_hashes(&unhardened.public_key(), start, count)?;
+    records.extend(derive_hardened_puzzle_hashes(&hardened, start, count)?);
+    Ok(records)
+}
+
+fn derive_in_parallel<F>(
+    start: u32,
+    count: u32,
+    derive: F,
+) -> Result<Vec<DerivationRecord>, DerivationError>
+where
+    F: Fn(u32) -> DerivationRecord + Sync,
+{
+    if count > MAX_DERIVATION_COUNT {
+        return Err(DerivationError::TooManyKeys {
+            count,
+            max: MAX_DERIVATION_COUNT,
+        });
+    }
+    let end = start
+        .checked_add(count)
+        .ok_or(DerivationError::IndexOverflow { start, count })?;
+    let indexes: Vec<u32> = (start..end).collect();
+    if indexes.is_empty() {
+        return Ok(Vec::new());
+    }
+    let threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let chunk_size = (indexes.len() + threads - 1) / threads;
+    let derive = &derive;
+    Ok(crossbeam::thread::scope(|scope| {
+        let handles: Vec<_> = indexes
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move |_| chunk.iter().map(|index| derive(*index)).collect::<Vec<_>>())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("derivation thread panicked"))
+            .collect()
+    })
+    .expect("derivation thread panicked"))
+}
+
+#[test]
+fn test_derivation_vectors() {
+    use crate::chia_wallet::keys::mnemonic::secret_key_from_mnemonic;
+
+    // bls-signatures src/test.cpp, "EIP-2333 hardened HD keys", test case 1.
+    let seed =
+        hex::decode("3141592653589793238462643383279502884197169399375105820974944592").unwrap();
+    let eip_master = SecretKey::from_seed(&seed);
+    assert_eq!(
+        hex::encode(eip_master.to_bytes()),
+        "4ff5e145590ed7b71e577bb04032396d1619ff41cb4e350053ed2dce8d1efd1c"
+    );
+    assert_eq!(
+        hex::encode(eip_master.derive_hardened(3141592653).to_bytes()),
+        "5c62dcf9654481292aafa3348f1d1b0017bbfb44d6881d26d2b17836b38f204d"
+    );
+
+    let mnemonic = [vec!["abandon"; 23], vec!["art"]].concat().join(" ");
+    let master = secret_key_from_mnemonic(&mnemonic, "").unwrap();
+    // Unhardened then hardened puzzle hashes for indexes 0, 1 and 2. These are not from
+    // chia-blockchain: they were computed with an independent Python implementation of
+    // KeyGen, the m/12381/8444/2 derivations and the standard puzzle's tree hash, which
+    // reproduces the bls-signatures vectors above and in `keys::fingerprint`.
+    let expected = [
+        "d207c1e11fc3b0cd7472e8c7e53c8d2b81709516346c7baa9fbb9070ffccfe89",
+        "7ba6cfb69d2cbd960dffed610aef280384d0435a4c9d9e8582430f0df5d4052f",
+        "6aaf6665bbde852f20f9b8f0f682624a369a09e4f73ef58faa0c67163a0d14a3",
+        "761bbd89ca2a12a4c950f1a4c6df81382425e47221d53869cce882393675d07d",
+        "7c8ddbc4f34e334664327b2db15e1c14c5ec8aaea3c935bda595b03367580ca4",
+        "f81d853471e974869d54c3ecaee01049aec12464a2bb6b6885e569e30f50a354",
+    ];
+    let records = derive_puzzle_hashes_from_master_sk(&master, 0, 3).unwrap();
+    assert_eq!(records.len(), expected.len());
+    for (i, (record, puzzle_hash)) in records.iter().zip(expected).enumerate() {
+        assert_eq!(record.index, i as u32 % 3);
+        assert_eq!(record.hardened, i >= 3);
+        assert_eq!(record.puzzle_hash, Bytes32::from(puzzle_hash));
+    }
+    assert_eq!(
+        hex::encode(records[0].public_key.to_bytes()),
+        "af6c8e1ade5f1e0fdf588d9fc5f7cb3fd587d45cad8f0d7d473220820d142cd2d6985c290f70420acbed80ba0b285860"
+    );
+    assert_eq!(
+        hex::encode(records[3].public_key.to_bytes()),
+        "8cf9590585eafc287497b15ec7e32e0e5bdcb27a0eef799b66ba685898514976a1933f2e03efeeca81064983a1372d68"
+    );
+}
+
+#[test]
+fn test_parallel_derivation_matches_single_index() {
+    use crate::api::{get_puzzle_from_public_key, public_key_derive_path_unhardened};
+    use crate::program_utils::program::Program;
+
+    let master_pk = SecretKey::from_seed(&[5u8; 64]).public_key();
+    let intermediate = master_pk_to_wallet_unhardened_intermediate(&master_pk);
+    let records = derive_unhardened_puzzle_hashes(&intermediate, 7, 50).unwrap();
+    assert_eq!(records.len(), 50);
+    for (offset, record) in records.iter().enumerate() {
+        let index = 7 + offset as u32;
+        let pk = public_key_derive_path_unhardened(
+            master_pk.to_bytes().to_vec(),
+            vec![12381, 8444, 2, index],
+        )
+        .unwrap();
+        let puzzle = get_puzzle_from_public_key(pk.clone()).unwrap();
+        assert_eq!(record.index, index);
+        assert_eq!(record.public_key.to_bytes().to_vec(), pk);
+        assert_eq!(record.puzzle_hash, Program::new(puzzle).tree_hash());
+    }
+    assert_eq!(
+        derive_unhardened_puzzle_hashes(&intermediate, u32::MAX, 1).err(),
+        Some(DerivationError::IndexOverflow {
+            start: u32::MAX,
+            count: 1
+        })
+    );
+    assert_eq!(
+        derive_unhardened_puzzle_hashes(&intermediate, 0, MAX_DERIVATION_COUNT + 1).err(),
+        Some(DerivationError::TooManyKeys {
+            count: MAX_DERIVATION_COUNT + 1,
+            max: MAX_DERIVATION_COUNT
+        })
+    );
+}
@@ -1 +1,3 @@
+pub(crate) mod derivation;
 pub(crate) mod puzzles;
+pub(crate) mod transaction;
@@ -6,11 +6,8 @@ pub fn p2_conditions_mod() -> SerializedProgram {
 }
 
 pub fn puzzleForConditions(conditions: Program) -> Program {
-    let p2_program = p2_conditions_mod().to_program().unwrap();
-    let mut args = Vec::new();
-    args.push(conditions);
-    let result = p2_program.run(Program::from(args));
-    return result.program;
+    // p2_conditions is `(c (q . 1) 2)`: it quotes the conditions it is given.
+    Program::from(1).cons(&conditions)
 }
 
 pub fn solution_for_conditions(conditions: Program) -> Program {
@@ -1,10 +1,14 @@
+use blst::min_pk::AggregatePublicKey;
 use chia_bls::public_key::PublicKey;
+use chia_bls::secret_key::SecretKey;
+use num_bigint::{BigInt, Sign};
+use sha2::{Digest, Sha256};
 
 use crate::api::{bytes_to_hex, hex_to_bytes, program_disassemble};
+use crate::blockchain::sized_bytes::Bytes32;
 use crate::program_utils::program::Program;
 use crate::program_utils::serialized_program::SerializedProgram;
 
-use super::calculate_synthetic_public_key::calculate_synthetic_public_key_program;
 use super::p2_conditions::puzzleForConditions;
 
 pub fn p2_delegated_puzzle_or_hidden_puzzle_program() -> Program {
@@ -29,26 +33,114 @@ pub fn solutionForConditions(conditions: Program) -> Program {
     return solution_for_delegated_puzzle(delegatedPuzzle, Program::from(empty_list));
 }
 
+const GROUP_ORDER: &str = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+
+fn group_order() -> BigInt {
+    BigInt::parse_bytes(GROUP_ORDER.as_bytes(), 16).unwrap()
+}
+
+fn secret_key_from_int(value: &BigInt) -> SecretKey {
+    let (_sign, bytes) = value.to_bytes_be();
+    let mut sk_bytes = [0u8; 32];
+    sk_bytes[32 - bytes.len()..].copy_from_slice(&bytes);
+    SecretKey::from_bytes(&sk_bytes).expect("value is reduced modulo the group order")
+}
+
+/// sha256(public_key + hidden_puzzle_hash) as a signed integer, modulo the group order.
+pub fn calculate_synthetic_offset(public_key: &PublicKey, hidden_puzzle_hash: &Bytes32) -> BigInt {
+    let mut hasher = Sha256::new();
+    hasher.update(public_key.to_bytes());
+    hasher.update(&hidden_puzzle_hash.bytes);
+    let offset = BigInt::from_signed_bytes_be(&hasher.finalize());
+    let group_order = group_order();
+    ((offset % &group_order) + &group_order) % &group_order
+}
+
+pub fn calculate_synthetic_secret_key(
+    secret_key: &SecretKey,
+    hidden_puzzle_hash: &Bytes32,
+) -> SecretKey {
+    let offset = calculate_synthetic_offset(&secret_key.public_key(), hidden_puzzle_hash);
+    let secret_exponent = BigInt::from_bytes_be(Sign::Plus, &secret_key.to_bytes());
+    secret_key_from_int(&((secret_exponent + offset) % group_order()))
+}
+
+/// Native equivalent of running `calculate_synthetic_public_key_program`.
+pub fn calculate_synthetic_public_key(
+    public_key: &PublicKey,
+    hidden_puzzle_hash: &Bytes32,
+) -> PublicKey {
+    let offset = calculate_synthetic_offset(public_key, hidden_puzzle_hash);
+    let offset_key = secret_key_from_int(&offset).public_key();
+    let points = [
+        blst::min_pk::PublicKey::from_bytes(&public_key.to_bytes()).unwrap(),
+        blst::min_pk::PublicKey::from_bytes(&offset_key.to_bytes()).unwrap(),
+    ];
+    let sum = AggregatePublicKey::aggregate(&[&points[0], &points[1]], false)
+        .unwrap()
+        .to_public_key();
+    PublicKey::from_bytes(&sum.to_bytes()).unwrap()
+}
+
+/// The standard puzzle for a key that is already synthetic, e.g. one a wallet signed with.
+pub fn get_puzzle_from_synthetic_pk(synthetic_pk: &PublicKey) -> Program {
+    let p2_args = [Program::from(synthetic_pk.to_bytes().to_vec())].to_vec();
+    p2_delegated_puzzle_or_hidden_puzzle_program().curry(p2_args)
+}
+
 pub fn get_puzzle_from_pk_and_hidden_puzzle(pk: PublicKey, hidden_puzzle: Program) -> Program {
-    let pk_bytes = pk.to_bytes().to_vec();
-    let hidden_puzzle_hash = hidden_puzzle.tree_hash().to_sized_bytes().to_vec();
+    let hidden_puzzle_hash = hidden_puzzle.tree_hash();
+    let synthetic_pub_key = calculate_synthetic_public_key(&pk, &hidden_puzzle_hash);
+    get_puzzle_from_synthetic_pk(&synthetic_pub_key)
+}
+
+pub fn get_puzzle_from_pk(pk: PublicKey) -> Program {
+    let hidden_puzzle = default_hidden_puzzle();
+    return get_puzzle_from_pk_and_hidden_puzzle(pk, hidden_puzzle);
+}
+
+#[test]
+fn test_synthetic_keys_match_puzzle() {
+    use super::calculate_synthetic_public_key::calculate_synthetic_public_key_program;
+
+    let sk = SecretKey::from_seed(&[3u8; 64]);
+    let pk = sk.public_key();
+    let hidden_puzzle_hash = default_hidden_puzzle().tree_hash();
+    let synthetic_sk = calculate_synthetic_secret_key(&sk, &hidden_puzzle_hash);
+    let synthetic_pk = calculate_synthetic_public_key(&pk, &hidden_puzzle_hash);
+    assert_eq!(
+        synthetic_sk.public_key().to_bytes(),
+        synthetic_pk.to_bytes()
+    );
+
     let args = Program::from(
         [
-            Program::from(pk_bytes.clone()),
-            Program::from(hidden_puzzle_hash.clone()),
+            Program::from(pk.to_bytes().to_vec()),
+            Program::from(hidden_puzzle_hash.bytes.clone()),
         ]
         .to_vec(),
     );
+    let clvm_pk = calculate_synthetic_public_key_program()
+        .run(args)
+        .unwrap()
+        .program;
+    let native_pk = Program::from(synthetic_pk.to_bytes().to_vec());
+    assert_eq!(clvm_pk.serialized, native_pk.serialized);
 
-    let synthetic_pub_key = calculate_synthetic_public_key_program().run(args);
-
-    let p2_args = [synthetic_pub_key.program].to_vec();
-    let curried = p2_delegated_puzzle_or_hidden_puzzle_program()
-        .curry(p2_args) ;
-    return curried;
+    let curried_args = get_puzzle_from_pk(pk).uncurry().unwrap().args;
+    assert_eq!(curried_args[0].serialized, clvm_pk.serialized);
 }
 
-pub fn get_puzzle_from_pk(pk: PublicKey) -> Program {
-    let hidden_puzzle = default_hidden_puzzle();
-    return get_puzzle_from_pk_and_hidden_puzzle(pk, hidden_puzzle);
+#[test]
+fn test_synthetic_keys_custom_hidden_puzzle() {
+    let sk = SecretKey::from_seed(&[9u8; 64]);
+    let hidden_puzzle_hash: Bytes32 =
+        "0101010101010101010101010101010101010101010101010101010101010101".into();
+    let synthetic_sk = calculate_synthetic_secret_key(&sk, &hidden_puzzle_hash);
+    let synthetic_pk = calculate_synthetic_public_key(&sk.public_key(), &hidden_puzzle_hash);
+    assert_eq!(
+        synthetic_sk.public_key().to_bytes(),
+        synthetic_pk.to_bytes()
+    );
+    assert_ne!(synthetic_pk.to_bytes(), sk.public_key().to_bytes());
 }
@@ -0,0 +1,225 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use chia_bls::public_key::PublicKey;
+
+use crate::blockchain::announcement::Announcement;
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::sized_bytes::{Bytes32, Bytes96};
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::puzzle_utils::{
+    make_assert_coin_announcement, make_create_coin_announcement, make_create_coin_condition,
+    make_reserve_fee_condition,
+};
+use crate::program_utils::program::Program;
+use crate::program_utils::serialized_program::SerializedProgram;
+use crate::program_utils::utils::hash_256;
+
+use super::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    get_puzzle_from_pk, solutionForConditions,
+};
+
+/// A coin locked by the standard puzzle for `public_key` (the unsynthesized wallet key).
+pub struct SpendableCoin {
+    pub coin: Coin,
+    pub public_key: PublicKey,
+}
+
+pub struct Payment {
+    pub puzzle_hash: Bytes32,
+    pub amount: u64,
+    pub memos: Vec<Vec<u8>>,
+}
+
+/// G2 point at infinity, the signature of an empty aggregate.
+pub fn empty_signature() -> Bytes96 {
+    let mut bytes = vec![0u8; 96];
+    bytes[0] = 0xc0;
+    Bytes96::from(bytes)
+}
+
+/// The conditions list each of `coins` outputs, in order, to pay `payments` and `fee`
+/// and return the rest to `change_puzzle_hash`. The first coin creates every output plus
+/// a coin announcement that all the other coins assert. Shared by the wallets whose
+/// puzzles run a list of conditions, whatever their signing scheme.
+pub fn spend_conditions_for_coins(
+    coins: &[Coin],
+    payments: &[Payment],
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+    extra_conditions: Vec<Program>,
+) -> Result<Vec<Program>, Box<dyn Error>> {
+    if coins.is_empty() {
+        return Err("no coins to spend".into());
+    }
+    let mut seen = HashSet::new();
+    for coin in coins {
+        if !seen.insert(coin.name()) {
+            return Err(format!("coin {} is listed twice", coin.name()).into());
+        }
+    }
+
+    let mut total_input: u64 = 0;
+    for coin in coins {
+        total_input = total_input
+            .checked_add(coin.amount)
+            .ok_or("coin amounts overflow")?;
+    }
+    let mut total_output: u64 = fee;
+    for payment in payments {
+        total_output = total_output
+            .checked_add(payment.amount)
+            .ok_or("payment amounts overflow")?;
+    }
+    if total_output > total_input {
+        return Err(format!(
+            "insufficient funds: spending {} mojos but coins only hold {}",
+            total_output, total_input
+        )
+        .into());
+    }
+    let change = total_input - total_output;
+
+    let mut primaries: Vec<(Bytes32, u64, Vec<Vec<u8>>)> = payments
+        .iter()
+        .map(|payment| {
+            (
+                payment.puzzle_hash.clone(),
+                payment.amount,
+                payment.memos.clone(),
+            )
+        })
+        .collect();
+    if change > 0 {
+        primaries.push((change_puzzle_hash.clone(), change, Vec::new()));
+    }
+
+    let origin = &coins[0];
+    let mut message_list: Vec<u8> = Vec::new();
+    for coin in coins {
+        message_list.extend(coin.name().bytes);
+    }
+    for (puzzle_hash, amount, _memos) in &primaries {
+        let output = Coin {
+            parent_coin_info: origin.name(),
+            puzzle_hash: puzzle_hash.clone(),
+            amount: *amount,
+        };
+        message_list.extend(output.name().bytes);
+    }
+    let message = hash_256(message_list);
+    let primary_announcement = Announcement {
+        origin_info: origin.name(),
+        message: message.clone(),
+    };
+
+    let mut coin_conditions = Vec::new();
+    for i in 0..coins.len() {
+        let mut conditions = Vec::new();
+        if i == 0 {
+            for (puzzle_hash, amount, memos) in &primaries {
+                conditions.push(make_create_coin_condition(puzzle_hash, *amount, memos));
+            }
+            conditions.push(make_create_coin_announcement(&message));
+            if fee > 0 {
+                conditions.push(make_reserve_fee_condition(fee));
+            }
+            conditions.extend(extra_conditions.iter().cloned());
+        } else {
+            conditions.push(make_assert_coin_announcement(&primary_announcement.name()));
+        }
+        coin_conditions.push(Program::from(conditions));
+    }
+    Ok(coin_conditions)
+}
+
+/// Builds an unsigned spend bundle paying `payments` and `fee` from `coins`, returning the
+/// rest to `change_puzzle_hash`. The first coin creates every output plus a coin
+/// announcement that all the other spends assert, as the reference wallet does.
+pub fn create_standard_spend_bundle(
+    coins: &[SpendableCoin],
+    payments: &[Payment],
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    create_standard_spend_bundle_with_conditions(
+        coins,
+        payments,
+        fee,
+        change_puzzle_hash,
+        Vec::new(),
+    )
+}
+
+/// Like `create_standard_spend_bundle`, with `extra_conditions` added to the first
+/// coin's spend, e.g. to assert announcements from other spends in the bundle.
+pub fn create_standard_spend_bundle_with_conditions(
+    coins: &[SpendableCoin],
+    payments: &[Payment],
+    fee: u64,
+    change_puzzle_hash: &Bytes32,
+    extra_conditions: Vec<Program>,
+) -> Result<SpendBundle, Box<dyn Error>> {
+    let plain_coins: Vec<Coin> = coins
+        .iter()
+        .map(|spendable| spendable.coin.clone())
+        .collect();
+    let conditions = spend_conditions_for_coins(
+        &plain_coins,
+        payments,
+        fee,
+        change_puzzle_hash,
+        extra_conditions,
+    )?;
+    let mut coin_spends = Vec::new();
+    for (spendable, conditions) in coins.iter().zip(conditions) {
+        let puzzle = get_puzzle_from_pk(spendable.public_key.clone());
+        if puzzle.tree_hash() != spendable.coin.puzzle_hash {
+            return Err(format!(
+                "public key does not match the puzzle hash of coin {}",
+                spendable.coin.name()
+            )
+            .into());
+        }
+        let solution = solutionForConditions(conditions);
+        coin_spends.push(CoinSpend {
+            coin: spendable.coin.clone(),
+            puzzle_reveal: SerializedProgram::from_bytes(&puzzle.serialized),
+            solution: SerializedProgram::from_bytes(&solution.serialized),
+        });
+    }
+
+    Ok(SpendBundle {
+        coin_spends,
+        aggregated_signature: empty_signature(),
+    })
+}
+
+#[test]
+fn test_standard_spend_bundle_outputs() {
+    use crate::chia_wallet::test_utils::{standard_coin, test_secret_key};
+
+    let pk = test_secret_key(5).public_key();
+    let coins: Vec<SpendableCoin> = [700u64, 500u64]
+        .iter()
+        .enumerate()
+        .map(|(i, amount)| standard_coin(&pk, i as u8, *amount))
+        .collect();
+    let puzzle_hash = coins[0].coin.puzzle_hash.clone();
+    let destination = Bytes32::from(vec![0xaa; 32]);
+    let payments = vec![Payment {
+        puzzle_hash: destination.clone(),
+        amount: 1000,
+        memos: vec![destination.bytes.clone()],
+    }];
+    let spend_bundle = create_standard_spend_bundle(&coins, &payments, 50, &puzzle_hash).unwrap();
+    assert_eq!(spend_bundle.coin_spends.len(), 2);
+    let additions = spend_bundle.coin_spends[0].additions();
+    assert_eq!(additions.len(), 2);
+    assert_eq!(additions[0].puzzle_hash, destination);
+    assert_eq!(additions[1].amount, 150);
+    assert!(spend_bundle.coin_spends[1].additions().is_empty());
+
+    assert!(create_standard_spend_bundle(&coins, &payments, 500, &puzzle_hash).is_err());
+}
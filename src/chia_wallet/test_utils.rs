@@ -0,0 +1,59 @@
+use chia_bls::public_key::PublicKey;
+use chia_bls::secret_key::SecretKey;
+
+use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
+use crate::blockchain::constants::MAINNET;
+use crate::blockchain::sized_bytes::Bytes32;
+use crate::blockchain::spend_bundle::SpendBundle;
+use crate::chia_wallet::puzzle_utils::make_create_coin_condition;
+use crate::chia_wallet::sign_coin_spends::sign_coin_spends;
+use crate::chia_wallet::standart::puzzles::p2_delegated_puzzle_or_hidden_puzzle::{
+    get_puzzle_from_pk, solutionForConditions,
+};
+use crate::chia_wallet::standart::transaction::SpendableCoin;
+use crate::program_utils::program::Program;
+use crate::program_utils::utils::INFINITE_COST;
+
+/// The secret key generated from a 64 byte seed filled with `seed`.
+pub fn test_secret_key(seed: u8) -> SecretKey {
+    SecretKey::from_seed(&[seed; 64])
+}
+
+/// A standard coin of `amount` for `public_key` whose parent id is filled with `parent`.
+pub fn standard_coin(public_key: &PublicKey, parent: u8, amount: u64) -> SpendableCoin {
+    SpendableCoin {
+        coin: Coin {
+            parent_coin_info: Bytes32::from(vec![parent; 32]),
+            puzzle_hash: get_puzzle_from_pk(public_key.clone()).tree_hash(),
+            amount,
+        },
+        public_key: public_key.clone(),
+    }
+}
+
+/// An unsigned spend of a standard coin that outputs `conditions`.
+pub fn standard_coin_spend(spendable: &SpendableCoin, conditions: Program) -> CoinSpend {
+    CoinSpend {
+        coin: spendable.coin.clone(),
+        puzzle_reveal: get_puzzle_from_pk(spendable.public_key.clone()).into(),
+        solution: solutionForConditions(conditions).into(),
+    }
+}
+
+/// One standard spend of 100 mojos per seed, each signed with `test_secret_key(seed)`.
+pub fn signed_standard_spend_bundle(seeds: &[u8]) -> SpendBundle {
+    let secret_keys: Vec<SecretKey> = seeds.iter().map(|seed| test_secret_key(*seed)).collect();
+    let coin_spends = secret_keys
+        .iter()
+        .map(|sk| {
+            let conditions = Program::from(vec![make_create_coin_condition(
+                &Bytes32::from(vec![0xee; 32]),
+                100,
+                &[],
+            )]);
+            standard_coin_spend(&standard_coin(&sk.public_key(), 0x31, 100), conditions)
+        })
+        .collect();
+    sign_coin_spends(coin_spends, &secret_keys, &MAINNET, INFINITE_COST).unwrap()
+}
@@ -1,5 +1,6 @@
 mod bridge_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
 mod api;
+mod api_error;
 mod program_utils;
 mod blockchain;
 mod chiapos;
@@ -1,4 +1,5 @@
 mod api;
+mod api_error;
 mod blockchain;
 mod bridge_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
 mod chia_wallet;
@@ -34,7 +35,7 @@ fn main() {
     let tail_hash = tail.tree_hash().to_sized_bytes().to_vec();
     let cat_puzzle = create_cat_puzzle(tail_hash.clone(), acs.clone().serialized.clone());
     let cat_puzzlehash = cat_puzzle.tree_hash().to_sized_bytes().to_vec();
-    let uncurried = program_uncurry(cat_puzzle.serialized.clone());
+    let uncurried = program_uncurry(cat_puzzle.serialized.clone()).unwrap();
     //println!("uncurried: {}", bytes_to_hex(uncurried.program.clone()));
 
     //iter args
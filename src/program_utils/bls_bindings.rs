@@ -8,6 +8,14 @@ const AUG_SCHEME_DST: &[u8; 43] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_AUG_"
 // const POP_SCHEME_DST: &[u8; 43] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
 // const AUG_SCHEME_POP_DST: &[u8; 43] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
 
+pub fn is_valid_public_key(public_key: &Bytes48) -> bool {
+    PublicKey::key_validate(public_key.to_bytes().as_slice()).is_ok()
+}
+
+pub fn is_valid_signature(signature: &Bytes96) -> bool {
+    Signature::sig_validate(signature.to_bytes().as_slice(), false).is_ok()
+}
+
 pub fn verify_signature(public_key: &Bytes48, msg: &[u8], signature: &Bytes96) -> bool {
     let sig = Signature::from_bytes(signature.to_bytes().as_slice()).unwrap();
     let pk = PublicKey::from_bytes(public_key.to_bytes().as_slice()).unwrap();
@@ -1,33 +1,40 @@
 use crate::blockchain::announcement::Announcement;
 use crate::blockchain::coin::Coin;
+use crate::blockchain::coin_spend::CoinSpend;
 use crate::blockchain::condition_opcode::ConditionOpcode;
 use crate::blockchain::condition_with_args::ConditionWithArgs;
-use crate::blockchain::sized_bytes::Bytes32;
-use crate::blockchain::utils::atom_to_int;
+use crate::blockchain::sized_bytes::{Bytes32, Bytes48, SizedBytes};
+use crate::blockchain::utils::{atom_to_int, int_to_bytes};
 use crate::program_utils::program::Program;
 use crate::program_utils::serialized_program::SerializedProgram;
+use crate::program_utils::utils::hash_256;
 use clvmr::allocator::{Allocator};
 use clvmr::node::Node;
+use num_bigint::BigInt;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::error::Error;
 use crate::program_utils::serialize::node_to_bytes;
 
-pub fn parse_sexp_to_condition(sexp: &mut Program) -> Result<ConditionWithArgs, Box<dyn Error>> {
+/// Parses one condition. Opcodes this crate does not know parse to `None`, as consensus
+/// ignores them.
+pub fn parse_sexp_to_condition(
+    sexp: &mut Program,
+) -> Result<Option<ConditionWithArgs>, Box<dyn Error>> {
     let as_atoms = sexp.as_atom_list();
-    if as_atoms.len() < 1 {
-        Err("Invalid Condition".into())
-    } else {
-        match as_atoms.split_first() {
-            Some((first, rest)) => match ConditionOpcode::from_u8(first[0]) {
-                Ok(opcode) => Ok(ConditionWithArgs {
+    match as_atoms.split_first() {
+        // Opcodes are a single byte; any other atom, nil included, is unknown.
+        Some((first, rest)) => match first.as_slice() {
+            [byte] => match ConditionOpcode::from_u8(*byte) {
+                Ok(opcode) => Ok(Some(ConditionWithArgs {
                     opcode,
                     vars: Vec::from(rest),
-                }),
-                Err(_error) => Err("Invalid Condition".into()),
+                })),
+                Err(_error) => Ok(None),
             },
-            None => Err("Invalid Condition".into()),
-        }
+            _ => Ok(None),
+        },
+        None => Err("Invalid Condition".into()),
     }
 }
 
@@ -38,9 +45,10 @@ pub fn parse_sexp_to_conditions(
     let prog = Program::new(sexp.to_bytes());
     for mut arg in prog.iter() {
         match parse_sexp_to_condition(&mut arg) {
-            Ok(condition) => {
+            Ok(Some(condition)) => {
                 results.push(condition);
             }
+            Ok(None) => {}
             Err(error) => return Err(error),
         }
     }
@@ -189,46 +197,236 @@ pub fn conditions_for_solution(
     }
 }
 
-//
-// pub fn pkm_pairs(npc_list: Vec<[NPC>, additional_data: bytes) -> (Vec<Bytes48>, Vec<Vec<u8>>){
-//     let ret:(Vec<Bytes48>, Vec<Vec<u8>>) = (Vec::new(), Vec::new());
-//     for npc in npc_list:
-//     for opcode, l in npc.conditions:
-//     if opcode == ConditionOpcode.AGG_SIG_UNSAFE:
-//     for cwa in l:
-//     assert len(cwa.vars) == 2
-//     assert len(cwa.vars[0]) == 48 and len(cwa.vars[1]) <= 1024
-//     assert cwa.vars[0] is not None and cwa.vars[1] is not None
-//     ret[0].append(bytes48(cwa.vars[0]))
-//     ret[1].append(cwa.vars[1])
-//     elif opcode == ConditionOpcode.AGG_SIG_ME:
-//     for cwa in l:
-//     assert len(cwa.vars) == 2
-//     assert len(cwa.vars[0]) == 48 and len(cwa.vars[1]) < = 1024
-//     assert cwa.vars[0] is not None and cwa.vars[1] is not None
-//     ret[0].append(bytes48(cwa.vars[0]))
-//     ret[1].append(cwa.vars[1] + npc.coin_name + additional_data)
-//     return ret
-// }
-//
-//
-// pub fn pkm_pairs_for_conditions_dict(
-//     conditions_dict: HashMap<ConditionOpcode, Vec<ConditionWithArgs>>, coin_name: bytes32, additional_data: bytes
-// ) -> Vec<(Bytes48, Vec<u8>)> {
-//     assert coin_name is not None
-//     ret: List[Tuple[bytes48, bytes]] = []
-//
-//     for cwa in conditions_dict.get(ConditionOpcode.AGG_SIG_UNSAFE, []):
-//     assert len(cwa.vars) == 2
-//     assert len(cwa.vars[0]) == 48 and len(cwa.vars[1]) <= 1024
-//     assert cwa.vars[0] is not None and cwa.vars[1] is not None
-//     ret.append((bytes48(cwa.vars[0]), cwa.vars[1]))
-//
-//     for cwa in conditions_dict.get(ConditionOpcode.AGG_SIG_ME, []):
-//     assert len(cwa.vars) == 2
-//     assert len(cwa.vars[0]) == 48 and len(cwa.vars[1]) <= 1024
-//     assert cwa.vars[0] is not None and cwa.vars[1] is not None
-//     ret.append((bytes48(cwa.vars[0]), cwa.vars[1] + coin_name + additional_data))
-//     return ret
-// }
-//
+/// The memos of a CREATE_COIN condition, `(51 puzzle_hash amount (memo ...))`. They are
+/// a list, so `parse_sexp_to_condition` leaves them out of `vars`.
+pub fn create_coin_memos(condition: &Program) -> Vec<Vec<u8>> {
+    let memos = condition
+        .rest()
+        .and_then(|args| args.rest()?.rest()?.first());
+    match memos {
+        Ok(mut memos) if memos.is_pair() => memos.as_atom_list(),
+        _ => Vec::new(),
+    }
+}
+
+/// Like `conditions_for_solution`, but pairs each condition with the program it was
+/// parsed from, for callers that read more than `vars`, such as CREATE_COIN memos.
+pub fn condition_programs_for_solution(
+    puzzle_reveal: &SerializedProgram,
+    solution: &SerializedProgram,
+    max_cost: u64,
+) -> Result<(Vec<(ConditionWithArgs, Program)>, u64), Box<dyn Error>> {
+    let mut allocator = Allocator::new();
+    let (cost, output) = puzzle_reveal.run_with_cost(
+        &mut allocator,
+        max_cost,
+        &Program::new(solution.to_bytes()),
+    )?;
+    let output = Program::new(node_to_bytes(&Node::new(&allocator, output))?);
+    let mut conditions = Vec::new();
+    for mut program in output.iter() {
+        if let Some(condition) = parse_sexp_to_condition(&mut program)? {
+            conditions.push((condition, program));
+        }
+    }
+    Ok((conditions, cost))
+}
+
+fn agg_sig_pair(cwa: &ConditionWithArgs) -> Result<(Bytes48, Vec<u8>), Box<dyn Error>> {
+    if cwa.vars.len() != 2 {
+        return Err(format!(
+            "{:?} expects 2 arguments, got {}",
+            cwa.opcode,
+            cwa.vars.len()
+        )
+        .into());
+    }
+    if cwa.vars[0].len() != 48 {
+        return Err(format!("{:?} public key must be 48 bytes", cwa.opcode).into());
+    }
+    if cwa.vars[1].len() > 1024 {
+        return Err(format!("{:?} message is longer than 1024 bytes", cwa.opcode).into());
+    }
+    Ok((Bytes48::from(cwa.vars[0].clone()), cwa.vars[1].clone()))
+}
+
+/// The coin fields an AGG_SIG condition appends to its message, as in chia's
+/// `make_aggsig_final_message`. AGG_SIG_UNSAFE appends nothing.
+fn agg_sig_coin_data(opcode: &ConditionOpcode, coin: &Coin) -> Vec<u8> {
+    let parent = coin.parent_coin_info.to_bytes();
+    let puzzle_hash = coin.puzzle_hash.to_bytes();
+    let amount = int_to_bytes(&BigInt::from(coin.amount));
+    match opcode {
+        ConditionOpcode::AggSigParent => parent,
+        ConditionOpcode::AggSigPuzzle => puzzle_hash,
+        ConditionOpcode::AggSigAmount => amount,
+        ConditionOpcode::AggSigPuzzleAmount => [puzzle_hash, amount].concat(),
+        ConditionOpcode::AggSigParentAmount => [parent, amount].concat(),
+        ConditionOpcode::AggSigParentPuzzle => [parent, puzzle_hash].concat(),
+        ConditionOpcode::AggSigMe => coin.name().to_bytes(),
+        _ => Vec::new(),
+    }
+}
+
+/// AGG_SIG_ME signs `additional_data` itself, the network's genesis challenge; the
+/// newer variants sign its hash with their opcode appended.
+fn agg_sig_additional_data(opcode: &ConditionOpcode, additional_data: &[u8]) -> Vec<u8> {
+    match opcode {
+        ConditionOpcode::AggSigMe => additional_data.to_vec(),
+        _ => hash_256([additional_data, &[opcode.clone() as u8]].concat()),
+    }
+}
+
+pub fn pkm_pairs_for_conditions_dict(
+    conditions_dict: &HashMap<ConditionOpcode, Vec<ConditionWithArgs>>,
+    coin: &Coin,
+    additional_data: &[u8],
+) -> Result<Vec<(Bytes48, Vec<u8>)>, Box<dyn Error>> {
+    let mut ret = Vec::new();
+    if let Some(conditions) = conditions_dict.get(&ConditionOpcode::AggSigUnsafe) {
+        for cwa in conditions {
+            ret.push(agg_sig_pair(cwa)?);
+        }
+    }
+    for opcode in [
+        ConditionOpcode::AggSigMe,
+        ConditionOpcode::AggSigParent,
+        ConditionOpcode::AggSigPuzzle,
+        ConditionOpcode::AggSigAmount,
+        ConditionOpcode::AggSigPuzzleAmount,
+        ConditionOpcode::AggSigParentAmount,
+        ConditionOpcode::AggSigParentPuzzle,
+    ] {
+        let conditions = match conditions_dict.get(&opcode) {
+            Some(conditions) => conditions,
+            None => continue,
+        };
+        let coin_data = agg_sig_coin_data(&opcode, coin);
+        let opcode_data = agg_sig_additional_data(&opcode, additional_data);
+        for cwa in conditions {
+            let (pk, mut msg) = agg_sig_pair(cwa)?;
+            msg.extend(&coin_data);
+            msg.extend(&opcode_data);
+            ret.push((pk, msg));
+        }
+    }
+    Ok(ret)
+}
+
+pub fn pkm_pairs_for_coin_spend(
+    coin_spend: &CoinSpend,
+    additional_data: &[u8],
+    max_cost: u64,
+) -> Result<Vec<(Bytes48, Vec<u8>)>, Box<dyn Error>> {
+    let (conditions_dict, _cost) =
+        conditions_dict_for_solution(&coin_spend.puzzle_reveal, &coin_spend.solution, max_cost)?;
+    pkm_pairs_for_conditions_dict(&conditions_dict, &coin_spend.coin, additional_data)
+}
+
+pub fn pkm_pairs(
+    coin_spends: &[CoinSpend],
+    additional_data: &[u8],
+    max_cost: u64,
+) -> Result<Vec<(Bytes48, Vec<u8>)>, Box<dyn Error>> {
+    let mut ret = Vec::new();
+    for coin_spend in coin_spends {
+        ret.extend(pkm_pairs_for_coin_spend(
+            coin_spend,
+            additional_data,
+            max_cost,
+        )?);
+    }
+    Ok(ret)
+}
+
+#[test]
+fn test_parse_conditions_skips_unknown_opcodes() {
+    let condition =
+        |opcode: Vec<u8>| Program::from(vec![Program::from(opcode), Program::from(vec![0x11; 32])]);
+    let conditions = Program::from(vec![
+        condition(vec![]),
+        condition(vec![0x01, 0x33]),
+        condition(vec![90]),
+        condition(vec![ConditionOpcode::AssertMyCoinId as u8]),
+    ]);
+    let parsed =
+        parse_sexp_to_conditions(&SerializedProgram::from_bytes(&conditions.serialized)).unwrap();
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].opcode, ConditionOpcode::AssertMyCoinId);
+}
+
+#[test]
+fn test_create_coin_memos_stay_out_of_vars() {
+    let puzzle_hash = vec![0x22; 32];
+    let memos = vec![vec![0x33; 32], b"hello".to_vec()];
+    let mut condition = Program::from(vec![
+        Program::from(vec![ConditionOpcode::CreateCoin as u8]),
+        Program::from(puzzle_hash.clone()),
+        Program::from(vec![0x03, 0xe8]),
+        Program::from(
+            memos
+                .iter()
+                .map(|memo| Program::from(memo.clone()))
+                .collect::<Vec<Program>>(),
+        ),
+    ]);
+    let parsed = parse_sexp_to_condition(&mut condition).unwrap().unwrap();
+    assert_eq!(parsed.vars, vec![puzzle_hash.clone(), vec![0x03, 0xe8]]);
+    assert_eq!(create_coin_memos(&condition), memos);
+
+    let without_memos = Program::from(vec![
+        Program::from(vec![ConditionOpcode::CreateCoin as u8]),
+        Program::from(puzzle_hash),
+        Program::from(vec![0x03, 0xe8]),
+    ]);
+    assert!(create_coin_memos(&without_memos).is_empty());
+}
+
+#[test]
+fn test_pkm_pairs_for_every_agg_sig_variant() {
+    let public_key = vec![0xa0; 48];
+    let genesis_challenge = vec![0xcc; 32];
+    let coin = Coin {
+        parent_coin_info: Bytes32::from(vec![0x11; 32]),
+        puzzle_hash: Program::from(1).tree_hash(),
+        amount: 1000,
+    };
+    // `1` returns its solution, so the solution is the list of conditions.
+    let opcodes = [49u8, 50, 43, 44, 45, 46, 47, 48];
+    let conditions: Vec<Program> = opcodes
+        .iter()
+        .map(|opcode| {
+            Program::from(vec![
+                Program::from(vec![*opcode]),
+                Program::from(public_key.clone()),
+                Program::from(vec![*opcode; 4]),
+            ])
+        })
+        .collect();
+    let coin_spend = CoinSpend {
+        coin: coin.clone(),
+        puzzle_reveal: Program::from(1).into(),
+        solution: Program::from(conditions).into(),
+    };
+    let pairs = pkm_pairs(&[coin_spend], &genesis_challenge, u64::MAX).unwrap();
+
+    let parent = coin.parent_coin_info.bytes.clone();
+    let puzzle_hash = coin.puzzle_hash.bytes.clone();
+    let amount = vec![0x03, 0xe8];
+    let data = |opcode: u8| hash_256([genesis_challenge.clone(), vec![opcode]].concat());
+    let expected = vec![
+        vec![49; 4],
+        [vec![50; 4], coin.name().bytes, genesis_challenge.clone()].concat(),
+        [vec![43; 4], parent.clone(), data(43)].concat(),
+        [vec![44; 4], puzzle_hash.clone(), data(44)].concat(),
+        [vec![45; 4], amount.clone(), data(45)].concat(),
+        [vec![46; 4], puzzle_hash.clone(), amount.clone(), data(46)].concat(),
+        [vec![47; 4], parent.clone(), amount, data(47)].concat(),
+        [vec![48; 4], parent, puzzle_hash, data(48)].concat(),
+    ];
+    assert_eq!(pairs.len(), expected.len());
+    for ((pk, msg), expected) in pairs.iter().zip(expected) {
+        assert_eq!(pk.bytes, public_key);
+        assert_eq!(*msg, expected);
+    }
+}
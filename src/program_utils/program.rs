@@ -178,24 +178,30 @@ impl Program {
         )
     }
 
-    pub fn uncurry(&self) -> UncurriedProgram {
+    pub fn uncurry(&self) -> Result<UncurriedProgram, Box<dyn Error>> {
+        self.try_uncurry()
+            .ok_or_else(|| "program is not curried".into())
+    }
+
+    /// Like `uncurry`, but returns None when the program is not in the curried form
+    pub fn try_uncurry(&self) -> Option<UncurriedProgram> {
         let mut allocator = Allocator::new();
-        let nodeptr = node_from_bytes(&mut allocator, self.serialized.clone().as_slice()).unwrap();
-        let uncurried = crate::program_utils::uncurry::uncurry(&allocator, nodeptr).unwrap();
+        let nodeptr = node_from_bytes(&mut allocator, self.serialized.clone().as_slice()).ok()?;
+        let uncurried = crate::program_utils::uncurry::uncurry(&allocator, nodeptr)?;
         let program_node = Node::new(&allocator, uncurried.0);
-        let uncurred_program_bytes = node_to_bytes(&program_node).unwrap();
+        let uncurred_program_bytes = node_to_bytes(&program_node).ok()?;
         let mut args = Vec::new();
         for arg in uncurried.1 {
             let arg_node = Node::new(&allocator, arg);
-            let arg_bytes = node_to_bytes(&arg_node).unwrap();
+            let arg_bytes = node_to_bytes(&arg_node).ok()?;
             args.push(
-                SerializedProgram::from_bytes(&arg_bytes.clone())
+                SerializedProgram::from_bytes(&arg_bytes)
                     .to_program()
-                    .unwrap(),
+                    .ok()?,
             );
         }
         let program = Program::new(uncurred_program_bytes);
-        UncurriedProgram { program, args }
+        Some(UncurriedProgram { program, args })
     }
 
     pub fn as_atom_list(&mut self) -> Vec<Vec<u8>> {
@@ -347,7 +353,16 @@ impl Program {
         let program_source = self.disassemble();
         let args_source = args.disassemble();
         let cmd_args = ["-c".to_string(), program_source, args_source];
-        let output = cmds_program_brun(cmd_args.to_vec());
+        let output = match cmds_program_brun(cmd_args.to_vec()) {
+            Ok(output) => output,
+            Err(error) => {
+                return RunOutput {
+                    program: Program::null(),
+                    cost: 0,
+                    error: Some(error.to_string()),
+                }
+            }
+        };
         let lines = output.split("\n").collect::<Vec<&str>>();
         let mut cleaned_lines = Vec::new();
         for line in lines {
@@ -374,21 +389,19 @@ impl Program {
         }
     }
 
-    pub fn run(&self, args: Program) -> RunOutput {
+    pub fn run(&self, args: Program) -> Result<RunOutput, Box<dyn Error>> {
         let mut alloc = Allocator::new();
         let serialized_p = SerializedProgram::from_bytes(&self.serialized.clone());
 
-        let (cost, result) = serialized_p
-            .run_with_cost(&mut alloc, Cost::MAX, &args.clone())
-            .unwrap();
+        let (cost, result) = serialized_p.run_with_cost(&mut alloc, Cost::MAX, &args.clone())?;
         let prog = Node::new(&alloc, result);
-        let bytes = node_to_bytes(&prog).unwrap();
+        let bytes = node_to_bytes(&prog)?;
         let program = Program::new(bytes);
-        RunOutput {
+        Ok(RunOutput {
             program,
             cost,
             error: None,
-        }
+        })
     }
 
     pub fn disassemble(&self) -> String {
@@ -234,6 +234,65 @@ pub fn serialized_length_from_bytes(b: &[u8]) -> std::io::Result<u64> {
     Ok(f.position())
 }
 
+// walks the buffer like serialized_length_from_bytes, but on failure returns the
+// offset of the atom (or cons box) that could not be parsed. Bytes left over after
+// the program are also rejected.
+pub fn validate_serialized_program(buf: &[u8]) -> Result<(), (u64, std::io::Error)> {
+    let mut f = Cursor::new(buf);
+    let mut ops = vec![ParseOp::SExp];
+    let mut b = [0; 1];
+    while let Some(op) = ops.pop() {
+        match op {
+            ParseOp::SExp => {
+                let offset = f.position();
+                f.read_exact(&mut b).map_err(|e| (offset, e))?;
+                if b[0] == CONS_BOX_MARKER {
+                    ops.push(ParseOp::SExp);
+                    ops.push(ParseOp::SExp);
+                } else if b[0] == 0x80 || b[0] <= MAX_SINGLE_BYTE {
+                    // the whole atom was this byte
+                } else {
+                    let blob_size = decode_size(&mut f, b[0]).map_err(|e| (offset, e))?;
+                    if (buf.len() as u64) < f.position() + blob_size {
+                        return Err((offset, bad_encoding()));
+                    }
+                    f.seek(SeekFrom::Current(blob_size as i64))
+                        .map_err(|e| (offset, e))?;
+                }
+            }
+            ParseOp::Cons => {}
+        }
+    }
+    if f.position() != buf.len() as u64 {
+        return Err((
+            f.position(),
+            Error::new(ErrorKind::InvalidData, "trailing bytes after program"),
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_validate_serialized_program() {
+    assert!(validate_serialized_program(&[0x80]).is_ok());
+    assert!(validate_serialized_program(&[0xff, 0x01, 0xff, 0x80, 0x80]).is_ok());
+
+    let (offset, _) = validate_serialized_program(&[]).unwrap_err();
+    assert_eq!(offset, 0);
+
+    // second element of the pair is missing
+    let (offset, _) = validate_serialized_program(&[0xff, 0x01]).unwrap_err();
+    assert_eq!(offset, 2);
+
+    // atom claims 15 bytes but only one follows
+    let (offset, e) = validate_serialized_program(&[0xff, 0x01, 0x8f, 0xff]).unwrap_err();
+    assert_eq!(offset, 2);
+    assert_eq!(e.to_string(), "bad encoding");
+
+    let (offset, _) = validate_serialized_program(&[0x80, 0x00]).unwrap_err();
+    assert_eq!(offset, 1);
+}
+
 #[test]
 fn test_serialized_length_from_bytes() {
     assert_eq!(